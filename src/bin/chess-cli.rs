@@ -0,0 +1,546 @@
+use std::{
+    env, fs,
+    io::{self, BufRead, Lines, StdinLock, Write},
+    process,
+    time::{Duration, Instant},
+};
+
+use chess::{
+    board::Board,
+    clock::TimeControl,
+    color::Color,
+    engine::Score,
+    fen::STARTING_FEN,
+    moves::Move,
+    outcome::Outcome,
+    pgn::{parse_pgn, PgnGame, PgnTags},
+    position::Position,
+    tournament::{
+        Adjudication, EngineMatch, MatchGame, MatchSettings, MatchStats, PlayerConfig, Sprt,
+    },
+    uci::{BestMove, GoParams, UciEngine, UciInfo},
+};
+
+const USAGE: &str = "usage:
+  chess-cli perft <depth> [fen]                              count the leaf nodes of the move tree
+  chess-cli divide <depth> [fen]                             count the leaf nodes below each legal move
+  chess-cli analyse <engine> <depth> [fen]                   analyse a position with a UCI engine
+  chess-cli play <engine> <movetime-ms> [white|black] [fen]  play a game against a UCI engine
+  chess-cli match <player> <player> [options]                play a match between two engines
+
+players are `builtin`, `builtin:depth=4,nodes=20000,hash=32,name=...` or the path of a UCI engine
+match options:
+  --games <n>                          number of games, 2 by default
+  --tc <time control>                  [moves/]seconds stages joined by colons, then +increment
+                                       or d and a delay, 10+0.1 by default
+  --openings <file>                    openings as FEN lines or as a .pgn file
+  --pgn <file>                         file receiving the games
+  --resign <cp> <moves>                lose after that many moves at or below -cp
+  --draw <move> <cp> <moves>           draw after that many moves within cp from the given move
+  --max-moves <n>                      draw after that many moves
+  --sprt <elo0> <elo1> [alpha beta]    stop once a test is conclusive, 0.05 errors by default";
+
+/// The number of games of a match when none is given.
+const DEFAULT_GAMES: u32 = 2;
+
+/// The time control of a match when none is given.
+const DEFAULT_TIME_CONTROL: &str = "10+0.1";
+
+/// The probability of each error of a test when none is given.
+const DEFAULT_SPRT_ERROR: f64 = 0.05;
+
+/// The longest time an engine may analyse before it is stopped.
+const ANALYSIS_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// The time an engine is given past its time per move before it is stopped.
+const MOVETIME_MARGIN: Duration = Duration::from_secs(1);
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result: Result<(), String> = match args.first().map(String::as_str) {
+        Some("perft") => perft(&args[1..]),
+        Some("divide") => divide(&args[1..]),
+        Some("analyse") => analyse(&args[1..]),
+        Some("play") => play(&args[1..]),
+        Some("match") => run_match(&args[1..]),
+        _ => Err(USAGE.to_string()),
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
+
+/// Reads a board from the optional FEN closing the arguments.
+///
+/// # Arguments
+///
+/// * `args` - The fields of the FEN, empty for the starting position.
+///
+/// # Returns
+///
+/// A `Result` containing the board, or a message explaining the error.
+fn parse_board(args: &[String]) -> Result<Board, String> {
+    let fen: String = if args.is_empty() {
+        STARTING_FEN.to_string()
+    } else {
+        args.join(" ")
+    };
+    Board::from_fen(&fen).map_err(|error| error.to_string())
+}
+
+/// Reads the depth and the optional FEN shared by `perft` and `divide`.
+///
+/// # Arguments
+///
+/// * `args` - The arguments following the subcommand.
+///
+/// # Returns
+///
+/// A `Result` containing the depth and the board, or a message explaining the error.
+fn parse_depth_and_board(args: &[String]) -> Result<(u32, Board), String> {
+    let depth: u32 = args
+        .first()
+        .ok_or_else(|| USAGE.to_string())?
+        .parse()
+        .map_err(|_| format!("invalid depth '{}'", args[0]))?;
+    let board: Board = parse_board(&args[1..])?;
+
+    Ok((depth, board))
+}
+
+/// Prints the number of leaf nodes at a depth and the time it took.
+///
+/// # Arguments
+///
+/// * `args` - The depth followed by an optional FEN.
+///
+/// # Returns
+///
+/// A `Result` which is an error message if the arguments are invalid.
+fn perft(args: &[String]) -> Result<(), String> {
+    let (depth, board): (u32, Board) = parse_depth_and_board(args)?;
+    let start: Instant = Instant::now();
+    let nodes: u64 = board.perft(depth);
+
+    println!("Nodes searched: {}", nodes);
+    println!("Time: {} ms", start.elapsed().as_millis());
+    Ok(())
+}
+
+/// Prints the number of leaf nodes below each legal move, then their total.
+///
+/// # Arguments
+///
+/// * `args` - The depth followed by an optional FEN.
+///
+/// # Returns
+///
+/// A `Result` which is an error message if the arguments are invalid.
+fn divide(args: &[String]) -> Result<(), String> {
+    let (depth, board): (u32, Board) = parse_depth_and_board(args)?;
+    let mut moves: Vec<(Move, u64)> = board.divide(depth);
+    moves.sort_by_key(|(chess_move, _)| chess_move.get_uci());
+
+    for (chess_move, nodes) in moves.iter() {
+        println!("{}: {}", chess_move.get_uci(), nodes);
+    }
+    println!();
+    println!(
+        "Nodes searched: {}",
+        moves.iter().map(|(_, nodes)| nodes).sum::<u64>()
+    );
+    Ok(())
+}
+
+/// Prints the lines found by a UCI engine as it searches a position.
+///
+/// # Arguments
+///
+/// * `args` - The path of the engine, the depth, then an optional FEN.
+///
+/// # Returns
+///
+/// A `Result` which is an error message if the arguments are invalid or the
+/// engine fails.
+fn analyse(args: &[String]) -> Result<(), String> {
+    let path: &String = args.first().ok_or_else(|| USAGE.to_string())?;
+    let depth: u32 = args
+        .get(1)
+        .ok_or_else(|| USAGE.to_string())?
+        .parse()
+        .map_err(|_| format!("invalid depth '{}'", args[1]))?;
+    let board: Board = parse_board(&args[2..])?;
+
+    let mut engine: UciEngine = UciEngine::spawn(path, &[]).map_err(|error| error.to_string())?;
+    println!("{}", engine.get_name());
+    engine
+        .set_position(&board)
+        .map_err(|error| error.to_string())?;
+    let best_move: BestMove = engine
+        .go(
+            &GoParams::new().with_depth(depth),
+            ANALYSIS_TIMEOUT,
+            |info| {
+                if let (Some(depth), Some(score)) = (info.get_depth(), info.get_score()) {
+                    println!(
+                        "{:>3} {:>8}  {}",
+                        depth,
+                        get_score_text(score),
+                        get_pv_text(&board, info)
+                    );
+                }
+            },
+        )
+        .map_err(|error| error.to_string())?;
+
+    match best_move
+        .get_best_move()
+        .and_then(|uci| board.parse_uci(uci))
+    {
+        Some(chess_move) => println!("Best move: {}", board.get_san(chess_move)),
+        None => println!("Best move: none"),
+    }
+    Ok(())
+}
+
+/// Plays a game on the terminal against a UCI engine. The player types moves in
+/// SAN or in long algebraic notation, and `quit` to leave.
+///
+/// # Arguments
+///
+/// * `args` - The path of the engine, its time per move in milliseconds, then
+///   optionally the color of the player and a FEN.
+///
+/// # Returns
+///
+/// A `Result` which is an error message if the arguments are invalid or the
+/// engine fails.
+fn play(args: &[String]) -> Result<(), String> {
+    let path: &String = args.first().ok_or_else(|| USAGE.to_string())?;
+    let movetime: u64 = args
+        .get(1)
+        .ok_or_else(|| USAGE.to_string())?
+        .parse()
+        .map_err(|_| format!("invalid time per move '{}'", args[1]))?;
+    let (color, fen_index): (Color, usize) = match args.get(2).map(String::as_str) {
+        Some("white") => (Color::White, 3),
+        Some("black") => (Color::Black, 3),
+        _ => (Color::White, 2),
+    };
+    let mut board: Board = parse_board(&args[fen_index.min(args.len())..])?;
+
+    let mut engine: UciEngine = UciEngine::spawn(path, &[]).map_err(|error| error.to_string())?;
+    engine.new_game().map_err(|error| error.to_string())?;
+    let params: GoParams = GoParams::new().with_movetime(Duration::from_millis(movetime));
+    let timeout: Duration = Duration::from_millis(movetime) + MOVETIME_MARGIN;
+    let mut lines: Lines<StdinLock<'static>> = io::stdin().lock().lines();
+
+    println!("Playing against {}", engine.get_name());
+    while !board.is_end() {
+        print_board(&board);
+
+        if board.get_color_turn() == color {
+            print!("Your move: ");
+            io::stdout().flush().map_err(|error| error.to_string())?;
+            let line: String = match lines.next() {
+                Some(Ok(line)) if line.trim() != "quit" => line,
+                _ => return Ok(()),
+            };
+            let chess_move: Option<Move> = board
+                .parse_uci(line.trim())
+                .or_else(|| board.parse_san(&line).ok());
+            match chess_move.map(|chess_move| board.make_move(chess_move)) {
+                Some(Ok(())) => {}
+                Some(Err(error)) => println!("{}", error),
+                None => println!("'{}' is not a legal move", line.trim()),
+            }
+        } else {
+            engine
+                .set_position(&board)
+                .map_err(|error| error.to_string())?;
+            let best_move: BestMove = engine
+                .go(&params, timeout, |_| {})
+                .map_err(|error| error.to_string())?;
+            let chess_move: Move = best_move
+                .get_best_move()
+                .and_then(|uci| board.parse_uci(uci))
+                .ok_or_else(|| format!("{} played an illegal move", engine.get_name()))?;
+            println!("{} plays {}", engine.get_name(), board.get_san(chess_move));
+            board
+                .make_move(chess_move)
+                .map_err(|error| error.to_string())?;
+        }
+    }
+
+    print_board(&board);
+    if let Some(outcome) = board.get_outcome() {
+        println!("{}", get_outcome_text(outcome));
+    }
+    Ok(())
+}
+
+/// Plays a match between two engines, printing the result of each game and the
+/// statistics at the end, and writing the games to a PGN file if asked.
+///
+/// # Arguments
+///
+/// * `args` - The specifications of both players, followed by options.
+///
+/// # Returns
+///
+/// A `Result` which is an error message if the arguments are invalid, a file cannot
+/// be read or written, or an engine fails.
+fn run_match(args: &[String]) -> Result<(), String> {
+    if args.len() < 2 {
+        return Err(USAGE.to_string());
+    }
+    let first: PlayerConfig = PlayerConfig::parse(&args[0]).map_err(|error| error.to_string())?;
+    let second: PlayerConfig = PlayerConfig::parse(&args[1]).map_err(|error| error.to_string())?;
+    let mut games: u32 = DEFAULT_GAMES;
+    let mut time_control: TimeControl =
+        TimeControl::parse(DEFAULT_TIME_CONTROL).map_err(|error| error.to_string())?;
+    let mut openings: Option<Vec<Board>> = None;
+    let mut pgn_path: Option<&String> = None;
+    let mut adjudication: Adjudication = Adjudication::new();
+    let mut sprt: Option<Sprt> = None;
+
+    let mut options: &[String] = &args[2..];
+    while let Some(option) = options.first() {
+        let count: usize = match option.as_str() {
+            "--games" | "--tc" | "--openings" | "--pgn" | "--max-moves" => 1,
+            "--resign" => 2,
+            "--draw" => 3,
+            "--sprt" if options.len() > 4 && !options[3].starts_with("--") => 4,
+            "--sprt" => 2,
+            _ => return Err(format!("unknown option '{}'", option)),
+        };
+        let values: &[String] = options
+            .get(1..=count)
+            .ok_or_else(|| format!("missing value for '{}'", option))?;
+        let number = |index: usize| -> Result<f64, String> {
+            values[index]
+                .parse()
+                .map_err(|_| format!("invalid value '{}' for '{}'", values[index], option))
+        };
+
+        match option.as_str() {
+            "--games" => games = number(0)? as u32,
+            "--tc" => {
+                time_control = TimeControl::parse(&values[0]).map_err(|error| error.to_string())?
+            }
+            "--openings" => openings = Some(load_openings(&values[0])?),
+            "--pgn" => pgn_path = Some(&values[0]),
+            "--resign" => {
+                adjudication = adjudication.with_resign(number(0)? as i32, number(1)? as u32)
+            }
+            "--draw" => {
+                adjudication =
+                    adjudication.with_draw(number(0)? as u32, number(1)? as i32, number(2)? as u32)
+            }
+            "--max-moves" => adjudication = adjudication.with_max_moves(number(0)? as u32),
+            _ => {
+                let (alpha, beta): (f64, f64) = if count == 4 {
+                    (number(2)?, number(3)?)
+                } else {
+                    (DEFAULT_SPRT_ERROR, DEFAULT_SPRT_ERROR)
+                };
+                sprt = Some(Sprt::new(number(0)?, number(1)?, alpha, beta));
+            }
+        }
+        options = &options[count + 1..];
+    }
+
+    let mut settings: MatchSettings =
+        MatchSettings::new(games, time_control).with_adjudication(adjudication);
+    if let Some(openings) = openings {
+        settings = settings
+            .with_openings(openings)
+            .map_err(|error| error.to_string())?;
+    }
+    if let Some(sprt) = sprt {
+        settings = settings.with_sprt(sprt);
+    }
+    let mut engine_match: EngineMatch = EngineMatch::new(
+        first.create().map_err(|error| error.to_string())?,
+        second.create().map_err(|error| error.to_string())?,
+        settings.clone(),
+    );
+    let mut pgn: Option<fs::File> = match pgn_path {
+        Some(path) => Some(
+            fs::File::create(path)
+                .map_err(|error| format!("cannot create '{}': {}", path, error))?,
+        ),
+        None => None,
+    };
+
+    while let Some(game) = engine_match
+        .play_next()
+        .map_err(|error| error.to_string())?
+    {
+        print_game(&engine_match, &game);
+        if let (Some(file), Some(path)) = (pgn.as_mut(), pgn_path) {
+            writeln!(file, "{}", game.get_board().to_pgn())
+                .map_err(|error| format!("cannot write '{}': {}", path, error))?;
+        }
+    }
+
+    let stats: MatchStats = engine_match.get_stats();
+    println!();
+    match (stats.get_elo_difference(), stats.get_elo_error()) {
+        (Some(elo), Some(error)) => println!("Elo difference: {:+.1} +/- {:.1}", elo, error),
+        (Some(elo), None) => println!("Elo difference: {:+.1} +/- inf", elo),
+        (None, _) => println!("Elo difference: unknown"),
+    }
+    if let Some(sprt) = settings.get_sprt() {
+        println!(
+            "SPRT: llr {:.2} ({:.2}, {:.2}), {}",
+            sprt.get_llr(&stats),
+            sprt.get_lower_bound(),
+            sprt.get_upper_bound(),
+            sprt.get_result(&stats)
+        );
+    }
+    Ok(())
+}
+
+/// Reads the openings of a match from a file of PGN games, if its name ends with
+/// `.pgn`, or else of FEN lines.
+///
+/// # Arguments
+///
+/// * `path` - The path of the file.
+///
+/// # Returns
+///
+/// A `Result` containing the games to continue from, or a message explaining the
+/// error.
+fn load_openings(path: &str) -> Result<Vec<Board>, String> {
+    let text: String =
+        fs::read_to_string(path).map_err(|error| format!("cannot read '{}': {}", path, error))?;
+
+    if path.ends_with(".pgn") {
+        let games: Vec<PgnGame> = parse_pgn(&text).map_err(|error| error.to_string())?;
+        games
+            .iter()
+            .map(|game| game.replay().map_err(|error| error.to_string()))
+            .collect()
+    } else {
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|fen| Board::from_fen(fen).map_err(|error| format!("{}: {}", fen, error)))
+            .collect()
+    }
+}
+
+/// Prints the result of a game of a match and the score of the match so far.
+///
+/// # Arguments
+///
+/// * `engine_match` - The match.
+/// * `game` - The game just played.
+fn print_game(engine_match: &EngineMatch, game: &MatchGame) {
+    let stats: MatchStats = engine_match.get_stats();
+    let tags: &PgnTags = game.get_board().get_pgn_tags();
+
+    println!(
+        "Game {} ({} vs {}): {} {{{}}}",
+        stats.get_games(),
+        tags.get("White").unwrap_or("?"),
+        tags.get("Black").unwrap_or("?"),
+        game.get_result(),
+        game.get_reason()
+    );
+    println!(
+        "Score of {} vs {}: {} - {} - {} [{:.3}] {}",
+        engine_match.get_first_name(),
+        engine_match.get_second_name(),
+        stats.get_wins(),
+        stats.get_losses(),
+        stats.get_draws(),
+        stats.get_score(),
+        stats.get_games()
+    );
+}
+
+/// Prints the board with white at the bottom, then its FEN.
+///
+/// # Arguments
+///
+/// * `board` - The board to print.
+fn print_board(board: &Board) {
+    println!();
+    for row in 0..board.get_size() {
+        let rank: String = (0..board.get_size())
+            .map(|col| {
+                board
+                    .get_cell(Position::new(row, col))
+                    .get_piece()
+                    .map_or('.', |piece| piece.get_fen_symbol())
+            })
+            .map(|symbol| format!(" {}", symbol))
+            .collect();
+        println!("{}{}", board.get_size() - row, rank);
+    }
+    println!("  a b c d e f g h");
+    println!("{}", board.to_fen());
+}
+
+/// Formats a score as a number of pawns or a mate distance.
+///
+/// # Arguments
+///
+/// * `score` - The score to format.
+///
+/// # Returns
+///
+/// A `String` such as `+0.35` or `#-3`.
+fn get_score_text(score: Score) -> String {
+    match score {
+        Score::Centipawns(centipawns) => format!("{:+.2}", centipawns as f64 / 100.0),
+        Score::Mate(moves) => format!("#{}", moves),
+    }
+}
+
+/// Formats the best line of an `info` line in SAN.
+///
+/// # Arguments
+///
+/// * `board` - The position the line starts from.
+/// * `info` - The `info` line.
+///
+/// # Returns
+///
+/// A `String` containing the moves of the line separated by spaces.
+fn get_pv_text(board: &Board, info: &UciInfo) -> String {
+    let mut board: Board = board.clone();
+    let mut sans: Vec<String> = Vec::new();
+
+    for chess_move in info.get_pv_moves(&board) {
+        sans.push(board.get_san(chess_move));
+        if board.make_move(chess_move).is_err() {
+            break;
+        }
+    }
+    sans.join(" ")
+}
+
+/// Describes how a game ended.
+///
+/// # Arguments
+///
+/// * `outcome` - The outcome of the game.
+///
+/// # Returns
+///
+/// A `String` such as `White wins by checkmate`.
+fn get_outcome_text(outcome: Outcome) -> String {
+    match outcome.get_winner() {
+        Some(Color::White) => format!("White wins by {}", outcome.get_description()),
+        Some(Color::Black) => format!("Black wins by {}", outcome.get_description()),
+        None => format!("Draw by {}", outcome.get_description()),
+    }
+}
@@ -0,0 +1,333 @@
+use std::{
+    io::{self, BufRead},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use chess::{
+    board::Board,
+    color::Color,
+    engine::{Engine, Score, SearchInfo, SearchLimits, DEFAULT_HASH_SIZE},
+    fen::STARTING_FEN,
+    moves::Move,
+};
+
+/// The largest transposition table accepted by the `Hash` option, in megabytes.
+const MAX_HASH_SIZE: usize = 1024;
+
+/// Represents the state of the engine between two commands.
+struct Session {
+    /// The position to search.
+    board: Board,
+    /// The engine, held by the search thread while a search runs.
+    engine: Option<Engine>,
+    /// The thread of the running search, which hands the engine back when it ends.
+    search: Option<JoinHandle<Engine>>,
+    /// Indicates whether the running search waits for `stop` before answering.
+    infinite: bool,
+    /// The flag raised to stop the running search.
+    stop: Arc<AtomicBool>,
+}
+
+fn main() {
+    let mut session: Session = Session::new();
+
+    for line in io::stdin().lock().lines() {
+        let line: String = match line {
+            Ok(line) => line,
+            Err(_) => break,
+        };
+        if !session.handle(&line) {
+            return;
+        }
+    }
+    session.finish(false);
+}
+
+impl Session {
+    /// Creates a session in the starting position.
+    ///
+    /// # Returns
+    ///
+    /// A new `Session` instance.
+    fn new() -> Session {
+        let engine: Engine = Engine::new();
+        Session {
+            board: Board::new().initialize(),
+            stop: engine.get_stop_flag(),
+            engine: Some(engine),
+            search: None,
+            infinite: false,
+        }
+    }
+
+    /// Handles one command sent by the GUI. Unknown commands are ignored, as the
+    /// protocol asks.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - The line read from the standard input.
+    ///
+    /// # Returns
+    ///
+    /// `false` if the engine must quit, `true` otherwise.
+    fn handle(&mut self, line: &str) -> bool {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+
+        match tokens.first().copied() {
+            Some("uci") => {
+                println!("id name Chess");
+                println!("id author Enzo Sergiani");
+                println!(
+                    "option name Hash type spin default {} min 1 max {}",
+                    DEFAULT_HASH_SIZE, MAX_HASH_SIZE
+                );
+                println!("option name Clear Hash type button");
+                println!("uciok");
+            }
+            Some("isready") => println!("readyok"),
+            Some("ucinewgame") => {
+                self.finish(true);
+                self.get_engine().clear();
+                self.board = Board::new().initialize();
+            }
+            Some("position") => {
+                self.finish(true);
+                if let Err(message) = self.set_position(&tokens[1..]) {
+                    println!("info string {}", message);
+                }
+            }
+            Some("go") => {
+                self.finish(true);
+                self.go(&tokens[1..]);
+            }
+            Some("stop") => self.finish(true),
+            Some("setoption") => {
+                self.finish(true);
+                if let Err(message) = self.set_option(&tokens[1..]) {
+                    println!("info string {}", message);
+                }
+            }
+            Some("quit") => {
+                self.finish(true);
+                return false;
+            }
+            _ => {}
+        }
+        true
+    }
+
+    /// Waits for the running search to end, if any, and takes the engine back.
+    ///
+    /// # Arguments
+    ///
+    /// * `stop` - Whether to stop the search instead of letting it reach its limits.
+    ///   A search without limits is always stopped.
+    fn finish(&mut self, stop: bool) {
+        if let Some(search) = self.search.take() {
+            if stop || self.infinite {
+                self.stop.store(true, Ordering::Relaxed);
+            }
+            self.engine = Some(search.join().expect("the search thread does not panic"));
+        }
+    }
+
+    /// Gets the engine, which is available whenever no search runs.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the engine.
+    fn get_engine(&mut self) -> &mut Engine {
+        self.engine.as_mut().expect("no search is running")
+    }
+
+    /// Sets up the position given by a `position` command.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - `startpos` or `fen` followed by the six FEN fields, then
+    ///   optionally `moves` and the moves to play.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is an error message if the position or a move is invalid.
+    fn set_position(&mut self, args: &[&str]) -> Result<(), String> {
+        let moves_index: usize = args
+            .iter()
+            .position(|arg| *arg == "moves")
+            .unwrap_or(args.len());
+
+        let mut board: Board = match args.first().copied() {
+            Some("startpos") => Board::from_fen(STARTING_FEN).expect("the starting FEN is valid"),
+            Some("fen") => Board::from_fen(&args[1..moves_index].join(" "))
+                .map_err(|error| error.to_string())?,
+            _ => return Err("expected startpos or fen".to_string()),
+        };
+        // User interfaces keep sending the moves of games played on past a draw
+        // which needs no claim, so such draws must not end the game here.
+        board.set_automatic_draws(false);
+
+        for uci in args.iter().skip(moves_index + 1) {
+            let chess_move: Move = board
+                .parse_uci(uci)
+                .ok_or_else(|| format!("illegal move {}", uci))?;
+            board
+                .make_move(chess_move)
+                .map_err(|error| error.to_string())?;
+        }
+
+        self.board = board;
+        Ok(())
+    }
+
+    /// Starts searching the current position in a new thread, as asked by a `go`
+    /// command. The thread prints an `info` line after each iteration and the
+    /// `bestmove` line at the end.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The parameters of the command, such as `depth 6` or
+    ///   `wtime 60000 btime 60000 winc 1000 binc 1000`.
+    fn go(&mut self, args: &[&str]) {
+        let limits: SearchLimits = get_limits(args, self.board.get_color_turn());
+        let board: Board = self.board.clone();
+        let mut engine: Engine = self.engine.take().expect("no search is running");
+        let stop: Arc<AtomicBool> = Arc::clone(&self.stop);
+        let infinite: bool = args.contains(&"infinite");
+
+        self.infinite = infinite;
+        self.stop.store(false, Ordering::Relaxed);
+        self.search = Some(thread::spawn(move || {
+            let start: Instant = Instant::now();
+            let result: Option<SearchInfo> = engine.search(&board, limits, |info| {
+                println!("{}", get_info_line(info, start.elapsed()));
+            });
+
+            // The protocol forbids answering an infinite search before `stop`.
+            while infinite && !stop.load(Ordering::Relaxed) {
+                thread::sleep(Duration::from_millis(5));
+            }
+
+            match result.and_then(|info| info.get_best_move()) {
+                Some(best_move) => println!("bestmove {}", best_move.get_uci()),
+                None => println!("bestmove 0000"),
+            }
+            engine
+        }));
+    }
+
+    /// Changes an option of the engine, as asked by a `setoption` command.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - `name` followed by the name of the option, then `value` and the
+    ///   new value for options that take one.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is an error message if the option or its value is unknown.
+    fn set_option(&mut self, args: &[&str]) -> Result<(), String> {
+        let value_index: usize = args
+            .iter()
+            .position(|arg| *arg == "value")
+            .unwrap_or(args.len());
+        let name: String = args
+            .get(1..value_index)
+            .unwrap_or_default()
+            .join(" ")
+            .to_lowercase();
+        let value: String = args.get(value_index + 1..).unwrap_or_default().join(" ");
+
+        match name.as_str() {
+            "hash" => {
+                let megabytes: usize = value
+                    .parse()
+                    .ok()
+                    .filter(|megabytes| (1..=MAX_HASH_SIZE).contains(megabytes))
+                    .ok_or_else(|| format!("invalid Hash value {}", value))?;
+                self.get_engine().set_hash_size(megabytes);
+            }
+            "clear hash" => self.get_engine().clear(),
+            _ => return Err(format!("unknown option {}", name)),
+        }
+        Ok(())
+    }
+}
+
+/// Reads the limits of a search from the parameters of a `go` command.
+///
+/// # Arguments
+///
+/// * `args` - The parameters of the command.
+/// * `color` - The color of the side to move, whose clock is used.
+///
+/// # Returns
+///
+/// The `SearchLimits` of the search.
+fn get_limits(args: &[&str], color: Color) -> SearchLimits {
+    let get_number = |name: &str| -> Option<u64> {
+        args.iter()
+            .position(|arg| *arg == name)
+            .and_then(|index| args.get(index + 1))
+            .and_then(|value| value.parse().ok())
+    };
+    let (time, increment): (Option<u64>, Option<u64>) = match color {
+        Color::White => (get_number("wtime"), get_number("winc")),
+        Color::Black => (get_number("btime"), get_number("binc")),
+    };
+    let mut limits: SearchLimits = SearchLimits::new();
+
+    if let Some(depth) = get_number("depth") {
+        limits = limits.with_depth(depth as u32);
+    }
+    if let Some(nodes) = get_number("nodes") {
+        limits = limits.with_nodes(nodes);
+    }
+    if let Some(movetime) = get_number("movetime") {
+        limits = limits.with_movetime(Duration::from_millis(movetime));
+    } else if let Some(time) = time {
+        limits = limits.with_clock(
+            Duration::from_millis(time),
+            Duration::from_millis(increment.unwrap_or(0)),
+            get_number("movestogo").map(|moves_to_go| moves_to_go as u32),
+        );
+    }
+    limits
+}
+
+/// Formats the result of an iteration as an `info` line.
+///
+/// # Arguments
+///
+/// * `info` - The result of the iteration.
+/// * `elapsed` - The time since the search started.
+///
+/// # Returns
+///
+/// A `String` such as `info depth 5 score cp 30 nodes 12000 nps 400000 time 30 pv e2e4 e7e5`.
+fn get_info_line(info: &SearchInfo, elapsed: Duration) -> String {
+    let score: String = match info.get_score() {
+        Score::Centipawns(centipawns) => format!("cp {}", centipawns),
+        Score::Mate(moves) => format!("mate {}", moves),
+    };
+    let milliseconds: u128 = elapsed.as_millis();
+    let pv: Vec<String> = info
+        .get_pv()
+        .iter()
+        .map(|chess_move| chess_move.get_uci())
+        .collect();
+
+    format!(
+        "info depth {} score {} nodes {} nps {} time {} pv {}",
+        info.get_depth(),
+        score,
+        info.get_nodes(),
+        info.get_nodes() as u128 * 1000 / milliseconds.max(1),
+        milliseconds,
+        pv.join(" ")
+    )
+}
@@ -0,0 +1,463 @@
+use crate::{color::Color, kind::Kind, piece::Piece};
+
+/// The steps of a knight, as rank and file offsets.
+const KNIGHT_STEPS: [(i32, i32); 8] = [
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+];
+
+/// The steps of a king, as rank and file offsets.
+const KING_STEPS: [(i32, i32); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+
+/// The directions of the sliding pieces, as rank and file offsets. The first four
+/// go towards higher square indices, the last four towards lower ones.
+const DIRECTIONS: [(i32, i32); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (1, -1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (-1, 1),
+];
+
+/// The indices in `DIRECTIONS` of the diagonal directions.
+const DIAGONALS: [usize; 4] = [1, 3, 5, 7];
+
+/// The indices in `DIRECTIONS` of the orthogonal directions.
+const ORTHOGONALS: [usize; 4] = [0, 2, 4, 6];
+
+/// The squares attacked by a knight standing on each square.
+const KNIGHT_ATTACKS: [u64; 64] = compute_step_attacks(&KNIGHT_STEPS);
+
+/// The squares attacked by a king standing on each square.
+const KING_ATTACKS: [u64; 64] = compute_step_attacks(&KING_STEPS);
+
+/// The squares attacked by a white pawn, then by a black pawn, standing on each square.
+const PAWN_ATTACKS: [[u64; 64]; 2] = [
+    compute_step_attacks(&[(1, -1), (1, 1)]),
+    compute_step_attacks(&[(-1, -1), (-1, 1)]),
+];
+
+/// The squares seen from each square in each direction on an empty board.
+const RAYS: [[u64; 64]; 8] = compute_rays();
+
+/// Computes the squares reached by one of the given steps from each square.
+///
+/// # Arguments
+///
+/// * `steps` - The rank and file offsets of the steps.
+///
+/// # Returns
+///
+/// An array containing the bitboard of the reached squares for each square.
+const fn compute_step_attacks(steps: &[(i32, i32)]) -> [u64; 64] {
+    let mut attacks: [u64; 64] = [0; 64];
+    let mut square: usize = 0;
+
+    while square < 64 {
+        let mut index: usize = 0;
+        while index < steps.len() {
+            let rank: i32 = (square / 8) as i32 + steps[index].0;
+            let file: i32 = (square % 8) as i32 + steps[index].1;
+            if rank >= 0 && rank < 8 && file >= 0 && file < 8 {
+                attacks[square] |= 1 << (rank * 8 + file);
+            }
+            index += 1;
+        }
+        square += 1;
+    }
+    attacks
+}
+
+/// Computes the squares seen from each square in each direction on an empty board.
+///
+/// # Returns
+///
+/// An array containing, for each direction, the bitboard of the ray from each square.
+const fn compute_rays() -> [[u64; 64]; 8] {
+    let mut rays: [[u64; 64]; 8] = [[0; 64]; 8];
+    let mut direction: usize = 0;
+
+    while direction < 8 {
+        let mut square: usize = 0;
+        while square < 64 {
+            let mut rank: i32 = (square / 8) as i32 + DIRECTIONS[direction].0;
+            let mut file: i32 = (square % 8) as i32 + DIRECTIONS[direction].1;
+            while rank >= 0 && rank < 8 && file >= 0 && file < 8 {
+                rays[direction][square] |= 1 << (rank * 8 + file);
+                rank += DIRECTIONS[direction].0;
+                file += DIRECTIONS[direction].1;
+            }
+            square += 1;
+        }
+        direction += 1;
+    }
+    rays
+}
+
+/// Gets the squares attacked from a square along a direction, stopping at the
+/// first occupied square, which is included.
+///
+/// # Arguments
+///
+/// * `square` - The square of the sliding piece.
+/// * `occupied` - The bitboard of every occupied square.
+/// * `direction` - The index of the direction in `DIRECTIONS`.
+///
+/// # Returns
+///
+/// The bitboard of the attacked squares.
+fn get_ray_attacks(square: usize, occupied: u64, direction: usize) -> u64 {
+    let ray: u64 = RAYS[direction][square];
+    let blockers: u64 = ray & occupied;
+
+    if blockers == 0 {
+        return ray;
+    }
+    let blocker: usize = if direction < 4 {
+        blockers.trailing_zeros() as usize
+    } else {
+        63 - blockers.leading_zeros() as usize
+    };
+    ray ^ RAYS[direction][blocker]
+}
+
+/// Gets the squares attacked by a knight.
+///
+/// # Arguments
+///
+/// * `square` - The square of the knight.
+///
+/// # Returns
+///
+/// The bitboard of the attacked squares.
+pub fn get_knight_attacks(square: usize) -> u64 {
+    KNIGHT_ATTACKS[square]
+}
+
+/// Gets the squares attacked by a king.
+///
+/// # Arguments
+///
+/// * `square` - The square of the king.
+///
+/// # Returns
+///
+/// The bitboard of the attacked squares.
+pub fn get_king_attacks(square: usize) -> u64 {
+    KING_ATTACKS[square]
+}
+
+/// Gets the squares attacked by a pawn, whether or not a piece stands on them.
+///
+/// # Arguments
+///
+/// * `square` - The square of the pawn.
+/// * `color` - The color of the pawn.
+///
+/// # Returns
+///
+/// The bitboard of the attacked squares.
+pub fn get_pawn_attacks(square: usize, color: Color) -> u64 {
+    PAWN_ATTACKS[get_color_index(color)][square]
+}
+
+/// Gets the squares attacked by a bishop.
+///
+/// # Arguments
+///
+/// * `square` - The square of the bishop.
+/// * `occupied` - The bitboard of every occupied square.
+///
+/// # Returns
+///
+/// The bitboard of the attacked squares, the first piece met in each direction included.
+pub fn get_bishop_attacks(square: usize, occupied: u64) -> u64 {
+    DIAGONALS.iter().fold(0, |attacks, direction| {
+        attacks | get_ray_attacks(square, occupied, *direction)
+    })
+}
+
+/// Gets the squares attacked by a rook.
+///
+/// # Arguments
+///
+/// * `square` - The square of the rook.
+/// * `occupied` - The bitboard of every occupied square.
+///
+/// # Returns
+///
+/// The bitboard of the attacked squares, the first piece met in each direction included.
+pub fn get_rook_attacks(square: usize, occupied: u64) -> u64 {
+    ORTHOGONALS.iter().fold(0, |attacks, direction| {
+        attacks | get_ray_attacks(square, occupied, *direction)
+    })
+}
+
+/// Gets the squares attacked by a queen.
+///
+/// # Arguments
+///
+/// * `square` - The square of the queen.
+/// * `occupied` - The bitboard of every occupied square.
+///
+/// # Returns
+///
+/// The bitboard of the attacked squares, the first piece met in each direction included.
+pub fn get_queen_attacks(square: usize, occupied: u64) -> u64 {
+    get_bishop_attacks(square, occupied) | get_rook_attacks(square, occupied)
+}
+
+/// Gets the squares of a bitboard, from the lowest index to the highest.
+///
+/// # Arguments
+///
+/// * `bitboard` - The bitboard to read.
+///
+/// # Returns
+///
+/// An iterator over the indices of the set squares.
+pub fn get_squares(bitboard: u64) -> impl Iterator<Item = usize> {
+    let mut remaining: u64 = bitboard;
+    std::iter::from_fn(move || {
+        if remaining == 0 {
+            None
+        } else {
+            let square: usize = remaining.trailing_zeros() as usize;
+            remaining &= remaining - 1;
+            Some(square)
+        }
+    })
+}
+
+/// Gets the index of a color in the color bitboards.
+///
+/// # Arguments
+///
+/// * `color` - The color.
+///
+/// # Returns
+///
+/// 0 for white and 1 for black.
+fn get_color_index(color: Color) -> usize {
+    match color {
+        Color::White => 0,
+        Color::Black => 1,
+    }
+}
+
+/// Gets the index of a kind in the piece bitboards.
+///
+/// # Arguments
+///
+/// * `kind` - The kind of the piece.
+///
+/// # Returns
+///
+/// An `Option<usize>` containing the index, or `None` for `Kind::None`.
+fn get_kind_index(kind: Kind) -> Option<usize> {
+    match kind {
+        Kind::None => None,
+        Kind::Pawn => Some(0),
+        Kind::Knight => Some(1),
+        Kind::Bishop => Some(2),
+        Kind::Rook => Some(3),
+        Kind::Queen => Some(4),
+        Kind::King => Some(5),
+    }
+}
+
+/// The kinds of pieces in the order of the piece bitboards.
+const KINDS: [Kind; 6] = [
+    Kind::Pawn,
+    Kind::Knight,
+    Kind::Bishop,
+    Kind::Rook,
+    Kind::Queen,
+    Kind::King,
+];
+
+/// Represents the placement of the pieces as one 64-bit set of squares per kind
+/// and per color, where bit 0 is `a1` and bit 63 is `h8`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bitboards {
+    /// The squares of the pieces of each kind, from pawns to kings.
+    pieces: [u64; 6],
+    /// The squares of the white pieces, then of the black pieces.
+    colors: [u64; 2],
+}
+
+impl Default for Bitboards {
+    fn default() -> Self {
+        Bitboards::new()
+    }
+}
+
+impl Bitboards {
+    /// Creates bitboards for an empty board.
+    ///
+    /// # Returns
+    ///
+    /// A new `Bitboards` instance without any piece.
+    pub fn new() -> Bitboards {
+        Bitboards {
+            pieces: [0; 6],
+            colors: [0; 2],
+        }
+    }
+
+    /// Gets the squares occupied by any piece.
+    ///
+    /// # Returns
+    ///
+    /// The bitboard of the occupied squares.
+    pub fn get_occupied(&self) -> u64 {
+        self.colors[0] | self.colors[1]
+    }
+
+    /// Gets the squares occupied by the pieces of a color.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color of the pieces.
+    ///
+    /// # Returns
+    ///
+    /// The bitboard of the squares of that color.
+    pub fn get_color(&self, color: Color) -> u64 {
+        self.colors[get_color_index(color)]
+    }
+
+    /// Gets the squares occupied by the pieces of a kind and a color.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of the pieces.
+    /// * `color` - The color of the pieces.
+    ///
+    /// # Returns
+    ///
+    /// The bitboard of the squares of those pieces, empty for `Kind::None`.
+    pub fn get_pieces(&self, kind: Kind, color: Color) -> u64 {
+        match get_kind_index(kind) {
+            Some(index) => self.pieces[index] & self.get_color(color),
+            None => 0,
+        }
+    }
+
+    /// Gets the piece standing on a square.
+    ///
+    /// # Arguments
+    ///
+    /// * `square` - The index of the square.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Piece>` containing the piece, or `None` if the square is empty.
+    pub fn get_piece(&self, square: usize) -> Option<Piece> {
+        let mask: u64 = 1 << square;
+        let color: Color = if self.colors[0] & mask != 0 {
+            Color::White
+        } else if self.colors[1] & mask != 0 {
+            Color::Black
+        } else {
+            return None;
+        };
+
+        KINDS
+            .iter()
+            .zip(self.pieces.iter())
+            .find(|(_, pieces)| **pieces & mask != 0)
+            .map(|(kind, _)| Piece::new(*kind, color))
+    }
+
+    /// Puts a piece on a square, replacing whatever stood there.
+    ///
+    /// # Arguments
+    ///
+    /// * `square` - The index of the square.
+    /// * `piece` - The piece to put, or `Piece::none()` to empty the square.
+    pub fn set_piece(&mut self, square: usize, piece: Piece) {
+        let mask: u64 = 1 << square;
+        for bitboard in self.pieces.iter_mut().chain(self.colors.iter_mut()) {
+            *bitboard &= !mask;
+        }
+
+        if let Some(index) = get_kind_index(piece.get_kind()) {
+            self.pieces[index] |= mask;
+            self.colors[get_color_index(piece.get_color())] |= mask;
+        }
+    }
+
+    /// Gets the square of the king of a color.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color of the king.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<usize>` containing the square, or `None` if there is no such king.
+    pub fn get_king_square(&self, color: Color) -> Option<usize> {
+        let kings: u64 = self.get_pieces(Kind::King, color);
+        if kings == 0 {
+            None
+        } else {
+            Some(kings.trailing_zeros() as usize)
+        }
+    }
+
+    /// Gets the pieces of a color attacking a square, given the occupied squares.
+    ///
+    /// # Arguments
+    ///
+    /// * `square` - The index of the attacked square.
+    /// * `color` - The color of the attacking pieces.
+    /// * `occupied` - The bitboard of the squares blocking the sliding pieces.
+    ///
+    /// # Returns
+    ///
+    /// The bitboard of the squares of the attacking pieces.
+    pub fn get_attackers(&self, square: usize, color: Color, occupied: u64) -> u64 {
+        let bishops: u64 =
+            self.get_pieces(Kind::Bishop, color) | self.get_pieces(Kind::Queen, color);
+        let rooks: u64 = self.get_pieces(Kind::Rook, color) | self.get_pieces(Kind::Queen, color);
+
+        (get_pawn_attacks(square, !color) & self.get_pieces(Kind::Pawn, color))
+            | (get_knight_attacks(square) & self.get_pieces(Kind::Knight, color))
+            | (get_king_attacks(square) & self.get_pieces(Kind::King, color))
+            | (get_bishop_attacks(square, occupied) & bishops)
+            | (get_rook_attacks(square, occupied) & rooks)
+    }
+
+    /// Checks if a square is attacked by the pieces of a color.
+    ///
+    /// # Arguments
+    ///
+    /// * `square` - The index of the square.
+    /// * `color` - The color of the attacking pieces.
+    ///
+    /// # Returns
+    ///
+    /// `true` if at least one piece of that color attacks the square, `false` otherwise.
+    pub fn is_attacked(&self, square: usize, color: Color) -> bool {
+        self.get_attackers(square, color, self.get_occupied()) != 0
+    }
+}
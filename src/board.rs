@@ -1,14 +1,67 @@
-use web_sys;
+use std::{sync::Arc, time::Duration};
 
-use yew::prelude::*;
+use crate::{
+    bitboard::Bitboards,
+    castling::CastlingRights,
+    cell::Cell,
+    clock::{Clock, SystemTimeSource, TimeControl, TimeSource},
+    color::Color,
+    event::GameEvent,
+    fen::{FenError, STARTING_FEN},
+    kind::Kind,
+    log::log,
+    moves::{Move, MoveError},
+    outcome::{GameResult, Outcome, Termination},
+    pgn::PgnTags,
+    piece::Piece,
+    position::Position,
+    setup::{get_castling_rook_squares, Setup},
+    shift::Shift,
+    zobrist::{get_castling_key, get_piece_key, get_turn_key},
+};
 
-use crate::{cell::Cell, color::Color, kind::Kind, piece::Piece, position::Position, shift::Shift};
+/// Represents a move played on the board with everything needed to take it back.
+#[derive(Clone)]
+struct MoveRecord {
+    /// The move that was played.
+    chess_move: Move,
+    /// The castling rights before the move.
+    castling_rights: CastlingRights,
+    /// The en passant square before the move.
+    en_passant: Option<Position>,
+    /// The halfmove clock before the move.
+    halfmove_clock: u32,
+    /// The fullmove number before the move.
+    fullmove_number: u32,
+    /// The white score before the move.
+    white_score: u8,
+    /// The black score before the move.
+    black_score: u8,
+    /// The outcome of the game before the move.
+    outcome: Option<Outcome>,
+    /// The length of the position history before the move.
+    position_count: usize,
+    /// The player whose draw offer was pending before the move, if any.
+    draw_offer: Option<Color>,
+    /// The number of events recorded before the move.
+    event_count: usize,
+}
 
 /// Represents the game board.
 #[derive(Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "crate::save::GameRecord", try_from = "crate::save::GameRecord")
+)]
 pub struct Board {
     /// A 2D vector of cells representing the board.
     board: Vec<Vec<Cell>>,
+    /// The placement of the pieces as bitboards, kept in step with the cells.
+    bitboards: Bitboards,
+    /// The Zobrist key of the placement, the side to move and the castling rights,
+    /// updated along with them.
+    zobrist: u64,
     /// The size of the board (typically 8 for an 8x8 board).
     size: usize,
     /// The currently selected piece, if any.
@@ -17,15 +70,54 @@ pub struct Board {
     shift: Shift,
     /// The color of the player whose turn it is.
     color_turn: Color,
-    /// Indicates whether the game has ended.
-    is_end: bool,
+    /// The way the game has ended, or `None` while it is still being played.
+    outcome: Option<Outcome>,
     /// A vector of strings representing the move notations.
     notations: Vec<String>,
     /// Points scored by the white player.
     white_score: u8,
     /// Points scored by the black player.
     black_score: u8,
+    /// The castling rights still available to both players.
+    castling_rights: CastlingRights,
+    /// The square a pawn can capture en passant on the next move, if any.
+    en_passant: Option<Position>,
+    /// The position of a pawn waiting for the player to choose its promotion, if any.
+    pending_promotion: Option<Position>,
+    /// Indicates whether pawns are promoted to a queen without asking.
+    auto_queen: bool,
+    /// Indicates whether the draws which need no claim end the game by themselves.
+    automatic_draws: bool,
+    /// The number of half moves since the last capture or pawn move.
+    halfmove_clock: u32,
+    /// The number of the current full move, incremented after each black move.
+    fullmove_number: u32,
+    /// The keys of every position reached in the game, used to detect repetitions.
+    position_history: Vec<u64>,
+    /// The FEN of the position the game started from.
+    initial_fen: String,
+    /// The PGN tags describing the game.
+    pgn_tags: PgnTags,
+    /// The moves played so far, the last one on top, used to take them back.
+    undo_stack: Vec<MoveRecord>,
+    /// The moves taken back, the next one to replay on top.
+    redo_stack: Vec<MoveRecord>,
+    /// The clocks of the players, or `None` for a game without time control.
+    clock: Option<Clock>,
+    /// The source of the time read by the clocks.
+    time_source: Arc<dyn TimeSource>,
+    /// The player whose draw offer waits for an answer, if any.
+    draw_offer: Option<Color>,
+    /// The events of the game, each with the number of half moves played before it.
+    events: Vec<(usize, GameEvent)>,
 }
+
+impl Default for Board {
+    fn default() -> Self {
+        Board::new()
+    }
+}
+
 impl Board {
     /// Creates a new `Board` instance with an 8x8 grid of cells.
     ///
@@ -54,14 +146,32 @@ impl Board {
 
         Board {
             board,
+            bitboards: Bitboards::new(),
+            zobrist: get_turn_key(Color::White),
             size,
             selected_piece: None,
             shift: Shift::new(),
             color_turn: Color::White,
-            is_end: false,
+            outcome: None,
             notations: Vec::new(),
             white_score: 0,
             black_score: 0,
+            castling_rights: CastlingRights::none(),
+            en_passant: None,
+            pending_promotion: None,
+            auto_queen: false,
+            automatic_draws: true,
+            halfmove_clock: 0,
+            fullmove_number: 1,
+            position_history: Vec::new(),
+            initial_fen: STARTING_FEN.to_string(),
+            pgn_tags: PgnTags::new(),
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            clock: None,
+            time_source: Arc::new(SystemTimeSource),
+            draw_offer: None,
+            events: Vec::new(),
         }
     }
 
@@ -78,6 +188,30 @@ impl Board {
         &self.board[position.get_row()][position.get_col()]
     }
 
+    /// Returns the placement of the pieces as bitboards.
+    ///
+    /// # Returns
+    ///
+    /// A copy of the `Bitboards` of the board.
+    pub fn get_bitboards(&self) -> Bitboards {
+        self.bitboards
+    }
+
+    /// Returns the part of the position the rules of movement depend on.
+    ///
+    /// # Returns
+    ///
+    /// A `Setup` with the placement, the side to move, the castling rights and the
+    /// en passant square.
+    pub fn get_setup(&self) -> Setup {
+        Setup::new(
+            self.bitboards,
+            self.color_turn,
+            self.castling_rights,
+            self.en_passant,
+        )
+    }
+
     /// Returns the size of the board.
     ///
     /// # Returns
@@ -87,538 +221,1686 @@ impl Board {
         self.size
     }
 
-    /// Gets the position of the king of the given color.
+    /// Returns the color of the player whose turn it is.
     ///
-    /// # Arguments
+    /// # Returns
     ///
-    /// * `color` - The color of the king to find.
+    /// The color of the side to move.
+    pub fn get_color_turn(&self) -> Color {
+        self.color_turn
+    }
+
+    /// Returns the way the game has ended.
     ///
     /// # Returns
     ///
-    /// An `Option<Position>` containing the position of the king if found, or `None` if not found.
-    fn get_position_king(&self, color: Color) -> Option<Position> {
-        for row in 0..self.size {
-            for col in 0..self.size {
-                if let Some(piece) = self.board[row][col].get_piece() {
-                    if piece.get_kind() == Kind::King && piece.get_color() == color {
-                        return Some(Position::new(row, col));
-                    }
-                }
-            }
-        }
-        None
+    /// An `Option<Outcome>` containing the outcome, or `None` while the game is being played.
+    pub fn get_outcome(&self) -> Option<Outcome> {
+        self.outcome
     }
 
-    /// Initializes the board with the standard chess starting position.
+    /// Checks if the game has ended.
     ///
     /// # Returns
     ///
-    /// The `Board` instance initialized with the standard chess starting position.
-    pub fn initialize(mut self) -> Self {
-        let fen_init: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR";
-        self.load_from_fen(fen_init);
-
-        self
+    /// `true` if the game has ended, `false` otherwise.
+    pub fn is_end(&self) -> bool {
+        self.outcome.is_some()
     }
 
-    /// Loads the board state from a FEN (Forsyth-Edwards Notation) string.
+    /// Returns the result of the game.
     ///
-    /// # Arguments
+    /// # Returns
     ///
-    /// * `fen` - A string slice representing the board state in FEN format.
-    fn load_from_fen(&mut self, fen: &str) -> () {
-        let mut row: usize = 0;
-        let mut col: usize = 0;
+    /// The `GameResult` of the game, `GameResult::Ongoing` while it is being played.
+    pub fn get_result(&self) -> GameResult {
+        self.outcome
+            .map_or(GameResult::Ongoing, |outcome| outcome.get_result())
+    }
 
-        for char in fen.chars() {
-            if char.is_digit(10) {
-                col += char.to_digit(10).unwrap() as usize;
-            }
-            if char.is_alphabetic() {
-                self.board[row][col] = Cell::create(
-                    Piece::from_symbol(char),
-                    self.board[row][col].get_color(),
-                    Position::new(row, col),
-                );
-                col += 1;
-            }
-            if char == '/' {
-                row += 1;
-                col = 0;
-            }
-        }
+    /// Returns the reason the game has ended.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Termination>` containing the reason, or `None` while the game is
+    /// being played.
+    pub fn get_termination(&self) -> Option<Termination> {
+        self.outcome.map(|outcome| outcome.get_termination())
     }
 
-    /// Handles a click event on a cell.
+    /// Ends the game because a player has left it. Its opponent wins.
     ///
     /// # Arguments
     ///
-    /// * `cell` - The cell that was clicked.
-    pub fn handle_click(&mut self, cell: Cell) -> () {
-        if !self.is_end {
-            if let Some(selected_pos) = self.selected_piece {
-                let (new_position_row, new_position_col): (usize, usize) = cell.get_position();
-                let new_position: Position = Position::new(new_position_row, new_position_col);
-                if self.is_valid_move(selected_pos, new_position) {
-                    self.move_piece(selected_pos, new_position);
-                    self.next_turn();
-                } else {
-                    self.handle_selection(cell);
-                }
-            } else {
-                self.handle_selection(cell);
-            }
-        } else {
-            self.handle_selection(cell);
+    /// * `color` - The color of the player who left.
+    pub fn abandon(&mut self, color: Color) {
+        if !self.is_end() {
+            self.end_game(Outcome::Abandonment(!color));
         }
     }
 
-    /// Advances to the next turn.
-    fn next_turn(&mut self) -> () {
-        if !self.is_end {
-            self.color_turn = !self.color_turn;
-            web_sys::console::log_1(&format!("Next turn").into());
-        }
+    /// Returns the events of the game, such as draw offers and resignations.
+    ///
+    /// # Returns
+    ///
+    /// A slice containing each event with the number of half moves played before it.
+    pub fn get_events(&self) -> &[(usize, GameEvent)] {
+        &self.events
     }
 
-    /// Handles the selection of a cell.
+    /// Returns the player whose draw offer waits for an answer.
     ///
-    /// # Arguments
+    /// # Returns
     ///
-    /// * `cell` - The cell that was selected.
-    fn handle_selection(&mut self, cell: Cell) -> () {
-        let (row, col) = cell.get_position();
-        let position: Position = Position::new(row, col);
-        if let Some(piece) = self.get_cell(position).get_piece() {
-            if piece.get_kind() != Kind::None && piece.get_color() == self.color_turn {
-                self.select_new_piece(cell);
-            } else {
-                self.selected_piece = None;
-            }
-        } else {
-            self.selected_piece = None;
-        }
+    /// An `Option<Color>` containing the player who offered a draw, or `None` if no
+    /// offer is pending.
+    pub fn get_draw_offer(&self) -> Option<Color> {
+        self.draw_offer
     }
 
-    /// Selects a new piece on the board.
+    /// Ends the game because a player resigns. Its opponent wins.
     ///
     /// # Arguments
     ///
-    /// * `cell` - The cell containing the piece to select.
-    fn select_new_piece(&mut self, cell: Cell) -> () {
-        let (row, col) = cell.get_position();
-        let position: Position = Position::new(row, col);
-        self.selected_piece = Some(position);
-        self.shift.set_possible_moves(self.clone(), cell);
-
-        self.clear();
-        self.display_possible_moves();
+    /// * `color` - The color of the player who resigns.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the player has resigned, `false` if the game was already over.
+    pub fn resign(&mut self, color: Color) -> bool {
+        if self.check_flag() || self.is_end() {
+            return false;
+        }
+        self.record_event(GameEvent::Resigned(color));
+        self.draw_offer = None;
+        self.end_game(Outcome::Resignation(!color));
+        true
     }
 
-    /// Moves a piece from one position to another on the board.
+    /// Offers a draw to the opponent, who can accept or decline it. Playing a move
+    /// instead declines it.
     ///
     /// # Arguments
     ///
-    /// * `from` - The starting position of the piece.
-    /// * `to` - The ending position of the piece.
-    fn move_piece(&mut self, from: Position, to: Position) -> () {
-        self.add_notation(self.get_chess_notation(from, to));
-        let piece: Piece = self.board[from.get_row()][from.get_col()]
-            .get_piece()
-            .unwrap()
-            .clone();
-
-        if let Some(piece_captured) = self.board[to.get_row()][to.get_col()].get_piece() {
-            self.update_points(piece_captured);
+    /// * `color` - The color of the player offering the draw.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the draw has been offered, `false` if the game is over or an offer
+    /// is already pending.
+    pub fn offer_draw(&mut self, color: Color) -> bool {
+        if self.check_flag() || self.is_end() || self.draw_offer.is_some() {
+            return false;
         }
-
-        self.board[to.get_row()][to.get_col()].set_piece(piece);
-        self.board[from.get_row()][from.get_col()].set_piece(Piece::none());
-        self.selected_piece = None;
-
-        self.clear();
-        self.check_king_status();
-
-        self.check_promote(to);
+        self.record_event(GameEvent::DrawOffered(color));
+        self.draw_offer = Some(color);
+        true
     }
 
-    /// Checks if a move from one position to another is valid.
+    /// Accepts the pending draw offer of the opponent, which ends the game as a
+    /// draw.
     ///
     /// # Arguments
     ///
-    /// * `from` - The starting position of the piece.
-    /// * `to` - The ending position of the piece.
+    /// * `color` - The color of the player accepting the draw.
     ///
     /// # Returns
     ///
-    /// `true` if the move is valid, `false` otherwise.
-    fn is_valid_move(&self, from: Position, to: Position) -> bool {
-        self.get_cell(from).get_piece().is_some()
-            && self.get_cell(from).get_piece_kind() != Kind::None
-            && self.shift.get_possible_moves().contains(&to)
-    }
-
-    /// Checks the status of the king and updates the board if the king is in check.
-    fn check_king_status(&mut self) -> () {
-        let opposant_color: Color = !self.color_turn;
-        let position_king: Option<Position> = self.get_position_king(opposant_color);
-
-        if let Some(position_king) = position_king {
-            if self.is_king_in_check(position_king) {
-                self.display_king_in_check(position_king);
-
-                if self.is_king_in_check_mate(position_king) {
-                    web_sys::console::log_1(&"King is in check mate".into());
-                    self.win();
-                    return;
-                }
-
-                web_sys::console::log_1(&"King is in check".into());
-            }
+    /// `true` if the draw has been accepted, `false` if the game is over or the
+    /// opponent has no pending offer.
+    pub fn accept_draw(&mut self, color: Color) -> bool {
+        if self.check_flag() || self.is_end() || self.draw_offer != Some(!color) {
+            return false;
         }
+        self.draw_offer = None;
+        self.record_event(GameEvent::DrawAccepted(color));
+        self.end_game(Outcome::Agreement);
+        true
     }
 
-    /// Checks if the king is in check at the given position.
+    /// Declines the pending draw offer of the opponent.
     ///
     /// # Arguments
     ///
-    /// * `position` - The position of the king to check.
+    /// * `color` - The color of the player declining the draw.
     ///
     /// # Returns
     ///
-    /// `true` if the king is in check, `false` otherwise.
-    fn is_king_in_check(&self, position: Position) -> bool {
-        self.clone()
-            .shift
-            .is_in_check(self, position, !self.color_turn)
+    /// `true` if the draw has been declined, `false` if the opponent has no pending
+    /// offer.
+    pub fn decline_draw(&mut self, color: Color) -> bool {
+        if self.draw_offer != Some(!color) {
+            return false;
+        }
+        self.draw_offer = None;
+        self.record_event(GameEvent::DrawDeclined(color));
+        true
     }
 
-    /// Checks if the king is in checkmate at the given position.
+    /// Records an event after the moves played so far.
     ///
     /// # Arguments
     ///
-    /// * `position` - The position of the king to check.
+    /// * `event` - The event to record.
+    fn record_event(&mut self, event: GameEvent) {
+        self.events.push((self.notations.len(), event));
+    }
+
+    /// Returns the notations of the moves played so far.
     ///
     /// # Returns
     ///
-    /// `true` if the king is in checkmate, `false` otherwise.
-    fn is_king_in_check_mate(&self, position: Position) -> bool {
-        let king_moves: [(isize, isize); 8] = [
-            (-1, -1),
-            (-1, 0),
-            (-1, 1),
-            (0, -1),
-            (0, 1),
-            (1, -1),
-            (1, 0),
-            (1, 1),
-        ];
-
-        self.is_king_in_check(position)
-            && king_moves.iter().all(|(dx, dy)| {
-                let new_row: isize = position.get_row() as isize + dx;
-                let new_col: isize = position.get_col() as isize + dy;
-                if new_row >= 0
-                    && new_row < self.size as isize
-                    && new_col >= 0
-                    && new_col < self.size as isize
-                {
-                    let new_position: Position = Position::new(new_row as usize, new_col as usize);
-                    self.is_valid_move(position, new_position)
-                } else {
-                    true
-                }
-            })
+    /// A slice of strings containing each move in Standard Algebraic Notation.
+    pub fn get_notations(&self) -> &[String] {
+        &self.notations
     }
 
-    /// Checks if a pawn should be promoted and promotes it if necessary.
+    /// Returns the moves played so far, including a move waiting for its promotion.
     ///
-    /// # Arguments
+    /// # Returns
     ///
-    /// * `position` - The position of the pawn to check for promotion.
-    fn check_promote(&mut self, position: Position) {
-        if self.board[position.get_row()][position.get_col()].get_piece_kind() == Kind::Pawn {
-            let color: Color = self.board[position.get_row()][position.get_col()].get_piece_color();
-            if position.get_row() == 0 && color == Color::White {
-                self.promote(position);
-            } else if position.get_row() == self.size - 1 && color == Color::Black {
-                self.promote(position);
-            }
-        }
+    /// A vector containing the moves from the initial position.
+    pub fn get_moves(&self) -> Vec<Move> {
+        self.undo_stack
+            .iter()
+            .map(|record| record.chess_move)
+            .collect()
     }
 
-    /// Promotes a pawn to a queen at the given position.
+    /// Returns the points scored by the white player.
     ///
-    /// # Arguments
+    /// # Returns
     ///
-    /// * `position` - The position of the pawn to promote.
-    fn promote(&mut self, position: Position) -> () {
-        self.board[position.get_row()][position.get_col()]
-            .set_piece(Piece::new(Kind::Queen, self.color_turn));
+    /// The value of the black pieces captured by white.
+    pub fn get_white_score(&self) -> u8 {
+        self.white_score
     }
 
-    /// Clears the selection and check status of all cells on the board.
-    fn clear(&mut self) -> () {
-        for r in 0..self.size {
-            for c in 0..self.size {
-                self.board[r][c].set_is_selected(false);
-                self.board[r][c].set_is_check(false);
-            }
-        }
+    /// Returns the points scored by the black player.
+    ///
+    /// # Returns
+    ///
+    /// The value of the white pieces captured by black.
+    pub fn get_black_score(&self) -> u8 {
+        self.black_score
     }
 
-    /// Displays the possible moves for the selected piece.
-    fn display_possible_moves(&mut self) -> () {
-        for pos in self.shift.get_possible_moves().iter() {
-            self.board[pos.get_row()][pos.get_col()].set_is_selected(true);
-        }
+    /// Returns the FEN of the position the game started from.
+    ///
+    /// # Returns
+    ///
+    /// A string slice containing the initial FEN.
+    pub fn get_initial_fen(&self) -> &str {
+        &self.initial_fen
     }
 
-    /// Displays the king in check by setting the `is_check` flag on the king's cell.
+    /// Returns the PGN tags describing the game.
     ///
-    /// # Arguments
+    /// # Returns
     ///
-    /// * `position` - The position of the king in check.
-    fn display_king_in_check(&mut self, position: Position) -> () {
-        self.board[position.get_row()][position.get_col()].set_is_check(true);
+    /// A reference to the `PgnTags` of the game.
+    pub fn get_pgn_tags(&self) -> &PgnTags {
+        &self.pgn_tags
     }
 
-    /// Converts a move from one position to another into standard chess notation.
+    /// Sets a PGN tag describing the game, such as `Event` or `White`.
     ///
     /// # Arguments
     ///
-    /// * `from` - The starting position of the piece.
-    /// * `to` - The ending position of the piece.
+    /// * `name` - The name of the tag.
+    /// * `value` - The value of the tag.
+    pub fn set_pgn_tag(&mut self, name: &str, value: &str) {
+        self.pgn_tags.set(name, value);
+    }
+
+    /// Returns the number of half moves since the last capture or pawn move.
     ///
     /// # Returns
     ///
-    /// A `String` representing the move in standard chess notation.
-    fn get_chess_notation(&self, from: Position, to: Position) -> String {
-        let is_attack: bool = self.get_cell(to).get_piece().is_some();
-        let piece_symbol: char = self.get_cell(from).get_piece().unwrap().get_symbol();
-        let position_king: Option<Position> = self.get_position_king(!self.color_turn);
-        let (row, col) = self.convert_index_to_notation(to);
-        let (is_check, is_check_mate) = if let Some(position_king) = position_king {
-            let is_check: bool = self.is_king_in_check(position_king);
-            let is_check_mate: bool = self.is_king_in_check_mate(position_king);
-            (is_check, is_check_mate)
-        } else {
-            (false, false)
-        };
+    /// The halfmove clock.
+    pub fn get_halfmove_clock(&self) -> u32 {
+        self.halfmove_clock
+    }
 
-        let symbol: char = if is_check_mate {
-            '#'
-        } else if is_check {
-            '+'
-        } else if is_attack {
-            'x'
-        } else {
-            ' '
-        };
-        web_sys::console::log_1(&format!("{}{}{}{}", symbol, piece_symbol, row, col).into());
-        format!("{}{}{}{}", symbol, piece_symbol, row, col)
+    /// Returns the number of the current full move.
+    ///
+    /// # Returns
+    ///
+    /// The fullmove number, starting at 1.
+    pub fn get_fullmove_number(&self) -> u32 {
+        self.fullmove_number
     }
 
-    /// Converts a board position to a chess notation index.
+    /// Returns the castling rights still available to both players.
     ///
-    /// # Arguments
+    /// # Returns
     ///
-    /// * `position` - The position on the board to convert.
+    /// The current `CastlingRights`.
+    pub fn get_castling_rights(&self) -> CastlingRights {
+        self.castling_rights
+    }
+
+    /// Returns the square a pawn can capture en passant on the next move.
     ///
     /// # Returns
     ///
-    /// A tuple containing the column as a character ('a' to 'h') and the row as a usize (1 to 8).
-    fn convert_index_to_notation(&self, position: Position) -> (char, usize) {
-        let char_index: char = match position.get_col() {
-            0 => 'a',
-            1 => 'b',
-            2 => 'c',
-            3 => 'd',
-            4 => 'e',
-            5 => 'f',
-            6 => 'g',
-            7 => 'h',
-            _ => '?',
-        };
-        let usize_index: usize = match position.get_row() {
-            0 => 8,
-            1 => 7,
-            2 => 6,
-            3 => 5,
-            4 => 4,
-            5 => 3,
-            6 => 2,
-            7 => 1,
-            _ => 0,
-        };
+    /// An `Option<Position>` containing the square skipped by a pawn that just moved
+    /// two squares, or `None` otherwise.
+    pub fn get_en_passant(&self) -> Option<Position> {
+        self.en_passant
+    }
 
-        (char_index, usize_index)
+    /// Returns the position of the pawn waiting for a promotion choice.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Position>` containing the position of the pawn, or `None` if no
+    /// promotion is pending.
+    pub fn get_pending_promotion(&self) -> Option<Position> {
+        self.pending_promotion
     }
 
-    /// Adds a move notation to the list of notations.
+    /// Returns whether pawns are promoted to a queen without asking.
+    ///
+    /// # Returns
+    ///
+    /// `true` if auto-queen is enabled, `false` otherwise.
+    pub fn get_auto_queen(&self) -> bool {
+        self.auto_queen
+    }
+
+    /// Sets whether pawns are promoted to a queen without asking.
     ///
     /// # Arguments
     ///
-    /// * `notation` - The notation of the move to add.
-    fn add_notation(&mut self, notation: String) -> () {
-        self.notations.push(notation);
+    /// * `auto_queen` - A boolean indicating whether auto-queen is enabled.
+    pub fn set_auto_queen(&mut self, auto_queen: bool) {
+        self.auto_queen = auto_queen;
     }
 
-    /// Updates the points for the player based on the captured piece.
+    /// Gets the clocks of the players.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<&Clock>` containing the clocks, or `None` for a game without time
+    /// control.
+    pub fn get_clock(&self) -> Option<&Clock> {
+        self.clock.as_ref()
+    }
+
+    /// Sets the time control of the game and starts the clock of the side to move,
+    /// or removes the clocks. A game with clocks cannot take moves back.
     ///
     /// # Arguments
     ///
-    /// * `piece` - The piece that was captured.
-    fn update_points(&mut self, piece: Piece) {
-        match piece.get_color() {
-            Color::White => {
-                if let Some(value) = piece.get_value() {
-                    self.black_score += value
+    /// * `time_control` - The time control, or `None` to play without clocks.
+    pub fn set_time_control(&mut self, time_control: Option<TimeControl>) {
+        match time_control {
+            Some(time_control) => {
+                self.pgn_tags.set("TimeControl", &time_control.to_pgn_tag());
+                let mut clock: Clock = Clock::new(time_control, self.color_turn);
+                if !self.is_end() {
+                    clock.resume(self.time_source.now_ms());
                 }
+                self.clock = Some(clock);
             }
-            Color::Black => {
-                if let Some(value) = piece.get_value() {
-                    self.white_score += value
+            None => {
+                if self.clock.take().is_some() {
+                    self.pgn_tags.set("TimeControl", "-");
                 }
             }
         }
     }
 
-    /// Ends the game and logs the end of the game.
-    fn win(&mut self) -> () {
-        self.is_end = true;
-        web_sys::console::log_1(&"End of the game".into());
+    /// Sets paused clocks with the times left to each player, such as the clocks of
+    /// a saved game once its moves have been replayed.
+    ///
+    /// # Arguments
+    ///
+    /// * `time_control` - The time control of the game.
+    /// * `white` - The time left to white.
+    /// * `black` - The time left to black.
+    pub fn restore_clock(&mut self, time_control: TimeControl, white: Duration, black: Duration) {
+        let white_moves: usize = self
+            .undo_stack
+            .iter()
+            .filter(|record| record.chess_move.get_piece().get_color() == Color::White)
+            .count();
+        let black_moves: usize = self.undo_stack.len() - white_moves;
+
+        self.pgn_tags.set("TimeControl", &time_control.to_pgn_tag());
+        self.clock = Some(Clock::restore(
+            time_control,
+            self.color_turn,
+            white,
+            black,
+            white_moves as u32,
+            black_moves as u32,
+        ));
     }
 
-    /// Renders the board as HTML.
+    /// Sets the source of the time read by the clocks.
     ///
     /// # Arguments
     ///
-    /// * `on_click` - A callback function to handle click events on the board cells.
+    /// * `time_source` - The source of the time, such as a `MockTimeSource` in tests.
+    pub fn set_time_source(&mut self, time_source: Arc<dyn TimeSource>) {
+        self.time_source = time_source;
+    }
+
+    /// Gets the source of the time read by the clocks.
     ///
     /// # Returns
     ///
-    /// An `Html` representation of the board.
-    pub fn render(&self, on_click: Callback<Position>) -> Html {
-        html! {
-            <div class={classes!("container")}>
-                {self.render_win_screen()}
-                <div class={classes!("container-board")}>
-                    {self.render_board(on_click.clone())}
-                </div>
-                <div class={classes!("container-data")}>
-                    {self.render_score(self.black_score, "score")}
-                    {self.render_notation()}
-                    {self.render_score(self.white_score, "score")}
-                </div>
-            </div>
-        }
+    /// A reference to the `TimeSource` of the board.
+    pub fn get_time_source(&self) -> &Arc<dyn TimeSource> {
+        &self.time_source
     }
 
-    /// Renders the win screen.
+    /// Gets the time left to a player.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color of the player.
     ///
     /// # Returns
     ///
-    /// An `Html` representation of the win screen.
-    fn render_win_screen(&self) -> Html {
-        html! {
-            <div class={classes!(if self.is_end { "win-screen" } else { "win-screen hidden" })}>
-                {match self.color_turn {
-                    Color::White => html! {
-                        <>
-                            <p class={classes!("win-screen-text", "win-screen-text-white")}>{"WHITE WON"}</p>
-                            <div class={classes!("win-screen-container", "win-screen-container-white")}></div>
-                        </>
-                    },
-                    Color::Black => html! {
-                        <>
-                            <p class={classes!("win-screen-text", "win-screen-text-black")}>{"BLACK WON"}</p>
-                            <div class={classes!("win-screen-container", "win-screen-container-black")}></div>
-                        </>
-                    },
-                }}
-            </div>
-        }
+    /// An `Option<Duration>` containing the time left, or `None` without clocks.
+    pub fn get_remaining_time(&self, color: Color) -> Option<Duration> {
+        let now: u64 = self.time_source.now_ms();
+        self.clock
+            .as_ref()
+            .map(|clock| clock.get_remaining(color, now))
     }
 
-    /// Renders the board.
+    /// Checks if the clocks are paused.
     ///
-    /// # Arguments
+    /// # Returns
     ///
-    /// * `on_click` - A callback function to handle click events on the board cells.
+    /// `true` if the game has clocks and none of them runs, `false` otherwise.
+    pub fn is_clock_paused(&self) -> bool {
+        self.clock.as_ref().is_some_and(Clock::is_paused)
+    }
+
+    /// Pauses the clocks. No move can be played until they are resumed.
+    pub fn pause_clock(&mut self) {
+        let now: u64 = self.time_source.now_ms();
+        if let Some(clock) = self.clock.as_mut() {
+            clock.pause(now);
+        }
+    }
+
+    /// Resumes the clocks of a game still being played.
+    pub fn resume_clock(&mut self) {
+        let now: u64 = self.time_source.now_ms();
+        if let Some(clock) = self.clock.as_mut().filter(|_| self.outcome.is_none()) {
+            clock.resume(now);
+        }
+    }
+
+    /// Ends the game if the player to move has run out of time. The opponent wins,
+    /// unless it has no material left to checkmate, which draws the game.
     ///
     /// # Returns
     ///
-    /// An `Html` representation of the board.
-    fn render_board(&self, on_click: Callback<Position>) -> Html {
-        html! {
-            <div class={classes!("board")}>
-                {for self.board.iter().enumerate().map(|(row_idx, row)| {
-                    html! {
-                        <div class="row">
-                            {for row.iter().enumerate().map(|(col_idx, cell)| {
-                                let on_click = {
-                                    let on_click = on_click.clone();
-                                    Callback::from(move |_| on_click.emit(Position::new(row_idx, col_idx)))
-                                };
-                                let cell_classes = classes!(
-                                    if cell.get_is_selected() { "cell-move" } else { "" },
-                                    if cell.get_is_check() { "cell-check" } else { "" },
-                                    if cell.get_color() == Color::White { "cell cell-white" } else { "cell cell-black" }
-                                );
-                                html! {
-                                    <div class={cell_classes} onclick={on_click}>
-                                        if cell.get_piece().is_some() {
-                                            <img src={cell.get_piece().unwrap().get_svg()} height="60px" />
-                                        }
-                                    </div>
-                                }
-                            })}
-                        </div>
-                    }
-                })}
-            </div>
+    /// `true` if the flag has fallen and ended the game, `false` otherwise.
+    pub fn check_flag(&mut self) -> bool {
+        let now: u64 = self.time_source.now_ms();
+        let flag: Option<Color> = match self.clock.as_ref() {
+            Some(clock) if self.outcome.is_none() => clock.get_flag(now),
+            _ => None,
+        };
+
+        match flag {
+            Some(color) if self.has_mating_material(!color) => {
+                self.end_game(Outcome::Timeout(!color));
+                true
+            }
+            Some(_) => {
+                self.end_game(Outcome::TimeoutVsInsufficientMaterial);
+                true
+            }
+            None => false,
         }
     }
 
-    /// Renders the score.
+    /// Gets the position of the king of the given color.
     ///
     /// # Arguments
     ///
-    /// * `score` - The score to display.
+    /// * `color` - The color of the king to find.
     ///
     /// # Returns
     ///
-    /// An `Html` representation of the score.
-    fn render_score(&self, score: u8, label: &str) -> Html {
-        html! {
-            <div class={classes!("score")}>
-                {format!("{} : {}", label, score)}
-            </div>
-        }
+    /// An `Option<Position>` containing the position of the king if found, or `None` if not found.
+    pub fn get_position_king(&self, color: Color) -> Option<Position> {
+        self.bitboards
+            .get_king_square(color)
+            .map(Position::from_square)
     }
 
-    /// Renders the notation.
+    /// Puts a piece on a position, keeping the cells and the bitboards in step.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// An `Html` representation of the notation.
-    fn render_notation(&self) -> Html {
-        html! {
-            <div class={classes!("notation")}>
-                {for self.notations.chunks(2).enumerate().map(|(index, chunk)| {
-                    let white_move = chunk.get(0).unwrap_or(&String::new()).clone();
-                    let black_move = chunk.get(1).unwrap_or(&String::new()).clone();
-                    let color_line  = classes!(if index % 2 == 0 { "notation-line notation-line-white" } else { "notation-line notation-line-black" });
-                    html! {
-                        <div class={color_line}>
-                            <div class={classes!("notation-column")}>{format!("{}",index+1)}</div>
-                            <div class={classes!("notation-column")}>{format!("{}",white_move)}</div>
-                            <div class={classes!("notation-column")}>{format!("{}",black_move)}</div>
-                        </div>
-                    }
-                })}
-            </div>
+    /// * `position` - The position to put the piece on.
+    /// * `piece` - The piece to put, or `Piece::none()` to empty the position.
+    fn set_piece_at(&mut self, position: Position, piece: Piece) {
+        let square: usize = position.get_square();
+        if let Some(piece_removed) = self.bitboards.get_piece(square) {
+            self.zobrist ^= get_piece_key(piece_removed, square);
         }
+        self.zobrist ^= get_piece_key(piece, square);
+
+        self.board[position.get_row()][position.get_col()].set_piece(piece);
+        self.bitboards.set_piece(square, piece);
+    }
+
+    /// Sets the color of the side to move and updates the Zobrist key.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color of the player whose turn it is.
+    fn set_color_turn(&mut self, color: Color) {
+        self.zobrist ^= get_turn_key(self.color_turn) ^ get_turn_key(color);
+        self.color_turn = color;
+    }
+
+    /// Sets the castling rights and updates the Zobrist key.
+    ///
+    /// # Arguments
+    ///
+    /// * `castling_rights` - The castling rights of both players.
+    fn set_castling_rights(&mut self, castling_rights: CastlingRights) {
+        self.zobrist ^= get_castling_key(self.castling_rights) ^ get_castling_key(castling_rights);
+        self.castling_rights = castling_rights;
+    }
+
+    /// Removes the castling rights lost by a move touching two positions.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The starting position of the piece.
+    /// * `to` - The ending position of the piece.
+    fn update_castling_rights(&mut self, from: Position, to: Position) {
+        let mut castling_rights: CastlingRights = self.castling_rights;
+        castling_rights.update(from);
+        castling_rights.update(to);
+        self.set_castling_rights(castling_rights);
+    }
+
+    /// Returns the Zobrist key of the position, following the Polyglot scheme so
+    /// that it can be used to look positions up in opening books.
+    ///
+    /// The en passant file is only part of the key when a pawn of the side to move
+    /// stands next to the pawn that has just moved two squares.
+    ///
+    /// # Returns
+    ///
+    /// The 64-bit key of the position.
+    pub fn hash(&self) -> u64 {
+        self.zobrist ^ self.get_setup().get_en_passant_key()
+    }
+
+    /// Initializes the board with the standard chess starting position.
+    ///
+    /// # Returns
+    ///
+    /// The `Board` instance initialized with the standard chess starting position.
+    pub fn initialize(self) -> Self {
+        Board::from_fen(STARTING_FEN).expect("the starting FEN is valid")
+    }
+
+    /// Creates a board from a FEN (Forsyth-Edwards Notation) string.
+    ///
+    /// # Arguments
+    ///
+    /// * `fen` - A string slice containing the six FEN fields: piece placement, side
+    ///   to move, castling rights, en passant square, halfmove clock and fullmove number.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the new `Board`, or a `FenError` describing what is wrong.
+    pub fn from_fen(fen: &str) -> Result<Board, FenError> {
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+        if fields.len() != 6 {
+            return Err(FenError::WrongFieldCount(fields.len()));
+        }
+
+        let mut board: Board = Board::new();
+        board.load_placement(fields[0])?;
+
+        board.set_color_turn(match fields[1] {
+            "w" => Color::White,
+            "b" => Color::Black,
+            field => return Err(FenError::InvalidSideToMove(field.to_string())),
+        });
+
+        let castling_rights: CastlingRights = board.parse_castling_rights(fields[2])?;
+        board.set_castling_rights(castling_rights);
+        board.en_passant = board.parse_en_passant(fields[3])?;
+
+        board.halfmove_clock = fields[4]
+            .parse()
+            .map_err(|_| FenError::InvalidHalfmoveClock(fields[4].to_string()))?;
+        board.fullmove_number = match fields[5].parse() {
+            Ok(number) if number > 0 => number,
+            _ => return Err(FenError::InvalidFullmoveNumber(fields[5].to_string())),
+        };
+
+        if let Some(position_king) = board.get_position_king(!board.color_turn) {
+            if Shift::new().is_in_check(&board, position_king, !board.color_turn) {
+                return Err(FenError::OpponentInCheck);
+            }
+        }
+
+        board.initial_fen = board.to_fen();
+        board.record_position();
+        Ok(board)
+    }
+
+    /// Exports the board as a FEN (Forsyth-Edwards Notation) string.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the six FEN fields of the current position.
+    pub fn to_fen(&self) -> String {
+        let mut placement: String = String::new();
+
+        for (row_idx, row) in self.board.iter().enumerate() {
+            let mut empty: u32 = 0;
+            for cell in row.iter() {
+                match cell.get_piece() {
+                    Some(piece) => {
+                        if empty > 0 {
+                            placement.push_str(&empty.to_string());
+                            empty = 0;
+                        }
+                        placement.push(piece.get_fen_symbol());
+                    }
+                    None => empty += 1,
+                }
+            }
+            if empty > 0 {
+                placement.push_str(&empty.to_string());
+            }
+            if row_idx + 1 < self.size {
+                placement.push('/');
+            }
+        }
+
+        let mut castling: String = String::new();
+        for (color, king_side, queen_side) in [(Color::White, 'K', 'Q'), (Color::Black, 'k', 'q')] {
+            if self.castling_rights.get_king_side(color) {
+                castling.push(king_side);
+            }
+            if self.castling_rights.get_queen_side(color) {
+                castling.push(queen_side);
+            }
+        }
+        if castling.is_empty() {
+            castling.push('-');
+        }
+
+        format!(
+            "{} {} {} {} {} {}",
+            placement,
+            match self.color_turn {
+                Color::White => 'w',
+                Color::Black => 'b',
+            },
+            castling,
+            self.en_passant
+                .map(|position| position.get_notation())
+                .unwrap_or("-".to_string()),
+            self.halfmove_clock,
+            self.fullmove_number
+        )
+    }
+
+    /// Places the pieces described by the first field of a FEN string.
+    ///
+    /// # Arguments
+    ///
+    /// * `placement` - A string slice containing the ranks separated by `/`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is an error if the placement is malformed or illegal.
+    fn load_placement(&mut self, placement: &str) -> Result<(), FenError> {
+        let ranks: Vec<&str> = placement.split('/').collect();
+        if ranks.len() != self.size {
+            return Err(FenError::WrongRankCount(ranks.len()));
+        }
+
+        for (row, rank) in ranks.iter().enumerate() {
+            let mut col: usize = 0;
+
+            for char in rank.chars() {
+                if let Some(empty) = char.to_digit(10).filter(|digit| (1..=8).contains(digit)) {
+                    col += empty as usize;
+                    continue;
+                }
+
+                let piece: Piece =
+                    Piece::try_from_symbol(char).ok_or(FenError::UnknownPiece(char))?;
+                if col >= self.size {
+                    col += 1;
+                    continue;
+                }
+                if piece.get_kind() == Kind::Pawn && (row == 0 || row == self.size - 1) {
+                    return Err(FenError::PawnOnBackRank);
+                }
+                self.set_piece_at(Position::new(row, col), piece);
+                col += 1;
+            }
+
+            if col != self.size {
+                return Err(FenError::WrongRankLength {
+                    rank: self.size - row,
+                    length: col,
+                });
+            }
+        }
+
+        for color in [Color::White, Color::Black] {
+            let kings: usize = self
+                .board
+                .iter()
+                .flatten()
+                .filter(|cell| {
+                    cell.get_piece().is_some()
+                        && cell.get_piece_kind() == Kind::King
+                        && cell.get_piece_color() == color
+                })
+                .count();
+            match kings {
+                0 => return Err(FenError::MissingKing(color)),
+                1 => {}
+                _ => return Err(FenError::TooManyKings(color)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parses the castling field of a FEN string and checks that the kings and rooks
+    /// are still on their starting squares.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - A string slice containing `-` or a combination of `KQkq`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `CastlingRights`, or a `FenError` if the field is invalid.
+    fn parse_castling_rights(&self, field: &str) -> Result<CastlingRights, FenError> {
+        let mut castling_rights: CastlingRights = CastlingRights::none();
+        let error = || FenError::InvalidCastling(field.to_string());
+
+        if field == "-" {
+            return Ok(castling_rights);
+        }
+
+        for char in field.chars() {
+            let (color, is_king_side): (Color, bool) = match char {
+                'K' => (Color::White, true),
+                'Q' => (Color::White, false),
+                'k' => (Color::Black, true),
+                'q' => (Color::Black, false),
+                _ => return Err(error()),
+            };
+            let row: usize = match color {
+                Color::White => self.size - 1,
+                Color::Black => 0,
+            };
+            let rook_col: usize = if is_king_side { self.size - 1 } else { 0 };
+            let is_in_place = |col: usize, kind: Kind| -> bool {
+                let cell: &Cell = &self.board[row][col];
+                cell.get_piece().is_some()
+                    && cell.get_piece_kind() == kind
+                    && cell.get_piece_color() == color
+            };
+
+            if !is_in_place(4, Kind::King) || !is_in_place(rook_col, Kind::Rook) {
+                return Err(error());
+            }
+            if is_king_side {
+                castling_rights.set_king_side(color, true);
+            } else {
+                castling_rights.set_queen_side(color, true);
+            }
+        }
+
+        Ok(castling_rights)
+    }
+
+    /// Parses the en passant field of a FEN string and checks that a pawn of the
+    /// opponent has just moved two squares past it.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - A string slice containing `-` or a square such as `e3`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the en passant square, or a `FenError` if the field is invalid.
+    fn parse_en_passant(&self, field: &str) -> Result<Option<Position>, FenError> {
+        if field == "-" {
+            return Ok(None);
+        }
+
+        let error = || FenError::InvalidEnPassant(field.to_string());
+        let position: Position = Position::from_notation(field).ok_or_else(error)?;
+        let (expected_row, pawn_row, start_row): (usize, usize, usize) = match self.color_turn {
+            Color::White => (2, 3, 1),
+            Color::Black => (5, 4, 6),
+        };
+        let pawn: &Cell = &self.board[pawn_row][position.get_col()];
+
+        if position.get_row() != expected_row
+            || self.get_cell(position).get_piece().is_some()
+            || self.board[start_row][position.get_col()]
+                .get_piece()
+                .is_some()
+            || pawn.get_piece().is_none()
+            || pawn.get_piece_kind() != Kind::Pawn
+            || pawn.get_piece_color() == self.color_turn
+        {
+            return Err(error());
+        }
+
+        Ok(Some(position))
+    }
+
+    /// Handles a click event on a cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - The cell that was clicked.
+    pub fn handle_click(&mut self, cell: Cell) {
+        if self.pending_promotion.is_some() || self.is_clock_paused() || self.check_flag() {
+            return;
+        }
+
+        if !self.is_end() {
+            if let Some(selected_pos) = self.selected_piece {
+                let (new_position_row, new_position_col): (usize, usize) = cell.get_position();
+                let new_position: Position = Position::new(new_position_row, new_position_col);
+                if self.is_valid_move(selected_pos, new_position) {
+                    self.move_piece(selected_pos, new_position);
+                    if self.pending_promotion.is_some() && self.auto_queen {
+                        self.set_promotion(Kind::Queen);
+                    }
+                    if self.pending_promotion.is_none() {
+                        self.end_turn();
+                    }
+                } else {
+                    self.handle_selection(cell);
+                }
+            } else {
+                self.handle_selection(cell);
+            }
+        } else {
+            self.handle_selection(cell);
+        }
+    }
+
+    /// Plays a legal move of the side to move, as if the player had clicked it and
+    /// chosen the promotion piece.
+    ///
+    /// # Arguments
+    ///
+    /// * `chess_move` - The move to play, with its promotion for a promotion.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is a `MoveError` if the move cannot be played.
+    pub fn make_move(&mut self, chess_move: Move) -> Result<(), MoveError> {
+        if self.check_flag() || self.is_end() {
+            return Err(MoveError::GameOver);
+        }
+        if self.pending_promotion.is_some() {
+            return Err(MoveError::PromotionPending);
+        }
+        if self.is_clock_paused() {
+            return Err(MoveError::ClockPaused);
+        }
+        if !self.is_legal(chess_move) {
+            return Err(MoveError::Illegal(chess_move));
+        }
+
+        self.move_piece(chess_move.get_from(), chess_move.get_to());
+        if let Some(kind) = chess_move.get_promotion() {
+            self.set_promotion(kind);
+        }
+        self.end_turn();
+        Ok(())
+    }
+
+    /// Checks if a move can be taken back.
+    ///
+    /// # Returns
+    ///
+    /// `true` if at least one move has been played in a game without clocks,
+    /// `false` otherwise.
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty() && self.clock.is_none()
+    }
+
+    /// Checks if a move taken back can be played again.
+    ///
+    /// # Returns
+    ///
+    /// `true` if at least one move has been taken back since the last new move,
+    /// `false` otherwise.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty() && self.clock.is_none()
+    }
+
+    /// Takes back the last move, restoring the exact position and game state that
+    /// preceded it. A move still waiting for its promotion piece is cancelled and
+    /// cannot be played again.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a move has been taken back, `false` if there was none.
+    pub fn undo(&mut self) -> bool {
+        if !self.can_undo() {
+            return false;
+        }
+        let record: MoveRecord = match self.undo_stack.pop() {
+            Some(record) => record,
+            None => return false,
+        };
+        let chess_move: Move = record.chess_move;
+        let (from, to): (Position, Position) = (chess_move.get_from(), chess_move.get_to());
+        let is_complete: bool = self.pending_promotion.is_none();
+
+        self.set_piece_at(to, Piece::none());
+        if let Some(piece_captured) = chess_move.get_piece_captured() {
+            self.set_piece_at(chess_move.get_captured_position(), piece_captured);
+        }
+        self.set_piece_at(from, chess_move.get_piece());
+        if chess_move.is_castling() {
+            self.move_castling_rook_back(from, to);
+        }
+
+        self.set_color_turn(chess_move.get_piece().get_color());
+        self.set_castling_rights(record.castling_rights);
+        self.en_passant = record.en_passant;
+        self.halfmove_clock = record.halfmove_clock;
+        self.fullmove_number = record.fullmove_number;
+        self.white_score = record.white_score;
+        self.black_score = record.black_score;
+        self.outcome = record.outcome;
+        self.position_history.truncate(record.position_count);
+        self.draw_offer = record.draw_offer;
+        self.events.truncate(record.event_count);
+        self.notations.pop();
+        self.pending_promotion = None;
+        self.selected_piece = None;
+
+        self.clear();
+        if let Some(position_king) = self.get_position_king(self.color_turn) {
+            if self.is_king_in_check(position_king) {
+                self.display_king_in_check(position_king);
+            }
+        }
+
+        if is_complete {
+            self.redo_stack.push(record);
+        }
+        true
+    }
+
+    /// Plays again the last move taken back.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a move has been played again, `false` if there was none.
+    pub fn redo(&mut self) -> bool {
+        if !self.can_redo() {
+            return false;
+        }
+        let record: MoveRecord = match self.redo_stack.pop() {
+            Some(record) => record,
+            None => return false,
+        };
+
+        let mut redo_stack: Vec<MoveRecord> = std::mem::take(&mut self.redo_stack);
+        let is_played: bool = self.make_move(record.chess_move).is_ok();
+        if !is_played {
+            redo_stack.push(record);
+        }
+        self.redo_stack = redo_stack;
+        is_played
+    }
+
+    /// Finishes the turn once the move and its promotion are complete: presses the
+    /// clock, looks for the end of the game and hands the move to the opponent.
+    fn end_turn(&mut self) {
+        let now: u64 = self.time_source.now_ms();
+        if let Some(clock) = self.clock.as_mut() {
+            // The flag was checked before the move, so it can only fall while the
+            // player chooses a promotion piece.
+            if !clock.press(now) && self.check_flag() {
+                return;
+            }
+        }
+        self.check_king_status();
+        self.next_turn();
+
+        if !self.is_end() {
+            self.record_position();
+            self.check_draw_status();
+        }
+    }
+
+    /// Advances to the next turn.
+    fn next_turn(&mut self) {
+        if !self.is_end() {
+            self.set_color_turn(!self.color_turn);
+            log("Next turn");
+        }
+    }
+
+    /// Handles the selection of a cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - The cell that was selected.
+    fn handle_selection(&mut self, cell: Cell) {
+        let (row, col) = cell.get_position();
+        let position: Position = Position::new(row, col);
+        if let Some(piece) = self.get_cell(position).get_piece() {
+            if piece.get_kind() != Kind::None && piece.get_color() == self.color_turn {
+                self.select_new_piece(cell);
+            } else {
+                self.selected_piece = None;
+            }
+        } else {
+            self.selected_piece = None;
+        }
+    }
+
+    /// Selects a new piece on the board.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - The cell containing the piece to select.
+    fn select_new_piece(&mut self, cell: Cell) {
+        let (row, col) = cell.get_position();
+        let position: Position = Position::new(row, col);
+        self.selected_piece = Some(position);
+        let mut shift: Shift = std::mem::take(&mut self.shift);
+        shift.set_possible_moves(self, cell);
+        self.shift = shift;
+
+        self.clear();
+        self.display_possible_moves();
+    }
+
+    /// Moves a piece from one position to another on the board.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The starting position of the piece.
+    /// * `to` - The ending position of the piece.
+    fn move_piece(&mut self, from: Position, to: Position) {
+        let draw_offer: Option<Color> = self.draw_offer;
+        let event_count: usize = self.events.len();
+        self.decline_draw(self.color_turn);
+        self.add_notation(self.get_san_without_suffix(from, to));
+        let piece: Piece = self.board[from.get_row()][from.get_col()]
+            .get_piece()
+            .unwrap();
+
+        self.undo_stack.push(MoveRecord {
+            chess_move: self.get_move(from, to, None),
+            castling_rights: self.castling_rights,
+            en_passant: self.en_passant,
+            halfmove_clock: self.halfmove_clock,
+            fullmove_number: self.fullmove_number,
+            white_score: self.white_score,
+            black_score: self.black_score,
+            outcome: self.outcome,
+            position_count: self.position_history.len(),
+            draw_offer,
+            event_count,
+        });
+        self.redo_stack.clear();
+
+        let piece_captured: Option<Piece> = self.displace_piece(from, to);
+        if let Some(piece_captured) = piece_captured {
+            self.update_points(piece_captured);
+        }
+        self.selected_piece = None;
+
+        if piece.get_kind() == Kind::Pawn || piece_captured.is_some() {
+            self.halfmove_clock = 0;
+        } else {
+            self.halfmove_clock += 1;
+        }
+        if self.color_turn == Color::Black {
+            self.fullmove_number += 1;
+        }
+
+        self.update_castling_rights(from, to);
+
+        self.en_passant = self.get_en_passant_after(piece, from, to);
+
+        self.clear();
+        self.check_promote(to);
+    }
+
+    /// Moves the pieces involved in a move, including the rook of a castling and
+    /// the pawn taken en passant, without any other bookkeeping.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The starting position of the piece.
+    /// * `to` - The ending position of the piece.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Piece>` containing the captured piece, if any.
+    fn displace_piece(&mut self, from: Position, to: Position) -> Option<Piece> {
+        let is_castling: bool = self.is_castling(from, to);
+        let is_en_passant: bool = self.is_en_passant(from, to);
+        let piece: Piece = self.board[from.get_row()][from.get_col()]
+            .get_piece()
+            .unwrap();
+        let mut piece_captured: Option<Piece> = self.board[to.get_row()][to.get_col()].get_piece();
+
+        if is_en_passant {
+            let position: Position = Position::new(from.get_row(), to.get_col());
+            piece_captured = self.get_cell(position).get_piece();
+            self.set_piece_at(position, Piece::none());
+        }
+
+        self.set_piece_at(to, piece);
+        self.set_piece_at(from, Piece::none());
+
+        if is_castling {
+            self.move_castling_rook(from, to);
+        }
+
+        piece_captured
+    }
+
+    /// Returns a copy of the board where a move has been played and the turn handed
+    /// to the opponent, with the castling rights and en passant square updated.
+    ///
+    /// # Arguments
+    ///
+    /// * `chess_move` - The move to play.
+    ///
+    /// # Returns
+    ///
+    /// A new `Board` in the position reached after the move.
+    pub(crate) fn play_move(&self, chess_move: Move) -> Board {
+        let mut board: Board = self.clone();
+        let (from, to): (Position, Position) = (chess_move.get_from(), chess_move.get_to());
+        let piece: Piece = chess_move.get_piece();
+
+        board.displace_piece(from, to);
+        if let Some(kind) = chess_move.get_promotion() {
+            board.set_piece_at(to, Piece::new(kind, piece.get_color()));
+        }
+        board.update_castling_rights(from, to);
+        board.en_passant = self.get_en_passant_after(piece, from, to);
+        board.set_color_turn(!self.color_turn);
+        board
+    }
+
+    /// Computes the en passant square created by a move.
+    ///
+    /// # Arguments
+    ///
+    /// * `piece` - The piece that moves.
+    /// * `from` - The starting position of the piece.
+    /// * `to` - The ending position of the piece.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Position>` containing the square skipped by a pawn moving two squares.
+    fn get_en_passant_after(&self, piece: Piece, from: Position, to: Position) -> Option<Position> {
+        if piece.get_kind() == Kind::Pawn && from.get_row().abs_diff(to.get_row()) == 2 {
+            Some(Position::new(
+                (from.get_row() + to.get_row()) / 2,
+                from.get_col(),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Describes a move from one position to another in the current position,
+    /// without checking that it is legal.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The starting position of the piece.
+    /// * `to` - The ending position of the piece.
+    /// * `promotion` - The kind of piece a pawn is promoted to, if any.
+    ///
+    /// # Returns
+    ///
+    /// A `Move` with the moved and captured pieces filled in.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no piece at `from`.
+    pub fn get_move(&self, from: Position, to: Position, promotion: Option<Kind>) -> Move {
+        self.get_setup().get_move(from, to, promotion)
+    }
+
+    /// Returns every legal move of the side to move, with one move per promotion piece.
+    ///
+    /// # Returns
+    ///
+    /// A vector containing the legal moves.
+    pub fn legal_moves(&self) -> Vec<Move> {
+        self.get_setup().legal_moves()
+    }
+
+    /// Returns the legal moves of the piece standing on a position, if it belongs
+    /// to the side to move.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position of the piece.
+    ///
+    /// # Returns
+    ///
+    /// A vector containing the legal moves of the piece, empty for an empty square
+    /// or an opponent piece.
+    pub fn legal_moves_from(&self, position: Position) -> Vec<Move> {
+        self.get_setup().legal_moves_from(position)
+    }
+
+    /// Finds the legal move written in the long algebraic notation used by UCI
+    /// engines.
+    ///
+    /// # Arguments
+    ///
+    /// * `uci` - The move, such as `e2e4`, `e1g1` or `e7e8q`.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Move>` containing the move, `None` if no legal move matches.
+    pub fn parse_uci(&self, uci: &str) -> Option<Move> {
+        self.legal_moves()
+            .into_iter()
+            .find(|chess_move| chess_move.get_uci() == uci)
+    }
+
+    /// Checks if a move can be played by the side to move.
+    ///
+    /// # Arguments
+    ///
+    /// * `chess_move` - The move to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the game is running and the move is legal, `false` otherwise.
+    pub fn is_legal(&self, chess_move: Move) -> bool {
+        !self.is_end()
+            && self.pending_promotion.is_none()
+            && self
+                .legal_moves_from(chess_move.get_from())
+                .contains(&chess_move)
+    }
+
+    /// Checks if a move brings a pawn to the last rank.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The starting position of the piece.
+    /// * `to` - The ending position of the piece.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the move is a promotion, `false` otherwise.
+    pub fn is_promotion(&self, from: Position, to: Position) -> bool {
+        let cell: &Cell = self.get_cell(from);
+
+        cell.get_piece().is_some()
+            && cell.get_piece_kind() == Kind::Pawn
+            && (to.get_row() == 0 || to.get_row() == self.size - 1)
+    }
+
+    /// Checks if a move from one position to another is a castling.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The starting position of the piece.
+    /// * `to` - The ending position of the piece.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a king moves two columns, `false` otherwise.
+    pub(crate) fn is_castling(&self, from: Position, to: Position) -> bool {
+        let cell: &Cell = self.get_cell(from);
+
+        cell.get_piece().is_some()
+            && cell.get_piece_kind() == Kind::King
+            && from.get_row() == to.get_row()
+            && from.get_col().abs_diff(to.get_col()) == 2
+    }
+
+    /// Checks if a move from one position to another is an en passant capture.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The starting position of the piece.
+    /// * `to` - The ending position of the piece.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a pawn moves onto the en passant square, `false` otherwise.
+    pub(crate) fn is_en_passant(&self, from: Position, to: Position) -> bool {
+        let cell: &Cell = self.get_cell(from);
+
+        cell.get_piece().is_some()
+            && cell.get_piece_kind() == Kind::Pawn
+            && from.get_col() != to.get_col()
+            && self.en_passant == Some(to)
+    }
+
+    /// Moves the rook next to the king once the king has castled.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The starting position of the king.
+    /// * `to` - The ending position of the king.
+    fn move_castling_rook(&mut self, from: Position, to: Position) {
+        let (rook_from, rook_to): (usize, usize) = get_castling_rook_squares(from, to);
+        self.move_rook(
+            Position::from_square(rook_from),
+            Position::from_square(rook_to),
+        );
+    }
+
+    /// Puts the rook back in its corner once a castling is taken back.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The starting position of the king.
+    /// * `to` - The ending position of the king.
+    fn move_castling_rook_back(&mut self, from: Position, to: Position) {
+        let (rook_from, rook_to): (usize, usize) = get_castling_rook_squares(from, to);
+        self.move_rook(
+            Position::from_square(rook_to),
+            Position::from_square(rook_from),
+        );
+    }
+
+    /// Moves the rook of a castling from one position to another.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The starting position of the rook.
+    /// * `to` - The ending position of the rook.
+    fn move_rook(&mut self, from: Position, to: Position) {
+        if let Some(rook) = self.get_cell(from).get_piece() {
+            self.set_piece_at(to, rook);
+            self.set_piece_at(from, Piece::none());
+        }
+    }
+
+    /// Checks if a move from one position to another is valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The starting position of the piece.
+    /// * `to` - The ending position of the piece.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the move is valid, `false` otherwise.
+    fn is_valid_move(&self, from: Position, to: Position) -> bool {
+        self.get_cell(from).get_piece().is_some()
+            && self.get_cell(from).get_piece_kind() != Kind::None
+            && self.shift.get_possible_moves().contains(&to)
+    }
+
+    /// Checks the status of the opponent once a move is played: displays its king
+    /// in check and ends the game when it has no legal move left.
+    fn check_king_status(&mut self) {
+        let opposant_color: Color = !self.color_turn;
+        let mut is_check: bool = false;
+
+        if let Some(position_king) = self.get_position_king(opposant_color) {
+            if self.is_king_in_check(position_king) {
+                is_check = true;
+                self.display_king_in_check(position_king);
+                log("King is in check");
+            }
+        }
+
+        let has_legal_moves: bool = self.has_legal_moves(opposant_color);
+        if let Some(notation) = self.notations.last_mut() {
+            match (is_check, has_legal_moves) {
+                (true, true) => notation.push('+'),
+                (true, false) => notation.push('#'),
+                _ => {}
+            }
+        }
+
+        if !has_legal_moves {
+            if is_check {
+                log("King is in check mate");
+                self.end_game(Outcome::Checkmate(self.color_turn));
+            } else {
+                log("Stalemate");
+                self.end_game(Outcome::Stalemate);
+            }
+        }
+    }
+
+    /// Adds the current position to the position history.
+    fn record_position(&mut self) {
+        let key: u64 = self.get_position_key();
+        self.position_history.push(key);
+    }
+
+    /// Gets the keys of every position reached in the game, used to detect repetitions.
+    /// They are the keys of `Setup::get_position_key`.
+    ///
+    /// # Returns
+    ///
+    /// A slice of the keys, from the initial position to the current one.
+    pub fn get_position_keys(&self) -> &[u64] {
+        &self.position_history
+    }
+
+    /// Builds a key identifying the current position for repetition purposes: the
+    /// Zobrist key of the placement, the side to move, the castling rights and the
+    /// en passant file when a capture on it is actually possible.
+    ///
+    /// # Returns
+    ///
+    /// A `u64` identifying the position.
+    fn get_position_key(&self) -> u64 {
+        self.zobrist ^ self.get_setup().get_legal_en_passant_key()
+    }
+
+    /// Counts how many times the current position has occurred in the game.
+    ///
+    /// # Returns
+    ///
+    /// The number of occurrences of the current position, itself included.
+    fn count_repetitions(&self) -> usize {
+        match self.position_history.last() {
+            Some(key) => self
+                .position_history
+                .iter()
+                .filter(|other| *other == key)
+                .count(),
+            None => 0,
+        }
+    }
+
+    /// Checks if neither side has enough material left to checkmate: king against
+    /// king, a single minor piece, or only bishops all standing on the same color.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the position is dead, `false` otherwise.
+    pub fn is_insufficient_material(&self) -> bool {
+        let mut minor_pieces: Vec<Cell> = Vec::new();
+
+        for row in self.board.iter() {
+            for cell in row.iter() {
+                if let Some(piece) = cell.get_piece() {
+                    match piece.get_kind() {
+                        Kind::King | Kind::None => {}
+                        Kind::Knight | Kind::Bishop => minor_pieces.push(*cell),
+                        Kind::Pawn | Kind::Rook | Kind::Queen => return false,
+                    }
+                }
+            }
+        }
+
+        minor_pieces.len() <= 1
+            || (minor_pieces
+                .iter()
+                .all(|cell| cell.get_piece_kind() == Kind::Bishop)
+                && minor_pieces
+                    .iter()
+                    .all(|cell| cell.get_color() == minor_pieces[0].get_color()))
+    }
+
+    /// Checks if a player could still checkmate through any series of legal moves,
+    /// helped by the opponent. It needs a piece other than its king, and the
+    /// material of both players must not be insufficient: king against king and
+    /// minor piece, or bishops all standing on the same color.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color of the player.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the player could still checkmate, `false` otherwise.
+    pub fn has_mating_material(&self, color: Color) -> bool {
+        let has_piece: bool = self.board.iter().flatten().any(|cell| {
+            cell.get_piece()
+                .is_some_and(|piece| piece.get_color() == color && piece.get_kind() != Kind::King)
+        });
+
+        has_piece && !self.is_insufficient_material()
+    }
+
+    /// Ends the game when a draw is automatic: seventy-five moves without capture
+    /// or pawn move, fivefold repetition or insufficient material. Nothing happens
+    /// when automatic draws are disabled.
+    fn check_draw_status(&mut self) {
+        if !self.automatic_draws {
+            return;
+        }
+        if self.is_insufficient_material() {
+            self.end_game(Outcome::InsufficientMaterial);
+        } else if self.count_repetitions() >= 5 {
+            self.end_game(Outcome::FivefoldRepetition);
+        } else if self.halfmove_clock >= 150 {
+            self.end_game(Outcome::SeventyFiveMoveRule);
+        }
+    }
+
+    /// Returns whether fivefold repetition, the seventy-five move rule and
+    /// insufficient material end the game by themselves.
+    ///
+    /// # Returns
+    ///
+    /// `true` if these draws end the game, `false` otherwise.
+    pub fn get_automatic_draws(&self) -> bool {
+        self.automatic_draws
+    }
+
+    /// Sets whether fivefold repetition, the seventy-five move rule and
+    /// insufficient material end the game by themselves. Turning them off lets the
+    /// moves of a game played on past these draws be replayed; turning them back on
+    /// ends the game if the current position is such a draw.
+    ///
+    /// # Arguments
+    ///
+    /// * `automatic_draws` - A boolean indicating whether these draws end the game.
+    pub fn set_automatic_draws(&mut self, automatic_draws: bool) {
+        self.automatic_draws = automatic_draws;
+        if !self.is_end() {
+            self.check_draw_status();
+        }
+    }
+
+    /// Returns the draw the side to move is entitled to claim, if any.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Outcome>` containing `ThreefoldRepetition` or `FiftyMoveRule`, or
+    /// `None` if no draw can be claimed.
+    pub fn get_claimable_draw(&self) -> Option<Outcome> {
+        if self.is_end() {
+            None
+        } else if self.count_repetitions() >= 3 {
+            Some(Outcome::ThreefoldRepetition)
+        } else if self.halfmove_clock >= 100 {
+            Some(Outcome::FiftyMoveRule)
+        } else {
+            None
+        }
+    }
+
+    /// Ends the game as a draw if the side to move is entitled to claim one.
+    pub fn claim_draw(&mut self) {
+        if let Some(outcome) = self.get_claimable_draw() {
+            self.end_game(outcome);
+        }
+    }
+
+    /// Checks if the given color has at least one legal move.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color of the player to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if any piece of that color can move, `false` otherwise.
+    pub fn has_legal_moves(&self, color: Color) -> bool {
+        Setup::new(self.bitboards, color, self.castling_rights, self.en_passant).has_legal_moves()
+    }
+
+    /// Checks if the king is in check at the given position.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position of the king to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the king is in check, `false` otherwise.
+    fn is_king_in_check(&self, position: Position) -> bool {
+        self.shift.is_in_check(self, position, !self.color_turn)
+    }
+
+    /// Checks if a pawn should be promoted and waits for the new piece to be chosen.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position of the pawn to check for promotion.
+    fn check_promote(&mut self, position: Position) {
+        if self.board[position.get_row()][position.get_col()].get_piece_kind() == Kind::Pawn {
+            let color: Color = self.board[position.get_row()][position.get_col()].get_piece_color();
+            if (position.get_row() == 0 && color == Color::White)
+                || (position.get_row() == self.size - 1 && color == Color::Black)
+            {
+                self.pending_promotion = Some(position);
+            }
+        }
+    }
+
+    /// Promotes the pending pawn to the chosen piece and finishes the turn.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of piece chosen by the player.
+    pub fn promote(&mut self, kind: Kind) {
+        if self.pending_promotion.is_some() {
+            self.set_promotion(kind);
+            self.end_turn();
+        }
+    }
+
+    /// Replaces the pending pawn by the given piece and records it in the notation.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of piece the pawn is promoted to.
+    fn set_promotion(&mut self, kind: Kind) {
+        if let Some(position) = self.pending_promotion {
+            if !matches!(kind, Kind::Knight | Kind::Bishop | Kind::Rook | Kind::Queen) {
+                return;
+            }
+
+            let piece: Piece = Piece::new(kind, self.color_turn);
+            self.set_piece_at(position, piece);
+            if let Some(notation) = self.notations.last_mut() {
+                notation.push('=');
+                notation.push(piece.get_symbol().to_ascii_uppercase());
+            }
+            if let Some(record) = self.undo_stack.last_mut() {
+                record.chess_move.set_promotion(Some(kind));
+            }
+            self.pending_promotion = None;
+        }
+    }
+
+    /// Clears the selection and check status of all cells on the board.
+    fn clear(&mut self) {
+        for r in 0..self.size {
+            for c in 0..self.size {
+                self.board[r][c].set_is_selected(false);
+                self.board[r][c].set_is_check(false);
+            }
+        }
+    }
+
+    /// Displays the possible moves for the selected piece.
+    fn display_possible_moves(&mut self) {
+        for pos in self.shift.get_possible_moves().iter() {
+            self.board[pos.get_row()][pos.get_col()].set_is_selected(true);
+        }
+    }
+
+    /// Displays the king in check by setting the `is_check` flag on the king's cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position of the king in check.
+    fn display_king_in_check(&mut self, position: Position) {
+        self.board[position.get_row()][position.get_col()].set_is_check(true);
+    }
+
+    /// Adds a move notation to the list of notations.
+    ///
+    /// # Arguments
+    ///
+    /// * `notation` - The notation of the move to add.
+    fn add_notation(&mut self, notation: String) {
+        self.notations.push(notation);
+    }
+
+    /// Updates the points for the player based on the captured piece.
+    ///
+    /// # Arguments
+    ///
+    /// * `piece` - The piece that was captured.
+    fn update_points(&mut self, piece: Piece) {
+        match piece.get_color() {
+            Color::White => {
+                if let Some(value) = piece.get_value() {
+                    self.black_score += value
+                }
+            }
+            Color::Black => {
+                if let Some(value) = piece.get_value() {
+                    self.white_score += value
+                }
+            }
+        }
+    }
+
+    /// Ends the game with the given outcome and logs the end of the game.
+    ///
+    /// # Arguments
+    ///
+    /// * `outcome` - The way the game has ended.
+    fn end_game(&mut self, outcome: Outcome) {
+        self.pause_clock();
+        self.outcome = Some(outcome);
+        log("End of the game");
     }
 }
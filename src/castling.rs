@@ -0,0 +1,135 @@
+use crate::{color::Color, position::Position};
+
+/// Represents the castling rights of both players.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CastlingRights {
+    /// Indicates whether white can still castle on the king side.
+    white_king_side: bool,
+    /// Indicates whether white can still castle on the queen side.
+    white_queen_side: bool,
+    /// Indicates whether black can still castle on the king side.
+    black_king_side: bool,
+    /// Indicates whether black can still castle on the queen side.
+    black_queen_side: bool,
+}
+
+impl Default for CastlingRights {
+    fn default() -> Self {
+        CastlingRights::all()
+    }
+}
+
+impl CastlingRights {
+    /// Creates castling rights where both players can castle on both sides.
+    ///
+    /// # Returns
+    ///
+    /// A new `CastlingRights` instance with every right available.
+    pub fn all() -> Self {
+        CastlingRights {
+            white_king_side: true,
+            white_queen_side: true,
+            black_king_side: true,
+            black_queen_side: true,
+        }
+    }
+
+    /// Creates castling rights where no player can castle.
+    ///
+    /// # Returns
+    ///
+    /// A new `CastlingRights` instance with every right removed.
+    pub fn none() -> Self {
+        CastlingRights {
+            white_king_side: false,
+            white_queen_side: false,
+            black_king_side: false,
+            black_queen_side: false,
+        }
+    }
+
+    /// Checks if the given color can still castle on the king side.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color of the player.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the right is still available, `false` otherwise.
+    pub fn get_king_side(&self, color: Color) -> bool {
+        match color {
+            Color::White => self.white_king_side,
+            Color::Black => self.black_king_side,
+        }
+    }
+
+    /// Checks if the given color can still castle on the queen side.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color of the player.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the right is still available, `false` otherwise.
+    pub fn get_queen_side(&self, color: Color) -> bool {
+        match color {
+            Color::White => self.white_queen_side,
+            Color::Black => self.black_queen_side,
+        }
+    }
+
+    /// Sets the king side castling right of the given color.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color of the player.
+    /// * `value` - Whether the right is available.
+    pub fn set_king_side(&mut self, color: Color, value: bool) {
+        match color {
+            Color::White => self.white_king_side = value,
+            Color::Black => self.black_king_side = value,
+        }
+    }
+
+    /// Sets the queen side castling right of the given color.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color of the player.
+    /// * `value` - Whether the right is available.
+    pub fn set_queen_side(&mut self, color: Color, value: bool) {
+        match color {
+            Color::White => self.white_queen_side = value,
+            Color::Black => self.black_queen_side = value,
+        }
+    }
+
+    /// Removes the rights affected by a piece leaving or arriving on a square.
+    ///
+    /// Moving a king from its starting square removes both of its rights, and
+    /// moving or capturing a rook on its starting corner removes the matching right.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The square a piece moved from or to.
+    pub fn update(&mut self, position: Position) {
+        match position.get_position() {
+            (7, 4) => {
+                self.white_king_side = false;
+                self.white_queen_side = false;
+            }
+            (0, 4) => {
+                self.black_king_side = false;
+                self.black_queen_side = false;
+            }
+            (7, 7) => self.white_king_side = false,
+            (7, 0) => self.white_queen_side = false,
+            (0, 7) => self.black_king_side = false,
+            (0, 0) => self.black_queen_side = false,
+            _ => {}
+        }
+    }
+}
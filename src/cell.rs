@@ -2,6 +2,7 @@ use crate::{color::Color, kind::Kind, piece::Piece, position::Position};
 
 /// Represents a cell on the chess board.
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cell {
     /// The color of the cell.
     color: Color,
@@ -62,7 +63,7 @@ impl Cell {
     /// # Arguments
     ///
     /// * `piece` - The piece to place on the cell.
-    pub fn set_piece(&mut self, piece: Piece) -> () {
+    pub fn set_piece(&mut self, piece: Piece) {
         self.piece = piece;
     }
 
@@ -74,7 +75,7 @@ impl Cell {
     /// - `None` if the cell is empty.
     pub fn get_piece(&self) -> Option<Piece> {
         if self.piece.get_kind() != Kind::None {
-            Some(self.piece.clone())
+            Some(self.piece)
         } else {
             None
         }
@@ -93,7 +94,7 @@ impl Cell {
     /// # Arguments
     ///
     /// * `is_check` - A boolean indicating whether the cell is in check.
-    pub fn set_is_check(&mut self, is_check: bool) -> () {
+    pub fn set_is_check(&mut self, is_check: bool) {
         self.is_check = is_check;
     }
 
@@ -111,7 +112,7 @@ impl Cell {
     /// # Arguments
     ///
     /// * `is_selected` - A boolean indicating whether the cell is selected.
-    pub fn set_is_selected(&mut self, is_selected: bool) -> () {
+    pub fn set_is_selected(&mut self, is_selected: bool) {
         self.is_selected = is_selected;
     }
 
@@ -1,33 +1,793 @@
-use crate::{board::Board, position::Position};
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    board::Board,
+    clock::{SystemTimeSource, TimeControl, TimeSource},
+    color::Color,
+    engine::{Engine, SearchInfo, SliceResult},
+    kind::Kind,
+    pgn::{parse_pgn, PgnGame, PgnTags},
+    position::Position,
+    save::{GameLibrary, LocalStorage, SavedGame},
+};
+use gloo_file::callbacks::FileReader;
+use gloo_timers::callback::{Interval, Timeout};
+use web_sys::{DragEvent, HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement};
 use yew::prelude::*;
 
+/// The strongest level of the computer, which searches one half move deeper per level.
+const MAX_LEVEL: u32 = 5;
+
+/// The time controls offered for a new game, by name.
+const TIME_CONTROLS: [(&str, Option<&str>); 6] = [
+    ("No clock", None),
+    ("Bullet 1+0", Some("60")),
+    ("Blitz 3+2", Some("180+2")),
+    ("Blitz 5+0", Some("300")),
+    ("Rapid 10, 5 s delay", Some("600d5")),
+    ("Classical 40/90, 30+30", Some("40/5400:1800+30")),
+];
+
+/// The number of nodes the computer searches between two chances for the page to
+/// render and handle events.
+const SEARCH_SLICE: u64 = 20_000;
+
+/// The time between two redraws of a running clock, in milliseconds.
+const CLOCK_REFRESH: u32 = 100;
+
+/// The name of a game until the players give it one.
+const DEFAULT_GAME_NAME: &str = "Game";
+
 #[function_component(Chess)]
 pub fn game() -> Html {
-    let board: UseStateHandle<Board> = use_state(|| Board::new());
+    let board: UseStateHandle<Board> = use_state(Board::new);
+    let games: UseStateHandle<Vec<PgnGame>> = use_state(Vec::new);
+    let game_index: UseStateHandle<usize> = use_state(|| 0);
+    let ply: UseStateHandle<usize> = use_state(|| 0);
+    let pgn_text: UseStateHandle<String> = use_state(String::new);
+    let pgn_error: UseStateHandle<Option<String>> = use_state(|| None);
+    let file_reader: Rc<RefCell<Option<FileReader>>> = use_mut_ref(|| None);
+    let computer: UseStateHandle<Option<Color>> = use_state(|| None);
+    let player_color: UseStateHandle<Color> = use_state(|| Color::White);
+    let level: UseStateHandle<u32> = use_state(|| 3);
+    // The position the computer is thinking about, the depth of its iteration and
+    // the number of slices of that iteration already searched.
+    let thinking: UseStateHandle<(u64, u32, u32)> = use_state(|| (0, 1, 0));
+    let engine: Rc<RefCell<Engine>> = use_mut_ref(Engine::new);
+    let time_control: UseStateHandle<usize> = use_state(|| 0);
+    let tick: UseStateHandle<u64> = use_state(|| 0);
+    let library: Rc<RefCell<Option<GameLibrary<LocalStorage>>>> =
+        use_mut_ref(|| LocalStorage::new().map(GameLibrary::new));
+    let is_loaded: UseStateHandle<bool> = use_state(|| false);
+    let game_name: UseStateHandle<String> = use_state(|| DEFAULT_GAME_NAME.to_string());
+    let saved_games: UseStateHandle<Vec<(u32, String)>> = use_state(Vec::new);
+    let save_error: UseStateHandle<Option<String>> = use_state(|| None);
+
+    let is_computer_turn: bool = *computer == Some(board.get_color_turn())
+        && !board.is_end()
+        && !board.is_clock_paused()
+        && board.get_pending_promotion().is_none();
+    let is_clock_running: bool =
+        board.get_clock().is_some() && !board.is_clock_paused() && !board.is_end();
 
     {
         let board: UseStateHandle<Board> = board.clone();
+        let computer: UseStateHandle<Option<Color>> = computer.clone();
+        let level: UseStateHandle<u32> = level.clone();
+        let time_control: UseStateHandle<usize> = time_control.clone();
+        let game_name: UseStateHandle<String> = game_name.clone();
+        let saved_games: UseStateHandle<Vec<(u32, String)>> = saved_games.clone();
+        let save_error: UseStateHandle<Option<String>> = save_error.clone();
+        let is_loaded: UseStateHandle<bool> = is_loaded.clone();
+        let library: Rc<RefCell<Option<GameLibrary<LocalStorage>>>> = library.clone();
         use_effect_with_deps(
             move |_| {
                 board.set(Board::new().initialize());
+                if let Some(library) = library.borrow().as_ref() {
+                    match library.load_current() {
+                        Ok(Some(saved)) => open_saved_game(
+                            &saved,
+                            &board,
+                            &computer,
+                            &level,
+                            &time_control,
+                            &game_name,
+                        ),
+                        Ok(None) => {}
+                        Err(error) => save_error.set(Some(error.to_string())),
+                    }
+                    saved_games.set(library.get_games());
+                }
+                is_loaded.set(true);
                 || ()
             },
             (),
         );
     }
 
+    {
+        // The game is saved after each move and each change of its settings, so it
+        // can be resumed after the page is reloaded. A running clock is saved with
+        // the time of the save, so the time until then is not given back.
+        let deps: (
+            u64,
+            usize,
+            usize,
+            bool,
+            bool,
+            bool,
+            Option<Color>,
+            u32,
+            String,
+            bool,
+        ) = (
+            board.hash(),
+            board.get_notations().len(),
+            board.get_events().len(),
+            board.is_end(),
+            board.get_auto_queen(),
+            board.is_clock_paused(),
+            *computer,
+            *level,
+            (*game_name).clone(),
+            *is_loaded,
+        );
+        let board: UseStateHandle<Board> = board.clone();
+        let save_error: UseStateHandle<Option<String>> = save_error.clone();
+        let library: Rc<RefCell<Option<GameLibrary<LocalStorage>>>> = library.clone();
+        use_effect_with_deps(
+            move |(_, _, _, _, _, _, computer, level, game_name, is_loaded)| {
+                if *is_loaded && board.get_pending_promotion().is_none() {
+                    if let Some(library) = library.borrow_mut().as_mut() {
+                        let saved: SavedGame =
+                            SavedGame::new(game_name, (*board).clone(), *computer, *level);
+                        if let Err(error) = library.save_current(&saved) {
+                            save_error.set(Some(error.to_string()));
+                        }
+                    }
+                }
+                || ()
+            },
+            deps,
+        );
+    }
+
+    {
+        let tick: UseStateHandle<u64> = tick.clone();
+        use_effect_with_deps(
+            move |is_clock_running| {
+                let mut interval: Option<Interval> = None;
+                if *is_clock_running {
+                    interval = Some(Interval::new(CLOCK_REFRESH, move || {
+                        tick.set(SystemTimeSource.now_ms())
+                    }));
+                }
+                move || drop(interval)
+            },
+            is_clock_running,
+        );
+    }
+
+    {
+        let board: UseStateHandle<Board> = board.clone();
+        use_effect_with_deps(
+            move |_| {
+                let mut new_board: Board = (*board).clone();
+                if new_board.check_flag() {
+                    board.set(new_board);
+                }
+                || ()
+            },
+            *tick,
+        );
+    }
+
+    {
+        let deps: (u64, u32, (u64, u32, u32), bool) =
+            (board.hash(), *level, *thinking, is_computer_turn);
+        let board: UseStateHandle<Board> = board.clone();
+        let thinking: UseStateHandle<(u64, u32, u32)> = thinking.clone();
+        let engine: Rc<RefCell<Engine>> = engine.clone();
+        use_effect_with_deps(
+            move |(key, level, (thinking_key, thinking_depth, slices), is_computer_turn)| {
+                let mut timeout: Option<Timeout> = None;
+                if *is_computer_turn {
+                    let (key, level): (u64, u32) = (*key, *level);
+                    let (depth, slices): (u32, u32) = if *thinking_key == key {
+                        (*thinking_depth, *slices)
+                    } else {
+                        (1, 0)
+                    };
+                    // Searching a slice of nodes per timeout gives the page a chance
+                    // to render and handle events while the computer thinks.
+                    timeout = Some(Timeout::new(0, move || {
+                        let result: SliceResult =
+                            engine
+                                .borrow_mut()
+                                .search_slice(&board, depth, SEARCH_SLICE);
+                        let info: Option<SearchInfo> = match result {
+                            SliceResult::Unfinished => {
+                                thinking.set((key, depth, slices + 1));
+                                return;
+                            }
+                            SliceResult::Finished(info) => info,
+                        };
+                        match info.and_then(|info| info.get_best_move()) {
+                            Some(_) if depth < level => thinking.set((key, depth + 1, 0)),
+                            Some(best_move) => {
+                                let mut new_board: Board = (*board).clone();
+                                if new_board.make_move(best_move).is_ok() {
+                                    board.set(new_board);
+                                }
+                            }
+                            None => {}
+                        }
+                    }));
+                }
+                move || drop(timeout)
+            },
+            deps,
+        );
+    }
+
     let on_click: Callback<Position> = {
         let board: UseStateHandle<Board> = board.clone();
         Callback::from(move |pos: Position| {
+            if is_computer_turn {
+                return;
+            }
             let mut new_board: Board = (*board).clone();
             new_board.handle_click(*board.get_cell(pos));
             board.set(new_board);
         })
     };
 
+    let on_promote: Callback<Kind> = {
+        let board: UseStateHandle<Board> = board.clone();
+        Callback::from(move |kind: Kind| {
+            let mut new_board: Board = (*board).clone();
+            new_board.promote(kind);
+            board.set(new_board);
+        })
+    };
+
+    let on_auto_queen: Callback<bool> = {
+        let board: UseStateHandle<Board> = board.clone();
+        Callback::from(move |auto_queen: bool| {
+            let mut new_board: Board = (*board).clone();
+            new_board.set_auto_queen(auto_queen);
+            board.set(new_board);
+        })
+    };
+
+    let on_claim_draw: Callback<()> = {
+        let board: UseStateHandle<Board> = board.clone();
+        Callback::from(move |_| {
+            let mut new_board: Board = (*board).clone();
+            new_board.claim_draw();
+            board.set(new_board);
+        })
+    };
+
+    let on_undo: Callback<()> = {
+        let board: UseStateHandle<Board> = board.clone();
+        let computer: UseStateHandle<Option<Color>> = computer.clone();
+        Callback::from(move |_| {
+            let mut new_board: Board = (*board).clone();
+            new_board.undo();
+            // Against the computer, take its answer back too so the player moves next.
+            if *computer == Some(new_board.get_color_turn()) && new_board.can_undo() {
+                new_board.undo();
+            }
+            board.set(new_board);
+        })
+    };
+
+    let on_redo: Callback<()> = {
+        let board: UseStateHandle<Board> = board.clone();
+        let computer: UseStateHandle<Option<Color>> = computer.clone();
+        Callback::from(move |_| {
+            let mut new_board: Board = (*board).clone();
+            new_board.redo();
+            if *computer == Some(new_board.get_color_turn()) && new_board.can_redo() {
+                new_board.redo();
+            }
+            board.set(new_board);
+        })
+    };
+
+    let on_pause: Callback<()> = {
+        let board: UseStateHandle<Board> = board.clone();
+        Callback::from(move |_| {
+            let mut new_board: Board = (*board).clone();
+            if new_board.is_clock_paused() {
+                new_board.resume_clock();
+            } else {
+                new_board.pause_clock();
+            }
+            board.set(new_board);
+        })
+    };
+
+    let on_resign: Callback<Color> = {
+        let board: UseStateHandle<Board> = board.clone();
+        Callback::from(move |color: Color| {
+            let mut new_board: Board = (*board).clone();
+            new_board.resign(color);
+            board.set(new_board);
+        })
+    };
+
+    let on_offer_draw: Callback<Color> = {
+        let board: UseStateHandle<Board> = board.clone();
+        Callback::from(move |color: Color| {
+            let mut new_board: Board = (*board).clone();
+            new_board.offer_draw(color);
+            board.set(new_board);
+        })
+    };
+
+    let on_answer_draw: Callback<(Color, bool)> = {
+        let board: UseStateHandle<Board> = board.clone();
+        Callback::from(move |(color, accept): (Color, bool)| {
+            let mut new_board: Board = (*board).clone();
+            if accept {
+                new_board.accept_draw(color);
+            } else {
+                new_board.decline_draw(color);
+            }
+            board.set(new_board);
+        })
+    };
+
+    let on_key_down: Callback<KeyboardEvent> = {
+        let on_undo: Callback<()> = on_undo.clone();
+        let on_redo: Callback<()> = on_redo.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            if !event.ctrl_key() && !event.meta_key() {
+                return;
+            }
+            match event.key().to_lowercase().as_str() {
+                "z" if event.shift_key() => on_redo.emit(()),
+                "z" => on_undo.emit(()),
+                "y" => on_redo.emit(()),
+                _ => return,
+            }
+            event.prevent_default();
+        })
+    };
+
+    let on_play_computer: Callback<()> = {
+        let board: UseStateHandle<Board> = board.clone();
+        let computer: UseStateHandle<Option<Color>> = computer.clone();
+        let player_color: UseStateHandle<Color> = player_color.clone();
+        let engine: Rc<RefCell<Engine>> = engine.clone();
+        let time_control: UseStateHandle<usize> = time_control.clone();
+        Callback::from(move |_| {
+            engine.borrow_mut().clear();
+            computer.set(Some(!*player_color));
+            board.set(new_game(*time_control));
+        })
+    };
+
+    let on_play_human: Callback<()> = {
+        let computer: UseStateHandle<Option<Color>> = computer.clone();
+        Callback::from(move |_| computer.set(None))
+    };
+
+    let on_new_game: Callback<()> = {
+        let board: UseStateHandle<Board> = board.clone();
+        let engine: Rc<RefCell<Engine>> = engine.clone();
+        let time_control: UseStateHandle<usize> = time_control.clone();
+        Callback::from(move |_| {
+            engine.borrow_mut().clear();
+            board.set(new_game(*time_control));
+        })
+    };
+
+    let on_time_control: Callback<Event> = {
+        let time_control: UseStateHandle<usize> = time_control.clone();
+        Callback::from(move |event: Event| {
+            let select: HtmlSelectElement = event.target_unchecked_into();
+            time_control.set(select.selected_index().max(0) as usize);
+        })
+    };
+
+    let on_player_color: Callback<Event> = {
+        let player_color: UseStateHandle<Color> = player_color.clone();
+        Callback::from(move |event: Event| {
+            let select: HtmlSelectElement = event.target_unchecked_into();
+            player_color.set(if select.selected_index() == 1 {
+                Color::Black
+            } else {
+                Color::White
+            });
+        })
+    };
+
+    let on_level: Callback<Event> = {
+        let level: UseStateHandle<u32> = level.clone();
+        Callback::from(move |event: Event| {
+            let select: HtmlSelectElement = event.target_unchecked_into();
+            level.set(select.selected_index().max(0) as u32 + 1);
+        })
+    };
+
+    let on_game_name: Callback<InputEvent> = {
+        let game_name: UseStateHandle<String> = game_name.clone();
+        Callback::from(move |event: InputEvent| {
+            let input: HtmlInputElement = event.target_unchecked_into();
+            game_name.set(input.value());
+        })
+    };
+
+    let on_save_game: Callback<()> = {
+        let board: UseStateHandle<Board> = board.clone();
+        let computer: UseStateHandle<Option<Color>> = computer.clone();
+        let level: UseStateHandle<u32> = level.clone();
+        let game_name: UseStateHandle<String> = game_name.clone();
+        let saved_games: UseStateHandle<Vec<(u32, String)>> = saved_games.clone();
+        let save_error: UseStateHandle<Option<String>> = save_error.clone();
+        let library: Rc<RefCell<Option<GameLibrary<LocalStorage>>>> = library.clone();
+        Callback::from(move |_| {
+            if let Some(library) = library.borrow_mut().as_mut() {
+                let saved: SavedGame =
+                    SavedGame::new(&game_name, (*board).clone(), *computer, *level);
+                match library.add_game(&saved) {
+                    Ok(_) => save_error.set(None),
+                    Err(error) => save_error.set(Some(error.to_string())),
+                }
+                saved_games.set(library.get_games());
+            }
+        })
+    };
+
+    let on_open_saved: Callback<u32> = {
+        let board: UseStateHandle<Board> = board.clone();
+        let computer: UseStateHandle<Option<Color>> = computer.clone();
+        let level: UseStateHandle<u32> = level.clone();
+        let time_control: UseStateHandle<usize> = time_control.clone();
+        let game_name: UseStateHandle<String> = game_name.clone();
+        let save_error: UseStateHandle<Option<String>> = save_error.clone();
+        let engine: Rc<RefCell<Engine>> = engine.clone();
+        let library: Rc<RefCell<Option<GameLibrary<LocalStorage>>>> = library.clone();
+        Callback::from(move |id: u32| {
+            if let Some(library) = library.borrow().as_ref() {
+                match library.open_game(id) {
+                    Ok(saved) => {
+                        engine.borrow_mut().clear();
+                        open_saved_game(
+                            &saved,
+                            &board,
+                            &computer,
+                            &level,
+                            &time_control,
+                            &game_name,
+                        );
+                        save_error.set(None);
+                    }
+                    Err(error) => save_error.set(Some(error.to_string())),
+                }
+            }
+        })
+    };
+
+    let on_rename_saved: Callback<u32> = {
+        let game_name: UseStateHandle<String> = game_name.clone();
+        let saved_games: UseStateHandle<Vec<(u32, String)>> = saved_games.clone();
+        let save_error: UseStateHandle<Option<String>> = save_error.clone();
+        let library: Rc<RefCell<Option<GameLibrary<LocalStorage>>>> = library.clone();
+        Callback::from(move |id: u32| {
+            if let Some(library) = library.borrow_mut().as_mut() {
+                match library.rename_game(id, &game_name) {
+                    Ok(()) => save_error.set(None),
+                    Err(error) => save_error.set(Some(error.to_string())),
+                }
+                saved_games.set(library.get_games());
+            }
+        })
+    };
+
+    let on_delete_saved: Callback<u32> = {
+        let saved_games: UseStateHandle<Vec<(u32, String)>> = saved_games.clone();
+        let save_error: UseStateHandle<Option<String>> = save_error.clone();
+        let library: Rc<RefCell<Option<GameLibrary<LocalStorage>>>> = library.clone();
+        Callback::from(move |id: u32| {
+            if let Some(library) = library.borrow_mut().as_mut() {
+                if let Err(error) = library.delete_game(id) {
+                    save_error.set(Some(error.to_string()));
+                }
+                saved_games.set(library.get_games());
+            }
+        })
+    };
+
+    let on_open_game: Callback<(usize, usize)> = {
+        let board: UseStateHandle<Board> = board.clone();
+        let games: UseStateHandle<Vec<PgnGame>> = games.clone();
+        let game_index: UseStateHandle<usize> = game_index.clone();
+        let ply: UseStateHandle<usize> = ply.clone();
+        let pgn_error: UseStateHandle<Option<String>> = pgn_error.clone();
+        Callback::from(move |(index, new_ply): (usize, usize)| {
+            if let Some(game) = games.get(index) {
+                open_game(game, index, new_ply, &board, &game_index, &ply, &pgn_error);
+            }
+        })
+    };
+
+    let on_load: Callback<String> = {
+        let board: UseStateHandle<Board> = board.clone();
+        let games: UseStateHandle<Vec<PgnGame>> = games.clone();
+        let game_index: UseStateHandle<usize> = game_index.clone();
+        let ply: UseStateHandle<usize> = ply.clone();
+        let pgn_error: UseStateHandle<Option<String>> = pgn_error.clone();
+        Callback::from(move |text: String| match parse_pgn(&text) {
+            Ok(new_games) if new_games.is_empty() => {
+                pgn_error.set(Some("no game found".to_string()));
+            }
+            Ok(new_games) => {
+                open_game(&new_games[0], 0, 0, &board, &game_index, &ply, &pgn_error);
+                games.set(new_games);
+            }
+            Err(error) => pgn_error.set(Some(error.to_string())),
+        })
+    };
+
+    let on_input: Callback<InputEvent> = {
+        let pgn_text: UseStateHandle<String> = pgn_text.clone();
+        Callback::from(move |event: InputEvent| {
+            let textarea: HtmlTextAreaElement = event.target_unchecked_into();
+            pgn_text.set(textarea.value());
+        })
+    };
+
+    let on_drop: Callback<DragEvent> = {
+        let pgn_text: UseStateHandle<String> = pgn_text.clone();
+        let on_load: Callback<String> = on_load.clone();
+        Callback::from(move |event: DragEvent| {
+            event.prevent_default();
+            let file: Option<web_sys::File> = event
+                .data_transfer()
+                .and_then(|data| data.files())
+                .and_then(|files| files.get(0));
+
+            if let Some(file) = file {
+                let pgn_text: UseStateHandle<String> = pgn_text.clone();
+                let on_load: Callback<String> = on_load.clone();
+                let reader: FileReader = gloo_file::callbacks::read_as_text(
+                    &gloo_file::File::from(file),
+                    move |result| {
+                        if let Ok(text) = result {
+                            pgn_text.set(text.clone());
+                            on_load.emit(text);
+                        }
+                    },
+                );
+                *file_reader.borrow_mut() = Some(reader);
+            }
+        })
+    };
+
+    let game_count: usize = games.len();
+    let ply_count: usize = games
+        .get(*game_index)
+        .map_or(0, |game| game.get_moves().len());
+    let index: usize = *game_index;
+    let current_ply: usize = *ply;
+
     html! {
-        <div>
-            {board.render(on_click)}
+        <div class={classes!("game")}>
+            <div class={classes!("shortcuts")} tabindex="0" onkeydown={on_key_down}>
+                {board.render(
+                    on_click,
+                    on_promote,
+                    on_auto_queen,
+                    on_claim_draw,
+                    on_undo,
+                    on_redo,
+                    on_pause,
+                    on_resign,
+                    on_offer_draw,
+                    on_answer_draw,
+                )}
+            </div>
+            <div class={classes!("computer")}>
+                <select class={classes!("setting")} onchange={on_player_color}>
+                    <option selected={*player_color == Color::White}>{"Play as white"}</option>
+                    <option selected={*player_color == Color::Black}>{"Play as black"}</option>
+                </select>
+                <select class={classes!("setting")} onchange={on_level}>
+                    {for (1..=MAX_LEVEL).map(|i| html! {
+                        <option selected={i == *level}>{format!("Level {}", i)}</option>
+                    })}
+                </select>
+                <select class={classes!("setting")} onchange={on_time_control}>
+                    {for TIME_CONTROLS.iter().enumerate().map(|(i, (name, _))| html! {
+                        <option selected={i == *time_control}>{*name}</option>
+                    })}
+                </select>
+                <button class={classes!("action")} onclick={on_new_game.reform(|_| ())}>{"New game"}</button>
+                <button class={classes!("action")} onclick={on_play_computer.reform(|_| ())}>{"Play vs computer"}</button>
+                <button class={classes!("action")} disabled={computer.is_none()} onclick={on_play_human.reform(|_| ())}>{"Two players"}</button>
+                if is_computer_turn {
+                    <p class={classes!("setting")}>{"Computer is thinking..."}</p>
+                }
+            </div>
+            <div class={classes!("saves")}>
+                <input
+                    class={classes!("setting")}
+                    type="text"
+                    placeholder="Name of the game"
+                    value={(*game_name).clone()}
+                    oninput={on_game_name}
+                />
+                <button class={classes!("action")} onclick={on_save_game.reform(|_| ())}>{"Save game"}</button>
+                if let Some(error) = &*save_error {
+                    <p class={classes!("import-error")}>{error}</p>
+                }
+                {for saved_games.iter().map(|(id, name)| {
+                    let id: u32 = *id;
+                    html! {
+                        <div class={classes!("saves-line")}>
+                            <span class={classes!("setting")}>{name}</span>
+                            <button class={classes!("action")} onclick={on_open_saved.reform(move |_| id)}>{"open"}</button>
+                            <button class={classes!("action")} title="Give it the name above" onclick={on_rename_saved.reform(move |_| id)}>{"rename"}</button>
+                            <button class={classes!("action")} onclick={on_delete_saved.reform(move |_| id)}>{"delete"}</button>
+                        </div>
+                    }
+                })}
+            </div>
+            <div
+                class={classes!("import")}
+                ondragover={Callback::from(|event: DragEvent| event.prevent_default())}
+                ondrop={on_drop}
+            >
+                <textarea
+                    class={classes!("import-text")}
+                    placeholder="Paste or drop a PGN file"
+                    value={(*pgn_text).clone()}
+                    oninput={on_input}
+                />
+                <button class={classes!("action")} onclick={
+                    let pgn_text: UseStateHandle<String> = pgn_text.clone();
+                    on_load.reform(move |_| (*pgn_text).clone())
+                }>{"Load PGN"}</button>
+                if let Some(error) = &*pgn_error {
+                    <p class={classes!("import-error")}>{error}</p>
+                }
+                if game_count > 1 {
+                    <select class={classes!("setting")} onchange={on_open_game.reform(|event: Event| {
+                        let select: HtmlSelectElement = event.target_unchecked_into();
+                        (select.selected_index().max(0) as usize, 0)
+                    })}>
+                        {for games.iter().enumerate().map(|(i, game)| html! {
+                            <option selected={i == index}>{get_game_title(game, i)}</option>
+                        })}
+                    </select>
+                }
+                if game_count > 0 {
+                    <div class={classes!("import-steps")}>
+                        <button class={classes!("action")} onclick={on_open_game.reform(move |_| (index, 0))}>{"<<"}</button>
+                        <button class={classes!("action")} onclick={on_open_game.reform(move |_| (index, current_ply.saturating_sub(1)))}>{"<"}</button>
+                        <span class={classes!("setting")}>{format!("{}/{}", current_ply, ply_count)}</span>
+                        <button class={classes!("action")} onclick={on_open_game.reform(move |_| (index, (current_ply + 1).min(ply_count)))}>{">"}</button>
+                        <button class={classes!("action")} onclick={on_open_game.reform(move |_| (index, ply_count))}>{">>"}</button>
+                    </div>
+                }
+            </div>
         </div>
     }
 }
+
+/// Shows a saved game on the board with the settings it was played with. The time
+/// control of the game is selected for the next game when it is one of those offered.
+///
+/// # Arguments
+///
+/// * `saved` - The saved game.
+/// * `board` - The board state to update.
+/// * `computer` - The computer color state to update.
+/// * `level` - The computer level state to update.
+/// * `time_control` - The selected time control state to update.
+/// * `game_name` - The game name state to update.
+fn open_saved_game(
+    saved: &SavedGame,
+    board: &UseStateHandle<Board>,
+    computer: &UseStateHandle<Option<Color>>,
+    level: &UseStateHandle<u32>,
+    time_control: &UseStateHandle<usize>,
+    game_name: &UseStateHandle<String>,
+) {
+    let saved_time_control: Option<&TimeControl> = saved
+        .get_board()
+        .get_clock()
+        .map(|clock| clock.get_time_control());
+    let index: Option<usize> = TIME_CONTROLS.iter().position(|(_, text)| {
+        text.and_then(|text| TimeControl::parse(text).ok()).as_ref() == saved_time_control
+    });
+
+    board.set(saved.get_board().clone());
+    computer.set(saved.get_computer());
+    level.set(saved.get_level());
+    if let Some(index) = index {
+        time_control.set(index);
+    }
+    game_name.set(saved.get_name().to_string());
+}
+
+/// Creates the board of a new game, with clocks when a time control is chosen.
+///
+/// # Arguments
+///
+/// * `time_control` - The index of the chosen time control in `TIME_CONTROLS`.
+///
+/// # Returns
+///
+/// The board in the starting position, its clocks running.
+fn new_game(time_control: usize) -> Board {
+    let mut board: Board = Board::new().initialize();
+    board.set_time_control(
+        TIME_CONTROLS
+            .get(time_control)
+            .and_then(|(_, text)| *text)
+            .and_then(|text| TimeControl::parse(text).ok()),
+    );
+    board
+}
+
+/// Shows an imported game on the board after the given number of half moves.
+///
+/// The whole game, variations included, is replayed first so an illegal move
+/// anywhere in it is reported even when stepping through an earlier ply.
+///
+/// # Arguments
+///
+/// * `game` - The imported game.
+/// * `index` - The index of the game in the imported file.
+/// * `new_ply` - The number of half moves to play.
+/// * `board` - The board state to update.
+/// * `game_index` - The selected game state to update.
+/// * `ply` - The current ply state to update.
+/// * `pgn_error` - The import error state to update.
+fn open_game(
+    game: &PgnGame,
+    index: usize,
+    new_ply: usize,
+    board: &UseStateHandle<Board>,
+    game_index: &UseStateHandle<usize>,
+    ply: &UseStateHandle<usize>,
+    pgn_error: &UseStateHandle<Option<String>>,
+) {
+    game_index.set(index);
+    ply.set(new_ply);
+
+    if let Err(error) = game.replay() {
+        pgn_error.set(Some(error.to_string()));
+    } else {
+        pgn_error.set(None);
+    }
+    if let Ok(new_board) = game.get_board_at(new_ply) {
+        board.set(new_board);
+    }
+}
+
+/// Gets the title of an imported game shown in the game list.
+///
+/// # Arguments
+///
+/// * `game` - The imported game.
+/// * `index` - The index of the game in the imported file.
+///
+/// # Returns
+///
+/// A `String` such as `1. Carlsen - Nepomniachtchi (1-0)`.
+fn get_game_title(game: &PgnGame, index: usize) -> String {
+    let tags: &PgnTags = game.get_tags();
+    format!(
+        "{}. {} - {} ({})",
+        index + 1,
+        tags.get("White").unwrap_or("?"),
+        tags.get("Black").unwrap_or("?"),
+        game.get_result()
+    )
+}
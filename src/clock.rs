@@ -0,0 +1,752 @@
+use std::{
+    fmt,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use crate::color::Color;
+
+/// Represents a source of the current time, in milliseconds since an arbitrary
+/// origin. Clocks only compare two readings of the same source.
+pub trait TimeSource: Send + Sync {
+    /// Reads the current time.
+    ///
+    /// # Returns
+    ///
+    /// The number of milliseconds since the origin of the source.
+    fn now_ms(&self) -> u64;
+}
+
+/// Represents the time of the system, or of the browser in the web front-end.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SystemTimeSource;
+
+impl TimeSource for SystemTimeSource {
+    #[cfg(target_arch = "wasm32")]
+    fn now_ms(&self) -> u64 {
+        js_sys::Date::now() as u64
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn now_ms(&self) -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis() as u64)
+    }
+}
+
+/// Represents a time that only moves when told to, used to test clocks.
+#[derive(Debug, Default)]
+pub struct MockTimeSource {
+    /// The current time in milliseconds.
+    now: AtomicU64,
+}
+
+impl MockTimeSource {
+    /// Creates a source stopped at a given time.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// A new `MockTimeSource` instance.
+    pub fn new(now: u64) -> MockTimeSource {
+        MockTimeSource {
+            now: AtomicU64::new(now),
+        }
+    }
+
+    /// Sets the current time.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time in milliseconds.
+    pub fn set(&self, now: u64) {
+        self.now.store(now, Ordering::Relaxed);
+    }
+
+    /// Moves the current time forward.
+    ///
+    /// # Arguments
+    ///
+    /// * `milliseconds` - The time to add.
+    pub fn advance(&self, milliseconds: u64) {
+        self.now.fetch_add(milliseconds, Ordering::Relaxed);
+    }
+}
+
+impl TimeSource for MockTimeSource {
+    fn now_ms(&self) -> u64 {
+        self.now.load(Ordering::Relaxed)
+    }
+}
+
+/// Represents the time given back to a player after each move.
+///
+/// # Variants
+///
+/// - `None`: No time is given back, as in sudden death.
+/// - `Fischer`: A fixed time is added after each move.
+/// - `Bronstein`: The time spent on the move is given back, up to a fixed delay.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Increment {
+    None,
+    Fischer(Duration),
+    Bronstein(Duration),
+}
+
+impl Increment {
+    /// Gets the time given back after a move.
+    ///
+    /// # Arguments
+    ///
+    /// * `spent` - The time spent on the move.
+    ///
+    /// # Returns
+    ///
+    /// The time to add to the clock.
+    pub fn get_bonus(&self, spent: Duration) -> Duration {
+        match self {
+            Increment::None => Duration::ZERO,
+            Increment::Fischer(increment) => *increment,
+            Increment::Bronstein(delay) => spent.min(*delay),
+        }
+    }
+
+    /// Gets the largest time given back after a move, as the increment announced to
+    /// engines which know no other kind.
+    ///
+    /// # Returns
+    ///
+    /// The increment or the delay, zero without either.
+    pub fn get_duration(&self) -> Duration {
+        match self {
+            Increment::None => Duration::ZERO,
+            Increment::Fischer(duration) | Increment::Bronstein(duration) => *duration,
+        }
+    }
+}
+
+/// Represents one stage of a time control: a time for a number of moves, or for
+/// the rest of the game.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stage {
+    /// The number of moves of the stage, or `None` for the rest of the game.
+    moves: Option<u32>,
+    /// The time added to the clock when the stage starts.
+    time: Duration,
+}
+
+impl Stage {
+    /// Creates a stage.
+    ///
+    /// # Arguments
+    ///
+    /// * `moves` - The number of moves of the stage, or `None` for the rest of the game.
+    /// * `time` - The time added to the clock when the stage starts.
+    ///
+    /// # Returns
+    ///
+    /// A new `Stage` instance.
+    pub fn new(moves: Option<u32>, time: Duration) -> Stage {
+        Stage {
+            moves: moves.map(|moves| moves.max(1)),
+            time,
+        }
+    }
+
+    /// Gets the number of moves of the stage.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<u32>` containing the number of moves, or `None` for the rest of the game.
+    pub fn get_moves(&self) -> Option<u32> {
+        self.moves
+    }
+
+    /// Gets the time added to the clock when the stage starts.
+    ///
+    /// # Returns
+    ///
+    /// The time of the stage as a `Duration`.
+    pub fn get_time(&self) -> Duration {
+        self.time
+    }
+}
+
+/// Represents the reasons a time control can be rejected.
+///
+/// # Variants
+///
+/// - `Empty`: The time control has no stage.
+/// - `InvalidStage`: A stage is malformed or gives no time.
+/// - `StageAfterRestOfGame`: A stage follows a stage lasting the rest of the game.
+/// - `MixedIncrements`: The stages do not share the same increment.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TimeControlError {
+    Empty,
+    InvalidStage(String),
+    StageAfterRestOfGame,
+    MixedIncrements,
+}
+
+impl fmt::Display for TimeControlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeControlError::Empty => write!(f, "the time control has no stage"),
+            TimeControlError::InvalidStage(stage) => write!(f, "invalid stage '{}'", stage),
+            TimeControlError::StageAfterRestOfGame => {
+                write!(f, "a stage follows the stage lasting the rest of the game")
+            }
+            TimeControlError::MixedIncrements => {
+                write!(f, "the stages have different increments")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TimeControlError {}
+
+/// Represents the time control of a game: its stages and the time given back after
+/// each move. The last stage is repeated when it has a number of moves.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "String", try_from = "String")
+)]
+pub struct TimeControl {
+    /// The stages, in the order they are played.
+    stages: Vec<Stage>,
+    /// The time given back after each move.
+    increment: Increment,
+}
+
+impl TimeControl {
+    /// Creates a time control.
+    ///
+    /// # Arguments
+    ///
+    /// * `stages` - The stages, in the order they are played.
+    /// * `increment` - The time given back after each move.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the time control, or a `TimeControlError` if it has no
+    /// stage or a stage follows the rest of the game.
+    pub fn new(stages: Vec<Stage>, increment: Increment) -> Result<TimeControl, TimeControlError> {
+        if stages.is_empty() {
+            return Err(TimeControlError::Empty);
+        }
+        if stages[..stages.len() - 1]
+            .iter()
+            .any(|stage| stage.moves.is_none())
+        {
+            return Err(TimeControlError::StageAfterRestOfGame);
+        }
+        Ok(TimeControl { stages, increment })
+    }
+
+    /// Creates a time control giving a single time for the whole game.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time of each player.
+    /// * `increment` - The time given back after each move.
+    ///
+    /// # Returns
+    ///
+    /// A new `TimeControl` instance.
+    pub fn sudden_death(time: Duration, increment: Increment) -> TimeControl {
+        TimeControl {
+            stages: vec![Stage::new(None, time)],
+            increment,
+        }
+    }
+
+    /// Reads a time control written as stages separated by colons. A stage is
+    /// `[moves/]seconds`, optionally followed by `+increment` for a Fischer increment
+    /// or `d` and a delay for a Bronstein delay, which applies to the whole game.
+    /// Times are in seconds, for example `300+2`, `600d5` or `40/5400+30:1800+30`.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The time control.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the time control, or a `TimeControlError` if it is
+    /// malformed.
+    pub fn parse(text: &str) -> Result<TimeControl, TimeControlError> {
+        let mut stages: Vec<Stage> = Vec::new();
+        let mut increment: Increment = Increment::None;
+
+        for field in text.split(':') {
+            let error = || TimeControlError::InvalidStage(field.to_string());
+            let (moves, time): (Option<&str>, &str) = match field.split_once('/') {
+                Some((moves, time)) => (Some(moves), time),
+                None => (None, field),
+            };
+            let (time, stage_increment): (&str, Increment) =
+                if let Some((time, bonus)) = time.split_once('+') {
+                    (
+                        time,
+                        Increment::Fischer(parse_seconds(bonus).ok_or_else(error)?),
+                    )
+                } else if let Some((time, delay)) = time.split_once('d') {
+                    (
+                        time,
+                        Increment::Bronstein(parse_seconds(delay).ok_or_else(error)?),
+                    )
+                } else {
+                    (time, Increment::None)
+                };
+            let moves: Option<u32> = match moves {
+                Some(moves) => Some(
+                    moves
+                        .parse()
+                        .ok()
+                        .filter(|moves| *moves > 0)
+                        .ok_or_else(error)?,
+                ),
+                None => None,
+            };
+            let time: Duration = parse_seconds(time)
+                .filter(|time| !time.is_zero())
+                .ok_or_else(error)?;
+
+            if stage_increment != Increment::None {
+                if increment != Increment::None && increment != stage_increment {
+                    return Err(TimeControlError::MixedIncrements);
+                }
+                increment = stage_increment;
+            }
+            stages.push(Stage::new(moves, time));
+        }
+
+        TimeControl::new(stages, increment)
+    }
+
+    /// Gets the stages of the time control.
+    ///
+    /// # Returns
+    ///
+    /// A slice containing the stages in the order they are played.
+    pub fn get_stages(&self) -> &[Stage] {
+        &self.stages
+    }
+
+    /// Gets the time given back after each move.
+    ///
+    /// # Returns
+    ///
+    /// The `Increment` of the time control.
+    pub fn get_increment(&self) -> Increment {
+        self.increment
+    }
+
+    /// Gets the time each player starts with.
+    ///
+    /// # Returns
+    ///
+    /// The time of the first stage.
+    pub fn get_initial_time(&self) -> Duration {
+        self.stages[0].time
+    }
+
+    /// Gets the time added to a clock after a move, when a stage ends there.
+    ///
+    /// # Arguments
+    ///
+    /// * `moves` - The number of moves the player has played, that move included.
+    ///
+    /// # Returns
+    ///
+    /// The time of the next stage, zero if the move does not end a stage.
+    fn get_stage_bonus(&self, moves: u32) -> Duration {
+        let mut stage_end: u32 = 0;
+
+        for (index, stage) in self.stages.iter().enumerate() {
+            let stage_moves: u32 = match stage.moves {
+                Some(stage_moves) => stage_moves,
+                None => return Duration::ZERO,
+            };
+            stage_end += stage_moves;
+            if moves == stage_end {
+                return self.stages.get(index + 1).unwrap_or(stage).time;
+            }
+            if moves < stage_end {
+                return Duration::ZERO;
+            }
+            if index == self.stages.len() - 1 && (moves - stage_end).is_multiple_of(stage_moves) {
+                return stage.time;
+            }
+        }
+        Duration::ZERO
+    }
+
+    /// Gets the number of moves a player must play before the end of the stage.
+    ///
+    /// # Arguments
+    ///
+    /// * `moves` - The number of moves the player has played.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<u32>` containing the number of moves, or `None` during a stage
+    /// lasting the rest of the game.
+    pub fn get_moves_to_go(&self, moves: u32) -> Option<u32> {
+        let mut stage_end: u32 = 0;
+
+        for stage in self.stages.iter() {
+            stage_end += stage.moves?;
+            if moves < stage_end {
+                return Some(stage_end - moves);
+            }
+        }
+        let last_moves: u32 = self.stages.last()?.moves?;
+        Some(last_moves - (moves - stage_end) % last_moves)
+    }
+
+    /// Writes the time control as the value of a PGN `TimeControl` tag, where a
+    /// Fischer increment is repeated on every stage. PGN has no notation for a
+    /// Bronstein delay, so such a time control is written as unknown, `?`.
+    ///
+    /// # Returns
+    ///
+    /// The value of the tag, for example `40/5400+30:1800+30`.
+    pub fn to_pgn_tag(&self) -> String {
+        let increment: String = match self.increment {
+            Increment::None => String::new(),
+            Increment::Fischer(increment) => format!("+{}", increment.as_secs_f64()),
+            Increment::Bronstein(_) => return "?".to_string(),
+        };
+        self.stages
+            .iter()
+            .map(|stage| match stage.moves {
+                Some(moves) => format!("{}/{}{}", moves, stage.time.as_secs_f64(), increment),
+                None => format!("{}{}", stage.time.as_secs_f64(), increment),
+            })
+            .collect::<Vec<String>>()
+            .join(":")
+    }
+}
+
+impl fmt::Display for TimeControl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, stage) in self.stages.iter().enumerate() {
+            if index > 0 {
+                write!(f, ":")?;
+            }
+            if let Some(moves) = stage.moves {
+                write!(f, "{}/", moves)?;
+            }
+            write!(f, "{}", stage.time.as_secs_f64())?;
+        }
+        match self.increment {
+            Increment::None => Ok(()),
+            Increment::Fischer(increment) => write!(f, "+{}", increment.as_secs_f64()),
+            Increment::Bronstein(delay) => write!(f, "d{}", delay.as_secs_f64()),
+        }
+    }
+}
+
+impl From<TimeControl> for String {
+    fn from(time_control: TimeControl) -> String {
+        time_control.to_string()
+    }
+}
+
+impl TryFrom<String> for TimeControl {
+    type Error = TimeControlError;
+
+    fn try_from(text: String) -> Result<TimeControl, TimeControlError> {
+        TimeControl::parse(&text)
+    }
+}
+
+/// Reads a number of seconds, which may have decimals.
+///
+/// # Arguments
+///
+/// * `text` - The number of seconds.
+///
+/// # Returns
+///
+/// An `Option<Duration>` containing the time, or `None` if the text is not a
+/// non-negative number.
+fn parse_seconds(text: &str) -> Option<Duration> {
+    text.parse::<f64>()
+        .ok()
+        .filter(|seconds| seconds.is_finite() && *seconds >= 0.0)
+        .map(Duration::from_secs_f64)
+}
+
+/// Represents the clocks of both players. The clock of one player runs at a time,
+/// and every reading takes the current time in milliseconds, as given by a
+/// `TimeSource`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Clock {
+    /// The time control of the game.
+    time_control: TimeControl,
+    /// The time left to white when its clock last stopped.
+    white: Duration,
+    /// The time left to black when its clock last stopped.
+    black: Duration,
+    /// The number of moves played by white.
+    white_moves: u32,
+    /// The number of moves played by black.
+    black_moves: u32,
+    /// The color of the player whose clock runs, or would run without the pause.
+    turn: Color,
+    /// The time already spent on the current move before the last pause.
+    spent: Duration,
+    /// The time the clock last started or resumed, or `None` while it is paused.
+    started_at: Option<u64>,
+}
+
+impl Clock {
+    /// Creates paused clocks where both players have the time of the first stage.
+    ///
+    /// # Arguments
+    ///
+    /// * `time_control` - The time control of the game.
+    /// * `turn` - The color of the player who moves first.
+    ///
+    /// # Returns
+    ///
+    /// A new `Clock` instance.
+    pub fn new(time_control: TimeControl, turn: Color) -> Clock {
+        let time: Duration = time_control.get_initial_time();
+        Clock {
+            time_control,
+            white: time,
+            black: time,
+            white_moves: 0,
+            black_moves: 0,
+            turn,
+            spent: Duration::ZERO,
+            started_at: None,
+        }
+    }
+
+    /// Creates paused clocks in the middle of a game, such as a game saved earlier.
+    ///
+    /// # Arguments
+    ///
+    /// * `time_control` - The time control of the game.
+    /// * `turn` - The color of the player to move.
+    /// * `white` - The time left to white.
+    /// * `black` - The time left to black.
+    /// * `white_moves` - The number of moves played by white.
+    /// * `black_moves` - The number of moves played by black.
+    ///
+    /// # Returns
+    ///
+    /// A new `Clock` instance.
+    pub fn restore(
+        time_control: TimeControl,
+        turn: Color,
+        white: Duration,
+        black: Duration,
+        white_moves: u32,
+        black_moves: u32,
+    ) -> Clock {
+        Clock {
+            time_control,
+            white,
+            black,
+            white_moves,
+            black_moves,
+            turn,
+            spent: Duration::ZERO,
+            started_at: None,
+        }
+    }
+
+    /// Gets the time control of the game.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `TimeControl` of the game.
+    pub fn get_time_control(&self) -> &TimeControl {
+        &self.time_control
+    }
+
+    /// Gets the color of the player whose clock runs.
+    ///
+    /// # Returns
+    ///
+    /// The `Color` of the player to move.
+    pub fn get_turn(&self) -> Color {
+        self.turn
+    }
+
+    /// Checks if the clocks are paused.
+    ///
+    /// # Returns
+    ///
+    /// `true` if no clock runs, `false` otherwise.
+    pub fn is_paused(&self) -> bool {
+        self.started_at.is_none()
+    }
+
+    /// Gets the time spent on the current move.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// The time spent since the move started, pauses excluded.
+    fn get_spent(&self, now: u64) -> Duration {
+        self.spent
+            + self.started_at.map_or(Duration::ZERO, |started_at| {
+                Duration::from_millis(now.saturating_sub(started_at))
+            })
+    }
+
+    /// Gets the time left to a player.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color of the player.
+    /// * `now` - The current time in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// The time left as a `Duration`, zero once the flag has fallen.
+    pub fn get_remaining(&self, color: Color, now: u64) -> Duration {
+        let remaining: Duration = match color {
+            Color::White => self.white,
+            Color::Black => self.black,
+        };
+        if color == self.turn {
+            remaining.saturating_sub(self.get_spent(now))
+        } else {
+            remaining
+        }
+    }
+
+    /// Gets the number of moves a player must play before the time of the next stage
+    /// is added.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color of the player.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<u32>` containing the number of moves, or `None` during a stage
+    /// lasting the rest of the game.
+    pub fn get_moves_to_go(&self, color: Color) -> Option<u32> {
+        self.time_control.get_moves_to_go(match color {
+            Color::White => self.white_moves,
+            Color::Black => self.black_moves,
+        })
+    }
+
+    /// Gets the player whose time has run out.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Color>` containing the player to move if its flag has fallen,
+    /// `None` otherwise.
+    pub fn get_flag(&self, now: u64) -> Option<Color> {
+        if self.get_remaining(self.turn, now).is_zero() {
+            Some(self.turn)
+        } else {
+            None
+        }
+    }
+
+    /// Starts or resumes the clock of the player to move.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time in milliseconds.
+    pub fn resume(&mut self, now: u64) {
+        if self.started_at.is_none() {
+            self.started_at = Some(now);
+        }
+    }
+
+    /// Stops the clock of the player to move, keeping the time spent on the move.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time in milliseconds.
+    pub fn pause(&mut self, now: u64) {
+        self.spent = self.get_spent(now);
+        self.started_at = None;
+    }
+
+    /// Ends the move of the player to move: takes the time spent from its clock, gives
+    /// back the increment and the time of the next stage, then starts the clock of
+    /// the opponent unless the clocks are paused.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// `false` if the flag of the player had fallen, which leaves the clocks
+    /// unchanged, `true` otherwise.
+    pub fn press(&mut self, now: u64) -> bool {
+        if self.get_flag(now).is_some() {
+            self.pause(now);
+            return false;
+        }
+
+        let spent: Duration = self.get_spent(now);
+        let bonus: Duration = self.time_control.increment.get_bonus(spent);
+        let (remaining, moves): (&mut Duration, &mut u32) = match self.turn {
+            Color::White => (&mut self.white, &mut self.white_moves),
+            Color::Black => (&mut self.black, &mut self.black_moves),
+        };
+        *remaining = *remaining - spent + bonus;
+        *moves += 1;
+        *remaining += self.time_control.get_stage_bonus(*moves);
+
+        self.turn = !self.turn;
+        self.spent = Duration::ZERO;
+        if self.started_at.is_some() {
+            self.started_at = Some(now);
+        }
+        true
+    }
+}
+
+/// Formats a time as shown on a clock: hours, minutes and seconds, with tenths of
+/// a second under ten seconds.
+///
+/// # Arguments
+///
+/// * `time` - The time to format.
+///
+/// # Returns
+///
+/// A `String` such as `1:30:00`, `4:59` or `0:07.3`.
+pub fn format_time(time: Duration) -> String {
+    let seconds: u64 = time.as_secs();
+    if seconds >= 3600 {
+        format!(
+            "{}:{:02}:{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    } else if seconds >= 10 {
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    } else {
+        format!("0:{:02}.{}", seconds, time.subsec_millis() / 100)
+    }
+}
@@ -6,7 +6,12 @@ use std::ops::Not;
 ///
 /// - `Black`: Represents black color.
 /// - `White`: Represents white color.
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum Color {
     Black,
     White,
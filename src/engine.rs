@@ -0,0 +1,1048 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::{
+    bitboard::get_squares, board::Board, color::Color, kind::Kind, moves::Move, piece::Piece,
+    setup::Setup,
+};
+
+/// A score above every evaluation, used as the bounds of the search window.
+const INFINITY: i32 = 32_000;
+
+/// The score of a checkmate on the board, reduced by one for each half move
+/// needed to reach it.
+const MATE: i32 = 31_000;
+
+/// The deepest half move the search can reach, quiescence included.
+const MAX_PLY: u32 = 96;
+
+/// The number of nodes searched between two checks of the stop conditions.
+const CHECK_INTERVAL: u64 = 1024;
+
+/// The size of the transposition table in megabytes when none is given.
+pub const DEFAULT_HASH_SIZE: usize = 16;
+
+/// The number of moves the remaining time is shared between when the time control
+/// does not say how many moves are left.
+const DEFAULT_MOVES_TO_GO: u32 = 30;
+
+/// The time kept aside for the communication around each move.
+const MOVE_OVERHEAD: Duration = Duration::from_millis(50);
+
+/// The kinds of pieces scored by the evaluation, in the order of `PIECE_SQUARE_TABLES`.
+const KINDS: [Kind; 6] = [
+    Kind::Pawn,
+    Kind::Knight,
+    Kind::Bishop,
+    Kind::Rook,
+    Kind::Queen,
+    Kind::King,
+];
+
+/// The bonus in centipawns of a white piece on each square, from a8 to h1, for
+/// each kind of `KINDS`. Black pieces read the tables upside down.
+#[rustfmt::skip]
+const PIECE_SQUARE_TABLES: [[i32; 64]; 6] = [
+    [
+          0,   0,   0,   0,   0,   0,   0,   0,
+         50,  50,  50,  50,  50,  50,  50,  50,
+         10,  10,  20,  30,  30,  20,  10,  10,
+          5,   5,  10,  25,  25,  10,   5,   5,
+          0,   0,   0,  20,  20,   0,   0,   0,
+          5,  -5, -10,   0,   0, -10,  -5,   5,
+          5,  10,  10, -20, -20,  10,  10,   5,
+          0,   0,   0,   0,   0,   0,   0,   0,
+    ],
+    [
+        -50, -40, -30, -30, -30, -30, -40, -50,
+        -40, -20,   0,   0,   0,   0, -20, -40,
+        -30,   0,  10,  15,  15,  10,   0, -30,
+        -30,   5,  15,  20,  20,  15,   5, -30,
+        -30,   0,  15,  20,  20,  15,   0, -30,
+        -30,   5,  10,  15,  15,  10,   5, -30,
+        -40, -20,   0,   5,   5,   0, -20, -40,
+        -50, -40, -30, -30, -30, -30, -40, -50,
+    ],
+    [
+        -20, -10, -10, -10, -10, -10, -10, -20,
+        -10,   0,   0,   0,   0,   0,   0, -10,
+        -10,   0,   5,  10,  10,   5,   0, -10,
+        -10,   5,   5,  10,  10,   5,   5, -10,
+        -10,   0,  10,  10,  10,  10,   0, -10,
+        -10,  10,  10,  10,  10,  10,  10, -10,
+        -10,   5,   0,   0,   0,   0,   5, -10,
+        -20, -10, -10, -10, -10, -10, -10, -20,
+    ],
+    [
+          0,   0,   0,   0,   0,   0,   0,   0,
+          5,  10,  10,  10,  10,  10,  10,   5,
+         -5,   0,   0,   0,   0,   0,   0,  -5,
+         -5,   0,   0,   0,   0,   0,   0,  -5,
+         -5,   0,   0,   0,   0,   0,   0,  -5,
+         -5,   0,   0,   0,   0,   0,   0,  -5,
+         -5,   0,   0,   0,   0,   0,   0,  -5,
+          0,   0,   0,   5,   5,   0,   0,   0,
+    ],
+    [
+        -20, -10, -10,  -5,  -5, -10, -10, -20,
+        -10,   0,   0,   0,   0,   0,   0, -10,
+        -10,   0,   5,   5,   5,   5,   0, -10,
+         -5,   0,   5,   5,   5,   5,   0,  -5,
+          0,   0,   5,   5,   5,   5,   0,  -5,
+        -10,   5,   5,   5,   5,   5,   0, -10,
+        -10,   0,   5,   0,   0,   0,   0, -10,
+        -20, -10, -10,  -5,  -5, -10, -10, -20,
+    ],
+    [
+        -30, -40, -40, -50, -50, -40, -40, -30,
+        -30, -40, -40, -50, -50, -40, -40, -30,
+        -30, -40, -40, -50, -50, -40, -40, -30,
+        -30, -40, -40, -50, -50, -40, -40, -30,
+        -20, -30, -30, -40, -40, -30, -30, -20,
+        -10, -20, -20, -20, -20, -20, -20, -10,
+         20,  20,   0,   0,   0,   0,  20,  20,
+         20,  30,  10,   0,   0,  10,  30,  20,
+    ],
+];
+
+/// Represents the score of a position for the side to move.
+///
+/// # Variants
+///
+/// - `Centipawns`: The advantage in hundredths of a pawn.
+/// - `Mate`: The number of moves to deliver mate, negative when the side to move
+///   is the one getting mated.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Score {
+    Centipawns(i32),
+    Mate(i32),
+}
+
+impl Score {
+    /// Converts a score of the search into a `Score`.
+    ///
+    /// # Arguments
+    ///
+    /// * `score` - The score returned by the search.
+    ///
+    /// # Returns
+    ///
+    /// The matching `Score`.
+    fn from_search(score: i32) -> Score {
+        if score > MATE - MAX_PLY as i32 {
+            Score::Mate((MATE - score + 1) / 2)
+        } else if score < -MATE + MAX_PLY as i32 {
+            Score::Mate(-(MATE + score) / 2)
+        } else {
+            Score::Centipawns(score)
+        }
+    }
+}
+
+/// Represents the limits of a search. A search without any limit only ends when
+/// it is stopped.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SearchLimits {
+    /// The deepest iteration to search, in half moves.
+    depth: Option<u32>,
+    /// The number of nodes after which the search stops.
+    nodes: Option<u64>,
+    /// The time after which the search stops.
+    movetime: Option<Duration>,
+}
+
+impl SearchLimits {
+    /// Creates limits that never stop the search.
+    ///
+    /// # Returns
+    ///
+    /// A new `SearchLimits` instance without any limit.
+    pub fn new() -> SearchLimits {
+        SearchLimits::default()
+    }
+
+    /// Sets the deepest iteration to search.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth` - The depth in half moves.
+    ///
+    /// # Returns
+    ///
+    /// The limits with the depth set.
+    pub fn with_depth(mut self, depth: u32) -> SearchLimits {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// Sets the number of nodes after which the search stops.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes` - The number of nodes.
+    ///
+    /// # Returns
+    ///
+    /// The limits with the node count set.
+    pub fn with_nodes(mut self, nodes: u64) -> SearchLimits {
+        self.nodes = Some(nodes);
+        self
+    }
+
+    /// Sets the time after which the search stops. The time is read from the
+    /// system clock, which is not available in the browser.
+    ///
+    /// # Arguments
+    ///
+    /// * `movetime` - The time to search.
+    ///
+    /// # Returns
+    ///
+    /// The limits with the time set.
+    pub fn with_movetime(mut self, movetime: Duration) -> SearchLimits {
+        self.movetime = Some(movetime);
+        self
+    }
+
+    /// Sets the time to search from the clock of the side to move. The remaining
+    /// time is shared between the moves left before the next time control, and
+    /// most of the increment is added.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time left on the clock.
+    /// * `increment` - The time added to the clock after the move.
+    /// * `moves_to_go` - The number of moves before the next time control, if any.
+    ///
+    /// # Returns
+    ///
+    /// The limits with the time set.
+    pub fn with_clock(
+        self,
+        time: Duration,
+        increment: Duration,
+        moves_to_go: Option<u32>,
+    ) -> SearchLimits {
+        let moves_to_go: u32 = moves_to_go.unwrap_or(DEFAULT_MOVES_TO_GO).max(1);
+        let budget: Duration = time / moves_to_go + increment * 3 / 4;
+        let budget: Duration = budget
+            .min(time.saturating_sub(MOVE_OVERHEAD))
+            .max(Duration::from_millis(1));
+        self.with_movetime(budget)
+    }
+
+    /// Gets the deepest iteration to search.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<u32>` containing the depth, if any.
+    pub fn get_depth(&self) -> Option<u32> {
+        self.depth
+    }
+
+    /// Gets the number of nodes after which the search stops.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<u64>` containing the node count, if any.
+    pub fn get_nodes(&self) -> Option<u64> {
+        self.nodes
+    }
+
+    /// Gets the time after which the search stops.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Duration>` containing the time, if any.
+    pub fn get_movetime(&self) -> Option<Duration> {
+        self.movetime
+    }
+}
+
+/// Represents the result of one iteration of the search.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchInfo {
+    /// The depth of the iteration, in half moves.
+    depth: u32,
+    /// The score of the position for the side to move.
+    score: Score,
+    /// The number of nodes searched since the search started.
+    nodes: u64,
+    /// The best line found, starting with the best move.
+    pv: Vec<Move>,
+}
+
+impl SearchInfo {
+    /// Gets the depth of the iteration.
+    ///
+    /// # Returns
+    ///
+    /// The depth in half moves.
+    pub fn get_depth(&self) -> u32 {
+        self.depth
+    }
+
+    /// Gets the score of the position for the side to move.
+    ///
+    /// # Returns
+    ///
+    /// The `Score` found by the iteration.
+    pub fn get_score(&self) -> Score {
+        self.score
+    }
+
+    /// Gets the number of nodes searched since the search started.
+    ///
+    /// # Returns
+    ///
+    /// The number of nodes.
+    pub fn get_nodes(&self) -> u64 {
+        self.nodes
+    }
+
+    /// Gets the best line found.
+    ///
+    /// # Returns
+    ///
+    /// A slice of the moves of the line, starting with the best move.
+    pub fn get_pv(&self) -> &[Move] {
+        &self.pv
+    }
+
+    /// Gets the best move found.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Move>` containing the best move, `None` if there is no legal move.
+    pub fn get_best_move(&self) -> Option<Move> {
+        self.pv.first().copied()
+    }
+}
+
+/// Represents the progress of an iteration searched in slices.
+///
+/// # Variants
+///
+/// - `Unfinished`: The slice ended before the iteration was complete.
+/// - `Finished`: The iteration is complete, with its result, `None` if the side
+///   to move has no legal move.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SliceResult {
+    Unfinished,
+    Finished(Option<SearchInfo>),
+}
+
+/// Represents how the score stored in a transposition table entry bounds the
+/// real score of the position.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Bound {
+    Exact,
+    Lower,
+    Upper,
+}
+
+/// Represents a position already searched, stored in the transposition table.
+#[derive(Clone, Copy, Debug)]
+struct Entry {
+    /// The Zobrist key of the position.
+    key: u64,
+    /// The depth the position was searched to.
+    depth: u32,
+    /// The score found, relative to the position for mate scores.
+    score: i32,
+    /// How the score bounds the real score.
+    bound: Bound,
+    /// The best move found, if any.
+    best_move: Option<Move>,
+}
+
+/// Represents an iteration of the root position, which can be interrupted between
+/// two slices and resumed.
+#[derive(Clone, Debug)]
+struct RootSearch {
+    /// The key of the root position.
+    key: u64,
+    /// The depth of the iteration, in half moves.
+    depth: u32,
+    /// The legal moves of the root position, in the order they are searched.
+    moves: Vec<Move>,
+    /// The index of the next move to search.
+    index: usize,
+    /// The best score found so far.
+    alpha: i32,
+    /// The best move found so far.
+    best_move: Move,
+    /// The number of nodes searched by the previous slices.
+    nodes: u64,
+    /// The number of nodes the next slice may search.
+    budget: u64,
+}
+
+/// Represents a computer player searching for the best move with iterative
+/// deepening alpha-beta, quiescence search and a transposition table.
+pub struct Engine {
+    /// The transposition table, indexed by the Zobrist key of the positions.
+    table: Vec<Option<Entry>>,
+    /// The flag raised to stop the search from another thread.
+    stop: Arc<AtomicBool>,
+    /// The number of nodes searched since the search started.
+    nodes: u64,
+    /// The limits of the current search.
+    limits: SearchLimits,
+    /// The time after which the current search stops, if any.
+    deadline: Option<Instant>,
+    /// Indicates whether the current iteration has been interrupted.
+    aborted: bool,
+    /// The keys of the positions played in the game and on the searched line.
+    history: Vec<u64>,
+    /// The iteration interrupted at the end of the last slice, if any.
+    slice: Option<RootSearch>,
+}
+
+impl Default for Engine {
+    fn default() -> Self {
+        Engine::new()
+    }
+}
+
+impl Engine {
+    /// Creates a new `Engine` with a transposition table of the default size.
+    ///
+    /// # Returns
+    ///
+    /// A new `Engine` instance.
+    pub fn new() -> Engine {
+        let mut engine: Engine = Engine {
+            table: Vec::new(),
+            stop: Arc::new(AtomicBool::new(false)),
+            nodes: 0,
+            limits: SearchLimits::new(),
+            deadline: None,
+            aborted: false,
+            history: Vec::new(),
+            slice: None,
+        };
+        engine.set_hash_size(DEFAULT_HASH_SIZE);
+        engine
+    }
+
+    /// Resizes the transposition table, which clears it.
+    ///
+    /// # Arguments
+    ///
+    /// * `megabytes` - The size of the table in megabytes.
+    pub fn set_hash_size(&mut self, megabytes: usize) {
+        let entries: usize = (megabytes.max(1) << 20) / std::mem::size_of::<Option<Entry>>().max(1);
+        self.table = vec![None; entries.max(1)];
+    }
+
+    /// Forgets every position searched so far, as between two games.
+    pub fn clear(&mut self) {
+        self.table.iter_mut().for_each(|entry| *entry = None);
+        self.slice = None;
+    }
+
+    /// Gets the flag that stops the search when raised, so that another thread can
+    /// end it early. The flag stays raised until the caller lowers it, which must
+    /// be done before starting the next search.
+    ///
+    /// # Returns
+    ///
+    /// A shared handle to the stop flag.
+    pub fn get_stop_flag(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.stop)
+    }
+
+    /// Searches the best move with iterative deepening until a limit is reached or
+    /// the search is stopped.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - The game to search, whose past positions count for repetitions.
+    /// * `limits` - The limits of the search.
+    /// * `on_info` - Called with the result of each completed iteration.
+    ///
+    /// # Returns
+    ///
+    /// The result of the deepest iteration, `None` if the side to move has no
+    /// legal move.
+    pub fn search(
+        &mut self,
+        board: &Board,
+        limits: SearchLimits,
+        mut on_info: impl FnMut(&SearchInfo),
+    ) -> Option<SearchInfo> {
+        self.start(board, limits);
+        let mut result: Option<SearchInfo> = None;
+        let mut depth: u32 = 1;
+
+        while depth < MAX_PLY && limits.depth.is_none_or(|max_depth| depth <= max_depth) {
+            match self.search_root(board, depth) {
+                Some(info) if !self.aborted || result.is_none() => {
+                    on_info(&info);
+                    result = Some(info);
+                }
+                _ => {}
+            }
+            // Searching deeper than a forced mate cannot change the result.
+            let is_mate_found: bool = matches!(
+                result.as_ref().map(|info| info.score),
+                Some(Score::Mate(moves)) if depth >= 2 * moves.unsigned_abs()
+            );
+            if self.aborted || result.is_none() || is_mate_found {
+                break;
+            }
+            depth += 1;
+        }
+        result
+    }
+
+    /// Searches a single iteration to a given depth. Iterations called one after
+    /// the other with increasing depths share the transposition table, which lets
+    /// the caller deepen the search step by step without blocking.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - The game to search, whose past positions count for repetitions.
+    /// * `depth` - The depth of the iteration, in half moves.
+    ///
+    /// # Returns
+    ///
+    /// The result of the iteration, `None` if the side to move has no legal move.
+    pub fn search_depth(&mut self, board: &Board, depth: u32) -> Option<SearchInfo> {
+        self.start(board, SearchLimits::new());
+        self.search_root(board, depth.clamp(1, MAX_PLY - 1))
+    }
+
+    /// Searches a slice of an iteration to a given depth, at most a given number of
+    /// nodes, so that a caller without threads can search in small steps. The next
+    /// call for the same position and depth resumes the iteration where it stopped,
+    /// and a root move which did not fit in a slice gets twice the nodes in the
+    /// next one, so the iteration always ends.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - The game to search, whose past positions count for repetitions.
+    /// * `depth` - The depth of the iteration, in half moves.
+    /// * `nodes` - The number of nodes of the slice.
+    ///
+    /// # Returns
+    ///
+    /// `SliceResult::Finished` with the result of the iteration once it is
+    /// complete, `SliceResult::Unfinished` otherwise.
+    pub fn search_slice(&mut self, board: &Board, depth: u32, nodes: u64) -> SliceResult {
+        let setup: Setup = board.get_setup();
+        let depth: u32 = depth.clamp(1, MAX_PLY - 1);
+        let mut root: RootSearch = match self.slice.take() {
+            Some(root) if root.key == setup.get_position_key() && root.depth == depth => root,
+            _ => match self.start_root(&setup, depth) {
+                Some(mut root) => {
+                    root.budget = nodes.max(1);
+                    root
+                }
+                None => return SliceResult::Finished(None),
+            },
+        };
+
+        self.start(board, SearchLimits::new().with_nodes(root.budget));
+        let index: usize = root.index;
+        self.search_root_moves(&setup, &mut root);
+        root.nodes += self.nodes;
+        if self.aborted {
+            root.budget = if root.index == index {
+                root.budget.saturating_mul(2)
+            } else {
+                nodes.max(1)
+            };
+            self.slice = Some(root);
+            SliceResult::Unfinished
+        } else {
+            SliceResult::Finished(Some(self.finish_root(&setup, &root)))
+        }
+    }
+
+    /// Prepares the engine for a new search.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - The game to search.
+    /// * `limits` - The limits of the search.
+    fn start(&mut self, board: &Board, limits: SearchLimits) {
+        self.nodes = 0;
+        self.limits = limits;
+        self.deadline = limits.movetime.map(|movetime| Instant::now() + movetime);
+        self.aborted = false;
+        self.history = board.get_position_keys().to_vec();
+    }
+
+    /// Searches every move of the root position to a given depth.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - The game to search.
+    /// * `depth` - The depth of the iteration, in half moves.
+    ///
+    /// # Returns
+    ///
+    /// The result of the iteration, or `None` if there is no legal move. An
+    /// interrupted iteration returns the best of the moves searched completely.
+    fn search_root(&mut self, board: &Board, depth: u32) -> Option<SearchInfo> {
+        let setup: Setup = board.get_setup();
+        let mut root: RootSearch = self.start_root(&setup, depth)?;
+        self.search_root_moves(&setup, &mut root);
+        root.nodes = self.nodes;
+        Some(self.finish_root(&setup, &root))
+    }
+
+    /// Prepares an iteration of the root position.
+    ///
+    /// # Arguments
+    ///
+    /// * `setup` - The root position.
+    /// * `depth` - The depth of the iteration, in half moves.
+    ///
+    /// # Returns
+    ///
+    /// The `RootSearch` of the iteration, or `None` if there is no legal move.
+    fn start_root(&self, setup: &Setup, depth: u32) -> Option<RootSearch> {
+        let key: u64 = setup.get_position_key();
+        let mut moves: Vec<Move> = setup.legal_moves();
+        if moves.is_empty() {
+            return None;
+        }
+        self.order_moves(
+            &mut moves,
+            self.probe(key).and_then(|entry| entry.best_move),
+        );
+
+        Some(RootSearch {
+            key,
+            depth,
+            best_move: moves[0],
+            moves,
+            index: 0,
+            alpha: -INFINITY,
+            nodes: 0,
+            budget: 0,
+        })
+    }
+
+    /// Searches the root moves not searched yet, until the last one or until the
+    /// search is interrupted. An interrupted move is searched again on resuming.
+    ///
+    /// # Arguments
+    ///
+    /// * `setup` - The root position.
+    /// * `root` - The iteration to continue.
+    fn search_root_moves(&mut self, setup: &Setup, root: &mut RootSearch) {
+        self.history.push(root.key);
+        while let Some(chess_move) = root.moves.get(root.index).copied() {
+            let score: i32 = -self.negamax(
+                &setup.play(chess_move),
+                root.depth - 1,
+                1,
+                -INFINITY,
+                -root.alpha,
+            );
+            if self.aborted {
+                break;
+            }
+            if score > root.alpha {
+                root.alpha = score;
+                root.best_move = chess_move;
+            }
+            root.index += 1;
+        }
+        self.history.pop();
+    }
+
+    /// Builds the result of an iteration, and stores it in the transposition table
+    /// when it is complete.
+    ///
+    /// # Arguments
+    ///
+    /// * `setup` - The root position.
+    /// * `root` - The iteration.
+    ///
+    /// # Returns
+    ///
+    /// The result of the iteration. An interrupted iteration gives the best of the
+    /// moves searched completely.
+    fn finish_root(&mut self, setup: &Setup, root: &RootSearch) -> SearchInfo {
+        if !self.aborted {
+            self.store(
+                root.key,
+                root.depth,
+                root.alpha,
+                Bound::Exact,
+                Some(root.best_move),
+                0,
+            );
+        }
+        SearchInfo {
+            depth: root.depth,
+            score: Score::from_search(root.alpha),
+            nodes: root.nodes,
+            pv: self.get_pv(setup, root.best_move, root.depth),
+        }
+    }
+
+    /// Searches a position with alpha-beta pruning.
+    ///
+    /// # Arguments
+    ///
+    /// * `setup` - The position to search.
+    /// * `depth` - The remaining depth, in half moves.
+    /// * `ply` - The number of half moves from the root.
+    /// * `alpha` - The score the side to move is already sure to get.
+    /// * `beta` - The score the opponent is already sure to hold the side to move to.
+    ///
+    /// # Returns
+    ///
+    /// The score of the position for the side to move.
+    fn negamax(&mut self, setup: &Setup, depth: u32, ply: u32, mut alpha: i32, beta: i32) -> i32 {
+        if self.should_stop() {
+            return 0;
+        }
+        let key: u64 = setup.get_position_key();
+        if self.history.contains(&key) {
+            return 0;
+        }
+
+        let mut table_move: Option<Move> = None;
+        if let Some(entry) = self.probe(key) {
+            table_move = entry.best_move;
+            if entry.depth >= depth {
+                let score: i32 = Engine::score_from_table(entry.score, ply);
+                match entry.bound {
+                    Bound::Exact => return score,
+                    Bound::Lower if score >= beta => return score,
+                    Bound::Upper if score <= alpha => return score,
+                    _ => {}
+                }
+            }
+        }
+
+        if depth == 0 || ply >= MAX_PLY {
+            return self.quiescence(setup, ply, alpha, beta);
+        }
+        self.nodes += 1;
+
+        let mut moves: Vec<Move> = setup.legal_moves();
+        if moves.is_empty() {
+            return if setup.is_check() {
+                -MATE + ply as i32
+            } else {
+                0
+            };
+        }
+        self.order_moves(&mut moves, table_move);
+
+        let original_alpha: i32 = alpha;
+        let mut best_score: i32 = -INFINITY;
+        let mut best_move: Option<Move> = None;
+        self.history.push(key);
+        for chess_move in moves {
+            let score: i32 =
+                -self.negamax(&setup.play(chess_move), depth - 1, ply + 1, -beta, -alpha);
+            if self.aborted {
+                self.history.pop();
+                return 0;
+            }
+            if score > best_score {
+                best_score = score;
+                best_move = Some(chess_move);
+            }
+            alpha = alpha.max(score);
+            if alpha >= beta {
+                break;
+            }
+        }
+        self.history.pop();
+
+        let bound: Bound = if best_score >= beta {
+            Bound::Lower
+        } else if best_score > original_alpha {
+            Bound::Exact
+        } else {
+            Bound::Upper
+        };
+        self.store(key, depth, best_score, bound, best_move, ply);
+        best_score
+    }
+
+    /// Searches the captures of a position until it is quiet, so that the
+    /// evaluation is never made in the middle of an exchange. Every move is
+    /// searched when the side to move is in check.
+    ///
+    /// # Arguments
+    ///
+    /// * `setup` - The position to search.
+    /// * `ply` - The number of half moves from the root.
+    /// * `alpha` - The score the side to move is already sure to get.
+    /// * `beta` - The score the opponent is already sure to hold the side to move to.
+    ///
+    /// # Returns
+    ///
+    /// The score of the position for the side to move.
+    fn quiescence(&mut self, setup: &Setup, ply: u32, mut alpha: i32, beta: i32) -> i32 {
+        if self.should_stop() {
+            return 0;
+        }
+        self.nodes += 1;
+
+        let is_check: bool = setup.is_check();
+        let mut moves: Vec<Move> = setup.legal_moves();
+        if moves.is_empty() {
+            return if is_check { -MATE + ply as i32 } else { 0 };
+        }
+
+        let mut best_score: i32 = -INFINITY;
+        if !is_check || ply >= MAX_PLY {
+            best_score = evaluate(setup);
+            if best_score >= beta || ply >= MAX_PLY {
+                return best_score;
+            }
+            alpha = alpha.max(best_score);
+            moves.retain(|chess_move| {
+                chess_move.is_capture() || chess_move.get_promotion().is_some()
+            });
+        }
+        self.order_moves(&mut moves, None);
+
+        for chess_move in moves {
+            let score: i32 = -self.quiescence(&setup.play(chess_move), ply + 1, -beta, -alpha);
+            if self.aborted {
+                return 0;
+            }
+            best_score = best_score.max(score);
+            alpha = alpha.max(score);
+            if alpha >= beta {
+                break;
+            }
+        }
+        best_score
+    }
+
+    /// Sorts moves so that the most promising are searched first: the best move
+    /// of the transposition table, then captures from the most valuable victim and
+    /// least valuable attacker, then promotions, then quiet moves.
+    ///
+    /// # Arguments
+    ///
+    /// * `moves` - The moves to sort.
+    /// * `table_move` - The best move stored in the transposition table, if any.
+    fn order_moves(&self, moves: &mut [Move], table_move: Option<Move>) {
+        moves.sort_by_cached_key(|chess_move| {
+            if Some(*chess_move) == table_move {
+                return i32::MIN;
+            }
+            let mut priority: i32 = 0;
+            if let Some(piece_captured) = chess_move.get_piece_captured() {
+                priority -=
+                    1000 + 10 * get_value(piece_captured) - get_value(chess_move.get_piece());
+            }
+            if let Some(kind) = chess_move.get_promotion() {
+                priority -= 100 * get_value(Piece::new(kind, Color::White));
+            }
+            priority
+        });
+    }
+
+    /// Checks the stop conditions every few nodes and remembers when one is met.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the search must stop, `false` otherwise.
+    fn should_stop(&mut self) -> bool {
+        if !self.aborted && self.nodes.is_multiple_of(CHECK_INTERVAL) {
+            self.aborted = self.stop.load(Ordering::Relaxed)
+                || self.limits.nodes.is_some_and(|nodes| self.nodes >= nodes)
+                || self
+                    .deadline
+                    .is_some_and(|deadline| Instant::now() >= deadline);
+        }
+        self.aborted
+    }
+
+    /// Looks a position up in the transposition table.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The Zobrist key of the position.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Entry>` containing the entry of the position, if stored.
+    fn probe(&self, key: u64) -> Option<Entry> {
+        self.table[self.get_index(key)].filter(|entry| entry.key == key)
+    }
+
+    /// Stores a searched position in the transposition table, replacing whatever
+    /// was stored at its index.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The Zobrist key of the position.
+    /// * `depth` - The depth the position was searched to.
+    /// * `score` - The score found.
+    /// * `bound` - How the score bounds the real score.
+    /// * `best_move` - The best move found, if any.
+    /// * `ply` - The number of half moves from the root.
+    fn store(
+        &mut self,
+        key: u64,
+        depth: u32,
+        score: i32,
+        bound: Bound,
+        best_move: Option<Move>,
+        ply: u32,
+    ) {
+        let index: usize = self.get_index(key);
+        self.table[index] = Some(Entry {
+            key,
+            depth,
+            score: Engine::score_to_table(score, ply),
+            bound,
+            best_move,
+        });
+    }
+
+    /// Gets the index of a position in the transposition table.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The Zobrist key of the position.
+    ///
+    /// # Returns
+    ///
+    /// The index of the entry.
+    fn get_index(&self, key: u64) -> usize {
+        (key % self.table.len() as u64) as usize
+    }
+
+    /// Makes a mate score relative to the position it is stored for.
+    ///
+    /// # Arguments
+    ///
+    /// * `score` - The score relative to the root.
+    /// * `ply` - The number of half moves from the root.
+    ///
+    /// # Returns
+    ///
+    /// The score to store.
+    fn score_to_table(score: i32, ply: u32) -> i32 {
+        if score > MATE - MAX_PLY as i32 {
+            score + ply as i32
+        } else if score < -MATE + MAX_PLY as i32 {
+            score - ply as i32
+        } else {
+            score
+        }
+    }
+
+    /// Makes a stored mate score relative to the root again.
+    ///
+    /// # Arguments
+    ///
+    /// * `score` - The stored score.
+    /// * `ply` - The number of half moves from the root.
+    ///
+    /// # Returns
+    ///
+    /// The score relative to the root.
+    fn score_from_table(score: i32, ply: u32) -> i32 {
+        if score > MATE - MAX_PLY as i32 {
+            score - ply as i32
+        } else if score < -MATE + MAX_PLY as i32 {
+            score + ply as i32
+        } else {
+            score
+        }
+    }
+
+    /// Follows the best moves stored in the transposition table from the root.
+    ///
+    /// # Arguments
+    ///
+    /// * `setup` - The root position.
+    /// * `best_move` - The best move of the root position.
+    /// * `depth` - The maximum length of the line.
+    ///
+    /// # Returns
+    ///
+    /// A vector containing the moves of the line.
+    fn get_pv(&self, setup: &Setup, best_move: Move, depth: u32) -> Vec<Move> {
+        let mut pv: Vec<Move> = vec![best_move];
+        let mut keys: Vec<u64> = vec![setup.get_position_key()];
+        let mut current: Setup = setup.play(best_move);
+
+        while (pv.len() as u32) < depth {
+            let key: u64 = current.get_position_key();
+            let chess_move: Move = match self.probe(key).and_then(|entry| entry.best_move) {
+                Some(chess_move)
+                    if !keys.contains(&key) && current.legal_moves().contains(&chess_move) =>
+                {
+                    chess_move
+                }
+                _ => break,
+            };
+            keys.push(key);
+            pv.push(chess_move);
+            current = current.play(chess_move);
+        }
+        pv
+    }
+}
+
+/// Evaluates a position from the material, taken from `Piece::get_value`, and
+/// the squares the pieces stand on.
+///
+/// # Arguments
+///
+/// * `setup` - The position to evaluate.
+///
+/// # Returns
+///
+/// The score of the position in centipawns for the side to move.
+pub fn evaluate(setup: &Setup) -> i32 {
+    let mut score: i32 = 0;
+
+    for (index, kind) in KINDS.iter().enumerate() {
+        for color in [Color::White, Color::Black] {
+            let piece: Piece = Piece::new(*kind, color);
+            let sign: i32 = if color == Color::White { 1 } else { -1 };
+
+            for square in get_squares(setup.get_bitboards().get_pieces(*kind, color)) {
+                let table_square: usize = match color {
+                    Color::White => (7 - square / 8) * 8 + square % 8,
+                    Color::Black => square,
+                };
+                score += sign * (100 * get_value(piece) + PIECE_SQUARE_TABLES[index][table_square]);
+            }
+        }
+    }
+
+    match setup.get_color_turn() {
+        Color::White => score,
+        Color::Black => -score,
+    }
+}
+
+/// Gets the value of a piece in pawns, with 0 for the king.
+///
+/// # Arguments
+///
+/// * `piece` - The piece.
+///
+/// # Returns
+///
+/// The value of the piece.
+fn get_value(piece: Piece) -> i32 {
+    piece.get_value().map_or(0, i32::from)
+}
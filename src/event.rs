@@ -0,0 +1,57 @@
+use crate::color::Color;
+
+/// Represents an action of a player which is not a move, recorded in the game.
+///
+/// # Variants
+///
+/// - `DrawOffered`: The given color has offered a draw.
+/// - `DrawAccepted`: The given color has accepted the draw offered by its opponent.
+/// - `DrawDeclined`: The given color has declined the draw, or moved instead of
+///   answering.
+/// - `Resigned`: The given color has resigned.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum GameEvent {
+    DrawOffered(Color),
+    DrawAccepted(Color),
+    DrawDeclined(Color),
+    Resigned(Color),
+}
+
+impl GameEvent {
+    /// Gets the color of the player behind the event.
+    ///
+    /// # Returns
+    ///
+    /// The `Color` of the player.
+    pub fn get_color(&self) -> Color {
+        match self {
+            GameEvent::DrawOffered(color)
+            | GameEvent::DrawAccepted(color)
+            | GameEvent::DrawDeclined(color)
+            | GameEvent::Resigned(color) => *color,
+        }
+    }
+
+    /// Gets a human readable description of the event, also written as a PGN comment.
+    ///
+    /// # Returns
+    ///
+    /// A `String` such as `White offers a draw`.
+    pub fn get_description(&self) -> String {
+        let player: &str = match self.get_color() {
+            Color::White => "White",
+            Color::Black => "Black",
+        };
+        match self {
+            GameEvent::DrawOffered(_) => format!("{} offers a draw", player),
+            GameEvent::DrawAccepted(_) => format!("{} accepts the draw", player),
+            GameEvent::DrawDeclined(_) => format!("{} declines the draw", player),
+            GameEvent::Resigned(_) => format!("{} resigns", player),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use std::fmt;
+
+use crate::color::Color;
+
+/// The FEN of the standard chess starting position.
+pub const STARTING_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+/// Represents the reasons a FEN (Forsyth-Edwards Notation) string can be rejected.
+///
+/// # Variants
+///
+/// - `WrongFieldCount`: The FEN does not contain six space separated fields.
+/// - `WrongRankCount`: The piece placement does not describe eight ranks.
+/// - `WrongRankLength`: A rank does not describe exactly eight squares.
+/// - `UnknownPiece`: A character of the piece placement is not a piece letter.
+/// - `InvalidSideToMove`: The side to move is neither `w` nor `b`.
+/// - `InvalidCastling`: The castling field is malformed or does not match the pieces.
+/// - `InvalidEnPassant`: The en passant field is malformed or does not match the pieces.
+/// - `InvalidHalfmoveClock`: The halfmove clock is not a number.
+/// - `InvalidFullmoveNumber`: The fullmove number is not a positive number.
+/// - `MissingKing`: A player has no king.
+/// - `TooManyKings`: A player has more than one king.
+/// - `PawnOnBackRank`: A pawn stands on the first or last rank.
+/// - `OpponentInCheck`: The player who just moved is still in check.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FenError {
+    WrongFieldCount(usize),
+    WrongRankCount(usize),
+    WrongRankLength { rank: usize, length: usize },
+    UnknownPiece(char),
+    InvalidSideToMove(String),
+    InvalidCastling(String),
+    InvalidEnPassant(String),
+    InvalidHalfmoveClock(String),
+    InvalidFullmoveNumber(String),
+    MissingKing(Color),
+    TooManyKings(Color),
+    PawnOnBackRank,
+    OpponentInCheck,
+}
+
+impl fmt::Display for FenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let color_name = |color: &Color| match color {
+            Color::White => "white",
+            Color::Black => "black",
+        };
+
+        match self {
+            FenError::WrongFieldCount(count) => write!(f, "expected 6 fields, found {}", count),
+            FenError::WrongRankCount(count) => write!(f, "expected 8 ranks, found {}", count),
+            FenError::WrongRankLength { rank, length } => {
+                write!(f, "rank {} describes {} squares instead of 8", rank, length)
+            }
+            FenError::UnknownPiece(symbol) => write!(f, "unknown piece letter '{}'", symbol),
+            FenError::InvalidSideToMove(field) => write!(f, "invalid side to move '{}'", field),
+            FenError::InvalidCastling(field) => write!(f, "invalid castling rights '{}'", field),
+            FenError::InvalidEnPassant(field) => write!(f, "invalid en passant square '{}'", field),
+            FenError::InvalidHalfmoveClock(field) => {
+                write!(f, "invalid halfmove clock '{}'", field)
+            }
+            FenError::InvalidFullmoveNumber(field) => {
+                write!(f, "invalid fullmove number '{}'", field)
+            }
+            FenError::MissingKing(color) => write!(f, "{} has no king", color_name(color)),
+            FenError::TooManyKings(color) => {
+                write!(f, "{} has more than one king", color_name(color))
+            }
+            FenError::PawnOnBackRank => write!(f, "a pawn stands on the first or last rank"),
+            FenError::OpponentInCheck => write!(f, "the side not to move is in check"),
+        }
+    }
+}
+
+impl std::error::Error for FenError {}
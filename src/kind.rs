@@ -9,8 +9,12 @@
 /// - `Rook`: Represents a rook piece.
 /// - `Queen`: Represents a queen piece.
 /// - `King`: Represents a king piece.
-
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum Kind {
     None,
     Pawn,
@@ -1,8 +1,30 @@
+pub mod bitboard;
 pub mod board;
+pub mod castling;
 pub mod cell;
+#[cfg(feature = "web")]
 pub mod chess;
+pub mod clock;
 pub mod color;
+pub mod engine;
+pub mod event;
+pub mod fen;
 pub mod kind;
+pub mod log;
+pub mod moves;
+pub mod outcome;
+pub mod perft;
+pub mod pgn;
 pub mod piece;
 pub mod position;
+#[cfg(feature = "web")]
+pub mod render;
+pub mod san;
+pub mod save;
+pub mod setup;
 pub mod shift;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod tournament;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod uci;
+pub mod zobrist;
@@ -0,0 +1,37 @@
+use std::sync::RwLock;
+
+/// The function receiving the messages logged by the game, if any.
+static LOG_HOOK: RwLock<Option<fn(&str)>> = RwLock::new(None);
+
+/// Sets the function receiving the messages logged by the game, such as the
+/// browser console in the web front-end.
+///
+/// # Arguments
+///
+/// * `hook` - The function called with each message.
+pub fn set_log_hook(hook: fn(&str)) {
+    if let Ok(mut log_hook) = LOG_HOOK.write() {
+        *log_hook = Some(hook);
+    }
+}
+
+/// Removes the function receiving the messages logged by the game, so that they
+/// are dropped.
+pub fn clear_log_hook() {
+    if let Ok(mut log_hook) = LOG_HOOK.write() {
+        *log_hook = None;
+    }
+}
+
+/// Logs a message through the hook, or drops it when no hook is set.
+///
+/// # Arguments
+///
+/// * `message` - The message to log.
+pub fn log(message: &str) {
+    if let Ok(log_hook) = LOG_HOOK.read() {
+        if let Some(hook) = *log_hook {
+            hook(message);
+        }
+    }
+}
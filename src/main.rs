@@ -1,3 +1,6 @@
+// `html!` binds the unit props of `<Chess />` to a variable.
+#![allow(clippy::let_unit_value)]
+
 use yew::prelude::*;
 
 use chess::chess::Chess;
@@ -10,5 +13,6 @@ fn app() -> Html {
 }
 
 fn main() {
+    chess::log::set_log_hook(|message| web_sys::console::log_1(&message.into()));
     yew::start_app::<App>();
 }
@@ -0,0 +1,199 @@
+use std::fmt;
+
+use crate::{color::Color, kind::Kind, piece::Piece, position::Position};
+
+/// Represents a move of a piece, with everything needed to play or describe it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Move {
+    /// The starting position of the piece.
+    from: Position,
+    /// The ending position of the piece.
+    to: Position,
+    /// The piece that moves, before any promotion.
+    piece: Piece,
+    /// The piece that is captured, if any.
+    piece_captured: Option<Piece>,
+    /// The kind of piece a pawn is promoted to, if any.
+    promotion: Option<Kind>,
+    /// Indicates whether the move is a castling.
+    is_castling: bool,
+    /// Indicates whether the move is an en passant capture.
+    is_en_passant: bool,
+}
+
+impl Move {
+    /// Creates a new `Move`.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The starting position of the piece.
+    /// * `to` - The ending position of the piece.
+    /// * `piece` - The piece that moves.
+    /// * `piece_captured` - The piece that is captured, if any.
+    /// * `promotion` - The kind of piece a pawn is promoted to, if any.
+    /// * `is_castling` - Whether the move is a castling.
+    /// * `is_en_passant` - Whether the move is an en passant capture.
+    ///
+    /// # Returns
+    ///
+    /// A new `Move` instance.
+    pub fn new(
+        from: Position,
+        to: Position,
+        piece: Piece,
+        piece_captured: Option<Piece>,
+        promotion: Option<Kind>,
+        is_castling: bool,
+        is_en_passant: bool,
+    ) -> Move {
+        Move {
+            from,
+            to,
+            piece,
+            piece_captured,
+            promotion,
+            is_castling,
+            is_en_passant,
+        }
+    }
+
+    /// Gets the starting position of the piece.
+    ///
+    /// # Returns
+    ///
+    /// The `Position` the piece moves from.
+    pub fn get_from(&self) -> Position {
+        self.from
+    }
+
+    /// Gets the ending position of the piece.
+    ///
+    /// # Returns
+    ///
+    /// The `Position` the piece moves to.
+    pub fn get_to(&self) -> Position {
+        self.to
+    }
+
+    /// Gets the piece that moves.
+    ///
+    /// # Returns
+    ///
+    /// The `Piece` that moves, before any promotion.
+    pub fn get_piece(&self) -> Piece {
+        self.piece
+    }
+
+    /// Gets the piece that is captured.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Piece>` containing the captured piece, if any.
+    pub fn get_piece_captured(&self) -> Option<Piece> {
+        self.piece_captured
+    }
+
+    /// Gets the position of the piece that is captured, which is not the ending
+    /// position for an en passant capture.
+    ///
+    /// # Returns
+    ///
+    /// The `Position` the captured piece stands on.
+    pub fn get_captured_position(&self) -> Position {
+        if self.is_en_passant {
+            Position::new(self.from.get_row(), self.to.get_col())
+        } else {
+            self.to
+        }
+    }
+
+    /// Gets the kind of piece a pawn is promoted to.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Kind>` containing the promotion, if any.
+    pub fn get_promotion(&self) -> Option<Kind> {
+        self.promotion
+    }
+
+    /// Sets the kind of piece a pawn is promoted to.
+    ///
+    /// # Arguments
+    ///
+    /// * `promotion` - The kind of the new piece.
+    pub(crate) fn set_promotion(&mut self, promotion: Option<Kind>) {
+        self.promotion = promotion;
+    }
+
+    /// Gets the move in the long algebraic notation used by UCI engines.
+    ///
+    /// # Returns
+    ///
+    /// A `String` such as `e2e4`, `e1g1` or `e7e8q`.
+    pub fn get_uci(&self) -> String {
+        let mut uci: String = format!("{}{}", self.from.get_notation(), self.to.get_notation());
+        if let Some(kind) = self.promotion {
+            uci.push(Piece::new(kind, Color::Black).get_fen_symbol());
+        }
+        uci
+    }
+
+    /// Checks if the move is a castling.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the king castles, `false` otherwise.
+    pub fn is_castling(&self) -> bool {
+        self.is_castling
+    }
+
+    /// Checks if the move is an en passant capture.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a pawn captures en passant, `false` otherwise.
+    pub fn is_en_passant(&self) -> bool {
+        self.is_en_passant
+    }
+
+    /// Checks if the move captures a piece.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a piece is captured, `false` otherwise.
+    pub fn is_capture(&self) -> bool {
+        self.piece_captured.is_some()
+    }
+}
+
+/// Represents the reasons a move can be refused by the board.
+///
+/// # Variants
+///
+/// - `GameOver`: The game has already ended.
+/// - `PromotionPending`: A pawn is waiting for its promotion piece.
+/// - `ClockPaused`: The clocks are paused.
+/// - `Illegal`: The move is not a legal move of the side to move.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MoveError {
+    GameOver,
+    PromotionPending,
+    ClockPaused,
+    Illegal(Move),
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoveError::GameOver => write!(f, "the game has ended"),
+            MoveError::PromotionPending => write!(f, "a pawn is waiting for its promotion"),
+            MoveError::ClockPaused => write!(f, "the clocks are paused"),
+            MoveError::Illegal(chess_move) => {
+                write!(f, "{} is not a legal move", chess_move.get_uci())
+            }
+        }
+    }
+}
+
+impl std::error::Error for MoveError {}
@@ -0,0 +1,237 @@
+use std::fmt;
+
+use crate::color::Color;
+
+/// Represents the way a finished game has ended.
+///
+/// # Variants
+///
+/// - `Checkmate`: The given color has checkmated its opponent.
+/// - `Stalemate`: The side to move has no legal move and is not in check.
+/// - `FiftyMoveRule`: A draw claimed after fifty moves without capture or pawn move.
+/// - `SeventyFiveMoveRule`: A draw after seventy-five moves without capture or pawn move.
+/// - `ThreefoldRepetition`: A draw claimed after the same position occurred three times.
+/// - `FivefoldRepetition`: A draw after the same position occurred five times.
+/// - `InsufficientMaterial`: A draw because neither side can checkmate anymore.
+/// - `Timeout`: The given color has won because its opponent ran out of time.
+/// - `TimeoutVsInsufficientMaterial`: A draw because a player ran out of time while
+///   its opponent could not checkmate anymore.
+/// - `Abandonment`: The given color has won because its opponent left the game.
+/// - `Resignation`: The given color has won because its opponent resigned.
+/// - `Agreement`: A draw agreed by both players.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Outcome {
+    Checkmate(Color),
+    Stalemate,
+    FiftyMoveRule,
+    SeventyFiveMoveRule,
+    ThreefoldRepetition,
+    FivefoldRepetition,
+    InsufficientMaterial,
+    Timeout(Color),
+    TimeoutVsInsufficientMaterial,
+    Abandonment(Color),
+    Resignation(Color),
+    Agreement,
+}
+
+impl Outcome {
+    /// Gets the color of the winner.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Color>` containing the winner, or `None` if the game is drawn.
+    pub fn get_winner(&self) -> Option<Color> {
+        match self {
+            Outcome::Checkmate(color)
+            | Outcome::Timeout(color)
+            | Outcome::Abandonment(color)
+            | Outcome::Resignation(color) => Some(*color),
+            _ => None,
+        }
+    }
+
+    /// Gets the result of the game.
+    ///
+    /// # Returns
+    ///
+    /// The `GameResult` of the finished game, never `GameResult::Ongoing`.
+    pub fn get_result(&self) -> GameResult {
+        match self.get_winner() {
+            Some(Color::White) => GameResult::WhiteWins,
+            Some(Color::Black) => GameResult::BlackWins,
+            None => GameResult::Draw,
+        }
+    }
+
+    /// Gets the reason the game ended, grouping the variants of a same rule.
+    ///
+    /// # Returns
+    ///
+    /// The `Termination` of the game.
+    pub fn get_termination(&self) -> Termination {
+        match self {
+            Outcome::Checkmate(_) => Termination::Checkmate,
+            Outcome::Stalemate => Termination::Stalemate,
+            Outcome::FiftyMoveRule | Outcome::SeventyFiveMoveRule => Termination::FiftyMove,
+            Outcome::ThreefoldRepetition | Outcome::FivefoldRepetition => Termination::Repetition,
+            Outcome::InsufficientMaterial => Termination::InsufficientMaterial,
+            Outcome::Timeout(_) | Outcome::TimeoutVsInsufficientMaterial => Termination::Timeout,
+            Outcome::Abandonment(_) => Termination::Abandonment,
+            Outcome::Resignation(_) => Termination::Resignation,
+            Outcome::Agreement => Termination::Agreement,
+        }
+    }
+
+    /// Gets a human readable description of the reason the game ended.
+    ///
+    /// # Returns
+    ///
+    /// A `String` describing the outcome.
+    pub fn get_description(&self) -> String {
+        match self {
+            Outcome::Checkmate(_) => "checkmate".to_string(),
+            Outcome::Stalemate => "stalemate".to_string(),
+            Outcome::FiftyMoveRule => "fifty-move rule".to_string(),
+            Outcome::SeventyFiveMoveRule => "seventy-five-move rule".to_string(),
+            Outcome::ThreefoldRepetition => "threefold repetition".to_string(),
+            Outcome::FivefoldRepetition => "fivefold repetition".to_string(),
+            Outcome::InsufficientMaterial => "insufficient material".to_string(),
+            Outcome::Timeout(_) => "timeout".to_string(),
+            Outcome::TimeoutVsInsufficientMaterial => {
+                "timeout vs insufficient material".to_string()
+            }
+            Outcome::Abandonment(_) => "abandonment".to_string(),
+            Outcome::Resignation(_) => "resignation".to_string(),
+            Outcome::Agreement => "agreement".to_string(),
+        }
+    }
+}
+
+/// Represents the result of a game.
+///
+/// # Variants
+///
+/// - `WhiteWins`: White has won the game.
+/// - `BlackWins`: Black has won the game.
+/// - `Draw`: The game is drawn.
+/// - `Ongoing`: The game is still being played.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum GameResult {
+    WhiteWins,
+    BlackWins,
+    Draw,
+    Ongoing,
+}
+
+impl GameResult {
+    /// Gets the color of the winner.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Color>` containing the winner, or `None` if the game is drawn or
+    /// still being played.
+    pub fn get_winner(&self) -> Option<Color> {
+        match self {
+            GameResult::WhiteWins => Some(Color::White),
+            GameResult::BlackWins => Some(Color::Black),
+            GameResult::Draw | GameResult::Ongoing => None,
+        }
+    }
+
+    /// Gets the PGN token of the result.
+    ///
+    /// # Returns
+    ///
+    /// A string slice containing `1-0`, `0-1`, `1/2-1/2` or `*`.
+    pub fn get_token(&self) -> &'static str {
+        match self {
+            GameResult::WhiteWins => "1-0",
+            GameResult::BlackWins => "0-1",
+            GameResult::Draw => "1/2-1/2",
+            GameResult::Ongoing => "*",
+        }
+    }
+}
+
+impl fmt::Display for GameResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.get_token())
+    }
+}
+
+/// Represents the reason a game has ended.
+///
+/// # Variants
+///
+/// - `Checkmate`: A king was checkmated.
+/// - `Resignation`: A player resigned.
+/// - `Timeout`: A player ran out of time.
+/// - `Stalemate`: The side to move had no legal move and was not in check.
+/// - `Repetition`: The same position occurred three or five times.
+/// - `FiftyMove`: Fifty or seventy-five moves were played without capture or pawn move.
+/// - `InsufficientMaterial`: Neither side could checkmate anymore.
+/// - `Agreement`: The players agreed to a draw.
+/// - `Abandonment`: A player left the game.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Termination {
+    Checkmate,
+    Resignation,
+    Timeout,
+    Stalemate,
+    Repetition,
+    FiftyMove,
+    InsufficientMaterial,
+    Agreement,
+    Abandonment,
+}
+
+impl Termination {
+    /// Gets a human readable description of the termination.
+    ///
+    /// # Returns
+    ///
+    /// A string slice such as `checkmate` or `fifty-move rule`.
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            Termination::Checkmate => "checkmate",
+            Termination::Resignation => "resignation",
+            Termination::Timeout => "timeout",
+            Termination::Stalemate => "stalemate",
+            Termination::Repetition => "repetition",
+            Termination::FiftyMove => "fifty-move rule",
+            Termination::InsufficientMaterial => "insufficient material",
+            Termination::Agreement => "agreement",
+            Termination::Abandonment => "abandonment",
+        }
+    }
+
+    /// Gets the value of the PGN `Termination` tag, which only tells games ended by
+    /// the rules or the players apart from time forfeits and abandoned games.
+    ///
+    /// # Returns
+    ///
+    /// A string slice containing `normal`, `time forfeit` or `abandoned`.
+    pub fn get_pgn_tag(&self) -> &'static str {
+        match self {
+            Termination::Timeout => "time forfeit",
+            Termination::Abandonment => "abandoned",
+            _ => "normal",
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{board::Board, moves::Move, setup::Setup};
+
+impl Setup {
+    /// Counts the leaf nodes of the move tree down to a given depth, the standard
+    /// way of checking a move generator against known results.
+    ///
+    /// Only the rules of movement are followed: draws by repetition, the fifty-move
+    /// rule or insufficient material do not stop the count.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth` - The number of half moves to look ahead.
+    ///
+    /// # Returns
+    ///
+    /// The number of positions reached after exactly `depth` half moves.
+    pub fn perft(&self, depth: u32) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+
+        let legal_moves: Vec<Move> = self.legal_moves();
+        if depth == 1 {
+            return legal_moves.len() as u64;
+        }
+
+        legal_moves
+            .into_iter()
+            .map(|chess_move| self.play(chess_move).perft(depth - 1))
+            .sum()
+    }
+
+    /// Counts the leaf nodes below each legal move, which helps find the move where
+    /// a generator and a reference disagree.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth` - The number of half moves to look ahead, the root move included.
+    ///
+    /// # Returns
+    ///
+    /// A vector containing each legal move with the number of leaf nodes below it.
+    pub fn divide(&self, depth: u32) -> Vec<(Move, u64)> {
+        self.legal_moves()
+            .into_iter()
+            .map(|chess_move| {
+                let nodes: u64 = self.play(chess_move).perft(depth.saturating_sub(1));
+                (chess_move, nodes)
+            })
+            .collect()
+    }
+}
+
+impl Board {
+    /// Counts the leaf nodes of the move tree down to a given depth.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth` - The number of half moves to look ahead.
+    ///
+    /// # Returns
+    ///
+    /// The number of positions reached after exactly `depth` half moves.
+    pub fn perft(&self, depth: u32) -> u64 {
+        self.get_setup().perft(depth)
+    }
+
+    /// Counts the leaf nodes below each legal move.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth` - The number of half moves to look ahead, the root move included.
+    ///
+    /// # Returns
+    ///
+    /// A vector containing each legal move with the number of leaf nodes below it.
+    pub fn divide(&self, depth: u32) -> Vec<(Move, u64)> {
+        self.get_setup().divide(depth)
+    }
+}
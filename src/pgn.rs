@@ -0,0 +1,796 @@
+use std::fmt;
+
+use crate::{
+    board::Board,
+    color::Color,
+    fen::{FenError, STARTING_FEN},
+    moves::Move,
+    outcome::GameResult,
+    san::SanError,
+};
+
+/// The names of the Seven Tag Roster, in the order they must be exported.
+const SEVEN_TAG_ROSTER: [&str; 7] = ["Event", "Site", "Date", "Round", "White", "Black", "Result"];
+
+/// The maximum length of a movetext line in exported PGN.
+const LINE_LENGTH: usize = 79;
+
+/// Represents the tag pairs describing a game in PGN (Portable Game Notation).
+#[derive(Clone, PartialEq)]
+pub struct PgnTags {
+    /// The tag pairs as names and values, in insertion order.
+    tags: Vec<(String, String)>,
+}
+
+impl Default for PgnTags {
+    fn default() -> Self {
+        PgnTags::new()
+    }
+}
+
+impl PgnTags {
+    /// Creates the Seven Tag Roster filled with the PGN values for unknown data.
+    ///
+    /// # Returns
+    ///
+    /// A new `PgnTags` instance.
+    pub fn new() -> PgnTags {
+        PgnTags {
+            tags: SEVEN_TAG_ROSTER
+                .iter()
+                .map(|name| {
+                    let value: &str = match *name {
+                        "Date" => "????.??.??",
+                        "Result" => "*",
+                        _ => "?",
+                    };
+                    (name.to_string(), value.to_string())
+                })
+                .collect(),
+        }
+    }
+
+    /// Gets the value of a tag.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the tag.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<&str>` containing the value, or `None` if the tag is not set.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(tag, _)| tag == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Sets the value of a tag, adding it if it is not set yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the tag.
+    /// * `value` - The value of the tag.
+    pub fn set(&mut self, name: &str, value: &str) {
+        match self.tags.iter_mut().find(|(tag, _)| tag == name) {
+            Some((_, old_value)) => *old_value = value.to_string(),
+            None => self.tags.push((name.to_string(), value.to_string())),
+        }
+    }
+
+    /// Returns the tag pairs in insertion order.
+    ///
+    /// # Returns
+    ///
+    /// A slice of tuples containing the name and the value of each tag.
+    pub fn get_tags(&self) -> &[(String, String)] {
+        &self.tags
+    }
+}
+
+/// Represents the reasons PGN text can be rejected.
+///
+/// # Variants
+///
+/// - `Syntax`: The text is malformed at the given line.
+/// - `InvalidFen`: The `FEN` tag of a game is not a valid position.
+/// - `IllegalMove`: A move of a game cannot be played at the given ply.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PgnError {
+    Syntax {
+        line: usize,
+        message: String,
+    },
+    InvalidFen(FenError),
+    IllegalMove {
+        ply: usize,
+        san: String,
+        reason: SanError,
+    },
+}
+
+impl fmt::Display for PgnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PgnError::Syntax { line, message } => write!(f, "line {}: {}", line, message),
+            PgnError::InvalidFen(error) => write!(f, "invalid FEN tag: {}", error),
+            PgnError::IllegalMove { ply, san, reason } => {
+                write!(f, "ply {} ({}): {}", ply, san, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PgnError {}
+
+/// Represents a move of a PGN game with its annotations.
+#[derive(Clone, PartialEq)]
+pub struct PgnMove {
+    /// The move in Standard Algebraic Notation.
+    san: String,
+    /// The Numeric Annotation Glyphs attached to the move, `!` and `?` included.
+    nags: Vec<u8>,
+    /// The comments following the move.
+    comments: Vec<String>,
+    /// The alternative lines that could have been played instead of the move.
+    variations: Vec<Vec<PgnMove>>,
+}
+
+impl PgnMove {
+    /// Creates a move without annotations.
+    ///
+    /// # Arguments
+    ///
+    /// * `san` - The move in Standard Algebraic Notation.
+    ///
+    /// # Returns
+    ///
+    /// A new `PgnMove` instance.
+    pub fn new(san: &str) -> PgnMove {
+        PgnMove {
+            san: san.to_string(),
+            nags: Vec::new(),
+            comments: Vec::new(),
+            variations: Vec::new(),
+        }
+    }
+
+    /// Gets the move in Standard Algebraic Notation.
+    ///
+    /// # Returns
+    ///
+    /// A string slice containing the SAN of the move.
+    pub fn get_san(&self) -> &str {
+        &self.san
+    }
+
+    /// Gets the Numeric Annotation Glyphs attached to the move.
+    ///
+    /// # Returns
+    ///
+    /// A slice containing the glyph numbers.
+    pub fn get_nags(&self) -> &[u8] {
+        &self.nags
+    }
+
+    /// Gets the comments following the move.
+    ///
+    /// # Returns
+    ///
+    /// A slice containing the comments.
+    pub fn get_comments(&self) -> &[String] {
+        &self.comments
+    }
+
+    /// Gets the alternative lines that could have been played instead of the move.
+    ///
+    /// # Returns
+    ///
+    /// A slice containing each variation as a list of moves.
+    pub fn get_variations(&self) -> &[Vec<PgnMove>] {
+        &self.variations
+    }
+}
+
+/// Represents a game read from PGN text.
+#[derive(Clone, PartialEq)]
+pub struct PgnGame {
+    /// The tag pairs of the game.
+    tags: PgnTags,
+    /// The comment written before the first move, if any.
+    comment: Option<String>,
+    /// The moves of the main line.
+    moves: Vec<PgnMove>,
+    /// The result token ending the movetext.
+    result: String,
+}
+
+impl PgnGame {
+    /// Gets the tag pairs of the game.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `PgnTags` of the game.
+    pub fn get_tags(&self) -> &PgnTags {
+        &self.tags
+    }
+
+    /// Gets the comment written before the first move. When several comments come
+    /// before it, the first one belongs to the game and the others to the move.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<&str>` containing the comment, if any.
+    pub fn get_comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// Gets the moves of the main line.
+    ///
+    /// # Returns
+    ///
+    /// A slice containing the moves.
+    pub fn get_moves(&self) -> &[PgnMove] {
+        &self.moves
+    }
+
+    /// Gets the result token ending the movetext.
+    ///
+    /// # Returns
+    ///
+    /// A string slice containing `1-0`, `0-1`, `1/2-1/2` or `*`.
+    pub fn get_result(&self) -> &str {
+        &self.result
+    }
+
+    /// Creates the board the game starts from, using the `FEN` tag if present.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the initial `Board`, or a `PgnError` if the tag is invalid.
+    pub fn get_initial_board(&self) -> Result<Board, PgnError> {
+        let mut board: Board = match self.tags.get("FEN") {
+            Some(fen) => Board::from_fen(fen).map_err(PgnError::InvalidFen)?,
+            None => Board::new().initialize(),
+        };
+
+        for (name, value) in self.tags.get_tags() {
+            if name != "Result" && name != "SetUp" && name != "FEN" {
+                board.set_pgn_tag(name, value);
+            }
+        }
+        Ok(board)
+    }
+
+    /// Replays the main line and every variation, checking that each move is legal.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the board at the end of the main line, or a `PgnError`
+    /// giving the ply and the reason of the first illegal move.
+    pub fn replay(&self) -> Result<Board, PgnError> {
+        let mut board: Board = replay_line(self.get_replay_board()?, &self.moves, 1)?;
+        board.set_automatic_draws(true);
+        Ok(board)
+    }
+
+    /// Replays the first moves of the main line.
+    ///
+    /// # Arguments
+    ///
+    /// * `ply` - The number of half moves to play.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the board after that many half moves, or a `PgnError`
+    /// if one of them is illegal.
+    pub fn get_board_at(&self, ply: usize) -> Result<Board, PgnError> {
+        let mut board: Board = self.get_replay_board()?;
+
+        for (index, pgn_move) in self.moves.iter().take(ply).enumerate() {
+            play_san(&mut board, pgn_move.get_san(), index + 1)?;
+        }
+        board.set_automatic_draws(true);
+        Ok(board)
+    }
+
+    /// Creates the board the moves are replayed on. Games are often played on past
+    /// a draw which needs no claim, so such draws are turned off until the moves
+    /// have been replayed.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the initial `Board`, or a `PgnError` if the tag is invalid.
+    fn get_replay_board(&self) -> Result<Board, PgnError> {
+        let mut board: Board = self.get_initial_board()?;
+        board.set_automatic_draws(false);
+        Ok(board)
+    }
+}
+
+/// Plays a SAN move on a board.
+///
+/// # Arguments
+///
+/// * `board` - The board to play the move on.
+/// * `san` - The move in Standard Algebraic Notation.
+/// * `ply` - The ply of the move, used in the error.
+///
+/// # Returns
+///
+/// A `Result` which is an error if the move is illegal.
+pub(crate) fn play_san(board: &mut Board, san: &str, ply: usize) -> Result<(), PgnError> {
+    let error = |reason: SanError| PgnError::IllegalMove {
+        ply,
+        san: san.to_string(),
+        reason,
+    };
+
+    let chess_move: Move = board.parse_san(san).map_err(error)?;
+    board
+        .make_move(chess_move)
+        .map_err(|_| error(SanError::Illegal(san.to_string())))
+}
+
+/// Replays a line of moves and its variations from a board.
+///
+/// # Arguments
+///
+/// * `board` - The board the line starts from.
+/// * `moves` - The moves of the line.
+/// * `first_ply` - The ply of the first move of the line.
+///
+/// # Returns
+///
+/// A `Result` containing the board at the end of the line, or a `PgnError` if a
+/// move of the line or of its variations is illegal.
+fn replay_line(mut board: Board, moves: &[PgnMove], first_ply: usize) -> Result<Board, PgnError> {
+    for (index, pgn_move) in moves.iter().enumerate() {
+        for variation in pgn_move.get_variations() {
+            replay_line(board.clone(), variation, first_ply + index)?;
+        }
+        play_san(&mut board, pgn_move.get_san(), first_ply + index)?;
+    }
+    Ok(board)
+}
+
+/// Reads every game of a PGN text.
+///
+/// Tag pairs, move numbers, `{}` and `;` comments, `%` escape lines, NAGs such as
+/// `$1` or `!?`, recursive `( )` variations and result tokens are understood. A
+/// comment opening a variation is attached to its first move. A game missing its
+/// result token ends where the next tag section starts, with the result `*`.
+///
+/// # Arguments
+///
+/// * `text` - A string slice containing one or more games.
+///
+/// # Returns
+///
+/// A `Result` containing the games, or a `PgnError` if the text is malformed.
+pub fn parse_pgn(text: &str) -> Result<Vec<PgnGame>, PgnError> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut games: Vec<PgnGame> = Vec::new();
+    let mut game: Option<PgnGame> = None;
+    let mut lines: Vec<Vec<PgnMove>> = vec![Vec::new()];
+    let mut pending_comments: Vec<String> = Vec::new();
+    let mut line: usize = 1;
+    let mut index: usize = 0;
+
+    let syntax = |line: usize, message: &str| PgnError::Syntax {
+        line,
+        message: message.to_string(),
+    };
+
+    while index < chars.len() {
+        let char: char = chars[index];
+
+        match char {
+            '\n' => {
+                line += 1;
+                index += 1;
+            }
+            '%' if index == 0 || chars[index - 1] == '\n' => {
+                while index < chars.len() && chars[index] != '\n' {
+                    index += 1;
+                }
+            }
+            ';' => {
+                let start: usize = index + 1;
+                while index < chars.len() && chars[index] != '\n' {
+                    index += 1;
+                }
+                let comment: String = chars[start..index].iter().collect();
+                attach_comment(&mut lines, &mut pending_comments, comment.trim());
+            }
+            '{' => {
+                let start: usize = index + 1;
+                let start_line: usize = line;
+                while index < chars.len() && chars[index] != '}' {
+                    if chars[index] == '\n' {
+                        line += 1;
+                    }
+                    index += 1;
+                }
+                if index == chars.len() {
+                    return Err(syntax(start_line, "unterminated comment"));
+                }
+                let comment: String = chars[start..index].iter().collect();
+                attach_comment(&mut lines, &mut pending_comments, comment.trim());
+                index += 1;
+            }
+            '[' => {
+                if !lines[0].is_empty() {
+                    // A game without a result token ends where the next tag section starts.
+                    if lines.len() > 1 {
+                        return Err(syntax(line, "unterminated variation"));
+                    }
+                    games.push(finish_game(
+                        &mut game,
+                        &mut lines,
+                        &mut pending_comments,
+                        "*",
+                    ));
+                }
+                let end: usize = find_tag_end(&chars, index)
+                    .ok_or_else(|| syntax(line, "unterminated tag pair"))?;
+                let content: String = chars[index + 1..end].iter().collect();
+                let (name, value) =
+                    parse_tag(&content).ok_or_else(|| syntax(line, "malformed tag pair"))?;
+                game.get_or_insert_with(new_game).tags.set(&name, &value);
+                index = end + 1;
+            }
+            '(' => {
+                if lines.last().is_none_or(|moves| moves.is_empty()) {
+                    return Err(syntax(line, "variation without a move to replace"));
+                }
+                lines.push(Vec::new());
+                index += 1;
+            }
+            ')' => {
+                if lines.len() == 1 {
+                    return Err(syntax(line, "unmatched closing parenthesis"));
+                }
+                let variation: Vec<PgnMove> = lines.pop().unwrap();
+                if let Some(parent) = lines.last_mut().and_then(|moves| moves.last_mut()) {
+                    parent.variations.push(variation);
+                }
+                index += 1;
+            }
+            '$' => {
+                let start: usize = index + 1;
+                index += 1;
+                while index < chars.len() && chars[index].is_ascii_digit() {
+                    index += 1;
+                }
+                let nag: String = chars[start..index].iter().collect();
+                let nag: u8 = nag.parse().map_err(|_| syntax(line, "malformed NAG"))?;
+                match lines.last_mut().and_then(|moves| moves.last_mut()) {
+                    Some(pgn_move) => pgn_move.nags.push(nag),
+                    None => return Err(syntax(line, "NAG without a move")),
+                }
+            }
+            _ if char.is_whitespace() => index += 1,
+            _ => {
+                let start: usize = index;
+                while index < chars.len()
+                    && !chars[index].is_whitespace()
+                    && !"{}()[];$".contains(chars[index])
+                {
+                    index += 1;
+                }
+                let token: String = chars[start..index].iter().collect();
+
+                if matches!(token.as_str(), "1-0" | "0-1" | "1/2-1/2" | "*") {
+                    if lines.len() > 1 {
+                        return Err(syntax(line, "unterminated variation"));
+                    }
+                    games.push(finish_game(
+                        &mut game,
+                        &mut lines,
+                        &mut pending_comments,
+                        &token,
+                    ));
+                    continue;
+                }
+
+                let san: &str = if token.starts_with("0-0") {
+                    &token
+                } else {
+                    token.trim_start_matches(|c: char| c.is_ascii_digit() || c == '.')
+                };
+                if san.is_empty() {
+                    continue;
+                }
+                if !san.starts_with(|c: char| c.is_ascii_alphabetic() || c == '0') {
+                    return Err(syntax(line, &format!("unexpected token '{}'", token)));
+                }
+                read_move(&mut lines, &mut game, &mut pending_comments, san);
+            }
+        }
+    }
+
+    if lines.len() > 1 {
+        return Err(syntax(line, "unterminated variation"));
+    }
+    if game.is_some() || !lines[0].is_empty() {
+        let mut finished: PgnGame = game.take().unwrap_or_else(new_game);
+        finished.moves = std::mem::take(&mut lines[0]);
+        finished.result = finished.tags.get("Result").unwrap_or("*").to_string();
+        games.push(finished);
+    }
+
+    Ok(games)
+}
+
+/// Creates an empty game with the Seven Tag Roster.
+///
+/// # Returns
+///
+/// A new `PgnGame` without moves.
+fn new_game() -> PgnGame {
+    PgnGame {
+        tags: PgnTags::new(),
+        comment: None,
+        moves: Vec::new(),
+        result: "*".to_string(),
+    }
+}
+
+/// Ends the game being read, taking its moves from the main line.
+///
+/// # Arguments
+///
+/// * `game` - The game being read, left empty for the next one.
+/// * `lines` - The stack of lines being read, holding only the main line.
+/// * `pending_comments` - The comments waiting for a move. The first one becomes the
+///   game comment when the game has none, the others are dropped.
+/// * `result` - The result of the game.
+///
+/// # Returns
+///
+/// The finished `PgnGame`.
+fn finish_game(
+    game: &mut Option<PgnGame>,
+    lines: &mut [Vec<PgnMove>],
+    pending_comments: &mut Vec<String>,
+    result: &str,
+) -> PgnGame {
+    let mut finished: PgnGame = game.take().unwrap_or_else(new_game);
+    finished.moves = std::mem::take(&mut lines[0]);
+    finished.result = result.to_string();
+    finished.comment = finished.comment.or(pending_comments.drain(..).next());
+    finished
+}
+
+/// Finds the square bracket closing a tag pair, skipping the brackets and escaped
+/// quotes inside its value. A tag pair ends on the line it starts on.
+///
+/// # Arguments
+///
+/// * `chars` - The characters of the PGN text.
+/// * `start` - The index of the opening square bracket.
+///
+/// # Returns
+///
+/// An `Option` containing the index of the closing square bracket, or `None` if the
+/// line ends first.
+fn find_tag_end(chars: &[char], start: usize) -> Option<usize> {
+    let mut is_quoted: bool = false;
+    let mut index: usize = start + 1;
+
+    while index < chars.len() && chars[index] != '\n' {
+        match chars[index] {
+            '\\' if is_quoted => index += 1,
+            '"' => is_quoted = !is_quoted,
+            ']' if !is_quoted => return Some(index),
+            _ => {}
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Parses the content of a tag pair, such as `Event "Club game"`.
+///
+/// # Arguments
+///
+/// * `content` - The text between the square brackets.
+///
+/// # Returns
+///
+/// An `Option` containing the name and the unescaped value, or `None` if malformed.
+fn parse_tag(content: &str) -> Option<(String, String)> {
+    let content: &str = content.trim();
+    let (name, value) = content.split_once(char::is_whitespace)?;
+    let value: &str = value.trim().strip_prefix('"')?.strip_suffix('"')?;
+    Some((
+        name.to_string(),
+        value.replace("\\\"", "\"").replace("\\\\", "\\"),
+    ))
+}
+
+/// Attaches a comment to the last move of the current line, or keeps it for the
+/// next move when the line has no move yet.
+///
+/// # Arguments
+///
+/// * `lines` - The stack of lines being read, the current one last.
+/// * `pending_comments` - The comments waiting for a move.
+/// * `comment` - The comment to attach.
+fn attach_comment(lines: &mut [Vec<PgnMove>], pending_comments: &mut Vec<String>, comment: &str) {
+    match lines.last_mut().and_then(|moves| moves.last_mut()) {
+        Some(pgn_move) => pgn_move.comments.push(comment.to_string()),
+        None => pending_comments.push(comment.to_string()),
+    }
+}
+
+/// Adds a move token, with its `!` and `?` suffixes turned into NAGs, to the
+/// current line.
+///
+/// # Arguments
+///
+/// * `lines` - The stack of lines being read, the current one last.
+/// * `game` - The game being read.
+/// * `pending_comments` - The comments waiting for a move.
+/// * `token` - The move token.
+fn read_move(
+    lines: &mut [Vec<PgnMove>],
+    game: &mut Option<PgnGame>,
+    pending_comments: &mut Vec<String>,
+    token: &str,
+) {
+    let san: &str = token.trim_end_matches(['!', '?']);
+    let mut pgn_move: PgnMove = PgnMove::new(san);
+
+    let nag: Option<u8> = match &token[san.len()..] {
+        "!" => Some(1),
+        "?" => Some(2),
+        "!!" => Some(3),
+        "??" => Some(4),
+        "!?" => Some(5),
+        "?!" => Some(6),
+        _ => None,
+    };
+    pgn_move.nags.extend(nag);
+
+    if lines.len() == 1 && lines[0].is_empty() {
+        let game: &mut PgnGame = game.get_or_insert_with(new_game);
+        if !pending_comments.is_empty() {
+            game.comment = Some(pending_comments.remove(0));
+        }
+    }
+    pgn_move.comments.append(pending_comments);
+
+    if let Some(moves) = lines.last_mut() {
+        moves.push(pgn_move);
+    }
+}
+
+/// Escapes a tag value so it can be written between double quotes.
+///
+/// # Arguments
+///
+/// * `value` - The value of the tag.
+///
+/// # Returns
+///
+/// A `String` where backslashes and double quotes are escaped.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+impl Board {
+    /// Exports the game as PGN (Portable Game Notation).
+    ///
+    /// The Seven Tag Roster comes first, followed by the `SetUp` and `FEN` tags when
+    /// the game did not start from the standard position, the other tags, and the
+    /// numbered movetext with the events of the game as comments, ending with the
+    /// result token. A game still being played on the board keeps the `Result` tag
+    /// it was given, such as the result of an adjudication. A finished game gets a
+    /// `Termination` tag unless it already has one.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the complete PGN of the game.
+    pub fn to_pgn(&self) -> String {
+        let tags: &PgnTags = self.get_pgn_tags();
+        let result: &str = match self.get_result() {
+            GameResult::Ongoing => tags.get("Result").unwrap_or("*"),
+            result => result.get_token(),
+        };
+        let mut pgn: String = String::new();
+
+        for name in SEVEN_TAG_ROSTER.iter() {
+            let value: &str = match *name {
+                "Result" => result,
+                _ => tags.get(name).unwrap_or("?"),
+            };
+            pgn.push_str(&format!("[{} \"{}\"]\n", name, escape(value)));
+        }
+
+        if self.get_initial_fen() != STARTING_FEN {
+            pgn.push_str("[SetUp \"1\"]\n");
+            pgn.push_str(&format!("[FEN \"{}\"]\n", self.get_initial_fen()));
+        }
+
+        for (name, value) in tags.get_tags() {
+            if !SEVEN_TAG_ROSTER.contains(&name.as_str()) && name != "SetUp" && name != "FEN" {
+                pgn.push_str(&format!("[{} \"{}\"]\n", name, escape(value)));
+            }
+        }
+        if let Some(termination) = self.get_termination() {
+            if tags.get("Termination").is_none() {
+                pgn.push_str(&format!(
+                    "[Termination \"{}\"]\n",
+                    termination.get_pgn_tag()
+                ));
+            }
+        }
+        pgn.push('\n');
+
+        let mut tokens: Vec<String> = Vec::new();
+        let initial: Board = Board::from_fen(self.get_initial_fen()).unwrap_or_default();
+        let mut move_number: u32 = initial.get_fullmove_number();
+        let mut color: Color = initial.get_color_turn();
+        // A black move gets its own number at the start and after a comment.
+        let mut is_interrupted: bool = true;
+
+        for (index, notation) in self.get_notations().iter().enumerate() {
+            let comments: Vec<String> = self.get_event_comments(index);
+            if !comments.is_empty() {
+                tokens.extend(comments);
+                is_interrupted = true;
+            }
+            match color {
+                Color::White => tokens.push(format!("{}.", move_number)),
+                Color::Black if is_interrupted => tokens.push(format!("{}...", move_number)),
+                Color::Black => {}
+            }
+            tokens.push(notation.clone());
+            is_interrupted = false;
+
+            if color == Color::Black {
+                move_number += 1;
+            }
+            color = !color;
+        }
+        tokens.extend(self.get_event_comments(self.get_notations().len()));
+        tokens.push(result.to_string());
+
+        let mut line: String = String::new();
+        for token in tokens {
+            if !line.is_empty() && line.len() + 1 + token.len() > LINE_LENGTH {
+                pgn.push_str(&line);
+                pgn.push('\n');
+                line.clear();
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(&token);
+        }
+        pgn.push_str(&line);
+        pgn.push('\n');
+
+        pgn
+    }
+
+    /// Gets the comments describing the events recorded after a number of half moves.
+    ///
+    /// # Arguments
+    ///
+    /// * `ply` - The number of half moves played before the events.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<String>` containing one `{}` comment per event.
+    fn get_event_comments(&self, ply: usize) -> Vec<String> {
+        self.get_events()
+            .iter()
+            .filter(|(event_ply, _)| *event_ply == ply)
+            .map(|(_, event)| format!("{{{}}}", event.get_description()))
+            .collect()
+    }
+}
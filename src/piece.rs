@@ -1,7 +1,8 @@
 use crate::{color::Color, kind::Kind};
 
 /// Represents a chess piece.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Piece {
     /// The kind of the piece.
     kind: Kind,
@@ -22,7 +23,7 @@ impl Piece {
     ///
     /// A new `Piece` instance with the specified kind and color.
     pub fn new(kind: Kind, color: Color) -> Piece {
-        return Piece { kind, color };
+        Piece { kind, color }
     }
 
     /// Creates a `Piece` representing no piece (an empty square).
@@ -125,6 +126,29 @@ impl Piece {
         }
     }
 
+    /// Gets the FEN symbol representing the chess piece, pawns included.
+    ///
+    /// # Returns
+    ///
+    /// A `char` representing the piece. Uppercase for white pieces, lowercase for black
+    /// pieces and a space for no piece.
+    pub fn get_fen_symbol(&self) -> char {
+        let symbol: char = match self.kind {
+            Kind::None => ' ',
+            Kind::Pawn => 'p',
+            Kind::Knight => 'n',
+            Kind::Bishop => 'b',
+            Kind::Rook => 'r',
+            Kind::Queen => 'q',
+            Kind::King => 'k',
+        };
+
+        match self.color {
+            Color::White => symbol.to_ascii_uppercase(),
+            Color::Black => symbol,
+        }
+    }
+
     /// Creates a `Piece` from a given symbol.
     ///
     /// # Arguments
@@ -133,8 +157,21 @@ impl Piece {
     ///
     /// # Returns
     ///
-    /// A `Piece` instance corresponding to the given symbol.
+    /// A `Piece` instance corresponding to the given symbol, or no piece if the symbol is unknown.
     pub fn from_symbol(symbol: char) -> Piece {
+        Piece::try_from_symbol(symbol).unwrap_or(Piece::none())
+    }
+
+    /// Creates a `Piece` from a given symbol, rejecting unknown symbols.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - A `char` representing the piece. Uppercase for white pieces and lowercase for black pieces.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Piece>` containing the piece, or `None` if the symbol is not a piece letter.
+    pub fn try_from_symbol(symbol: char) -> Option<Piece> {
         let (kind, color) = match symbol {
             'P' => (Kind::Pawn, Color::White),
             'p' => (Kind::Pawn, Color::Black),
@@ -148,9 +185,9 @@ impl Piece {
             'q' => (Kind::Queen, Color::Black),
             'K' => (Kind::King, Color::White),
             'k' => (Kind::King, Color::Black),
-            _ => (Kind::None, Color::White),
+            _ => return None,
         };
 
-        Piece { kind, color }
+        Some(Piece { kind, color })
     }
 }
@@ -4,7 +4,12 @@
 ///
 /// * `row` -
 /// * `col` -
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "String", try_from = "String")
+)]
 pub struct Position {
     /// The row index of the position.
     row: usize,
@@ -53,4 +58,81 @@ impl Position {
     pub fn get_position(&self) -> (usize, usize) {
         (self.row, self.col)
     }
+
+    /// Creates a `Position` from its algebraic notation, such as `e4`.
+    ///
+    /// # Arguments
+    ///
+    /// * `notation` - A string slice containing a file letter and a rank digit.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Position>` containing the position, or `None` if the notation is invalid.
+    pub fn from_notation(notation: &str) -> Option<Position> {
+        let mut chars = notation.chars();
+        let (file, rank) = match (chars.next(), chars.next(), chars.next()) {
+            (Some(file), Some(rank), None) => (file, rank),
+            _ => return None,
+        };
+
+        if !('a'..='h').contains(&file) || !('1'..='8').contains(&rank) {
+            return None;
+        }
+
+        Some(Position::new(
+            (b'8' - rank as u8) as usize,
+            (file as u8 - b'a') as usize,
+        ))
+    }
+
+    /// Gets the algebraic notation of the position, such as `e4`.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the file letter and the rank digit.
+    pub fn get_notation(&self) -> String {
+        format!(
+            "{}{}",
+            (b'a' + self.col as u8) as char,
+            (b'8' - self.row as u8) as char
+        )
+    }
+
+    /// Creates a `Position` from a square index, where `a1` is 0, `h1` is 7 and
+    /// `h8` is 63.
+    ///
+    /// # Arguments
+    ///
+    /// * `square` - The index of the square, from 0 to 63.
+    ///
+    /// # Returns
+    ///
+    /// A new `Position` instance.
+    pub fn from_square(square: usize) -> Position {
+        Position::new(7 - square / 8, square % 8)
+    }
+
+    /// Gets the square index of the position, where `a1` is 0, `h1` is 7 and `h8`
+    /// is 63.
+    ///
+    /// # Returns
+    ///
+    /// The index of the square, from 0 to 63.
+    pub fn get_square(&self) -> usize {
+        (7 - self.row) * 8 + self.col
+    }
+}
+
+impl From<Position> for String {
+    fn from(position: Position) -> String {
+        position.get_notation()
+    }
+}
+
+impl TryFrom<String> for Position {
+    type Error = String;
+
+    fn try_from(notation: String) -> Result<Position, String> {
+        Position::from_notation(&notation).ok_or_else(|| format!("invalid square '{}'", notation))
+    }
 }
@@ -0,0 +1,431 @@
+use std::time::Duration;
+
+use yew::prelude::*;
+
+use crate::{
+    board::Board, cell::Cell, clock::format_time, color::Color, kind::Kind, outcome::GameResult,
+    piece::Piece, position::Position,
+};
+
+impl Board {
+    /// Renders the board as HTML.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_click` - A callback function to handle click events on the board cells.
+    /// * `on_promote` - A callback function to handle the choice of a promotion piece.
+    /// * `on_auto_queen` - A callback function to handle toggling the auto-queen setting.
+    /// * `on_claim_draw` - A callback function to handle a draw claim.
+    /// * `on_undo` - A callback function to handle taking back a move.
+    /// * `on_redo` - A callback function to handle playing again a move taken back.
+    /// * `on_pause` - A callback function to handle pausing or resuming the clocks.
+    /// * `on_resign` - A callback function to handle the resignation of a player.
+    /// * `on_offer_draw` - A callback function to handle a player offering a draw.
+    /// * `on_answer_draw` - A callback function to handle a player accepting (`true`)
+    ///   or declining (`false`) a draw offer.
+    ///
+    /// # Returns
+    ///
+    /// An `Html` representation of the board.
+    #[allow(clippy::too_many_arguments)]
+    pub fn render(
+        &self,
+        on_click: Callback<Position>,
+        on_promote: Callback<Kind>,
+        on_auto_queen: Callback<bool>,
+        on_claim_draw: Callback<()>,
+        on_undo: Callback<()>,
+        on_redo: Callback<()>,
+        on_pause: Callback<()>,
+        on_resign: Callback<Color>,
+        on_offer_draw: Callback<Color>,
+        on_answer_draw: Callback<(Color, bool)>,
+    ) -> Html {
+        html! {
+            <div class={classes!("container")}>
+                {self.render_win_screen()}
+                <div class={classes!("container-board")}>
+                    {self.render_board(on_click.clone())}
+                    {self.render_promotion(on_promote)}
+                </div>
+                <div class={classes!("container-data")}>
+                    <div class={classes!("player")}>
+                        {self.render_score(self.get_black_score(), "score")}
+                        {self.render_clock(Color::Black)}
+                    </div>
+                    {self.render_game_actions(Color::Black, on_resign.clone(), on_offer_draw.clone(), on_answer_draw.clone())}
+                    {self.render_notation()}
+                    <div class={classes!("player")}>
+                        {self.render_score(self.get_white_score(), "score")}
+                        {self.render_clock(Color::White)}
+                    </div>
+                    {self.render_game_actions(Color::White, on_resign, on_offer_draw, on_answer_draw)}
+                    {self.render_pause(on_pause)}
+                    {self.render_undo_redo(on_undo, on_redo)}
+                    {self.render_download_pgn()}
+                    {self.render_claim_draw(on_claim_draw)}
+                    {self.render_auto_queen(on_auto_queen)}
+                </div>
+            </div>
+        }
+    }
+
+    /// Renders the win screen.
+    ///
+    /// # Returns
+    ///
+    /// An `Html` representation of the win screen.
+    fn render_win_screen(&self) -> Html {
+        html! {
+            <div class={classes!(if self.is_end() { "win-screen" } else { "win-screen hidden" })}>
+                {match self.get_result() {
+                    GameResult::WhiteWins => html! {
+                        <>
+                            <p class={classes!("win-screen-text", "win-screen-text-white")}>{"WHITE WON"}</p>
+                            <div class={classes!("win-screen-container", "win-screen-container-white")}></div>
+                        </>
+                    },
+                    GameResult::BlackWins => html! {
+                        <>
+                            <p class={classes!("win-screen-text", "win-screen-text-black")}>{"BLACK WON"}</p>
+                            <div class={classes!("win-screen-container", "win-screen-container-black")}></div>
+                        </>
+                    },
+                    GameResult::Draw => html! {
+                        <>
+                            <p class={classes!("win-screen-text", "win-screen-text-draw")}>{"DRAW"}</p>
+                            <div class={classes!("win-screen-container", "win-screen-container-draw")}></div>
+                        </>
+                    },
+                    GameResult::Ongoing => html! {},
+                }}
+                if let Some(termination) = self.get_termination() {
+                    <p class={classes!("win-screen-reason")}>
+                        {format!("{} by {}", self.get_result(), termination.get_description())}
+                    </p>
+                }
+            </div>
+        }
+    }
+
+    /// Renders the promotion picker shown while a pawn waits for its new piece.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_promote` - A callback function to handle the choice of a promotion piece.
+    ///
+    /// # Returns
+    ///
+    /// An `Html` representation of the promotion picker.
+    fn render_promotion(&self, on_promote: Callback<Kind>) -> Html {
+        if self.get_pending_promotion().is_none() {
+            return html! {};
+        }
+
+        html! {
+            <div class={classes!("promotion")}>
+                {for [Kind::Queen, Kind::Rook, Kind::Bishop, Kind::Knight].iter().map(|kind| {
+                    let kind: Kind = *kind;
+                    let on_click = {
+                        let on_promote = on_promote.clone();
+                        Callback::from(move |_| on_promote.emit(kind))
+                    };
+                    html! {
+                        <div class={classes!("promotion-choice")} onclick={on_click}>
+                            <img src={Piece::new(kind, self.get_color_turn()).get_svg()} height="60px" />
+                        </div>
+                    }
+                })}
+            </div>
+        }
+    }
+
+    /// Renders the link downloading the game as a PGN file.
+    ///
+    /// # Returns
+    ///
+    /// An `Html` representation of the download link.
+    fn render_download_pgn(&self) -> Html {
+        let mut href: String = "data:application/x-chess-pgn;charset=utf-8,".to_string();
+        for byte in self.to_pgn().bytes() {
+            if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+                href.push(byte as char);
+            } else {
+                href.push_str(&format!("%{:02X}", byte));
+            }
+        }
+
+        html! {
+            <a class={classes!("action")} href={href} download="game.pgn">{"download PGN"}</a>
+        }
+    }
+
+    /// Renders the buttons to take back a move and to play it again.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_undo` - A callback function to handle taking back a move.
+    /// * `on_redo` - A callback function to handle playing again a move taken back.
+    ///
+    /// # Returns
+    ///
+    /// An `Html` representation of the undo and redo buttons.
+    fn render_undo_redo(&self, on_undo: Callback<()>, on_redo: Callback<()>) -> Html {
+        html! {
+            <div class={classes!("actions")}>
+                <button
+                    class={classes!("action")}
+                    title="Ctrl+Z"
+                    disabled={!self.can_undo()}
+                    onclick={Callback::from(move |_| on_undo.emit(()))}
+                >
+                    {"undo"}
+                </button>
+                <button
+                    class={classes!("action")}
+                    title="Ctrl+Y"
+                    disabled={!self.can_redo()}
+                    onclick={Callback::from(move |_| on_redo.emit(()))}
+                >
+                    {"redo"}
+                </button>
+            </div>
+        }
+    }
+
+    /// Renders the actions of a player other than moves: resigning, and offering a
+    /// draw or answering the offer of the opponent. Nothing is shown once the game is
+    /// over.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color of the player.
+    /// * `on_resign` - A callback function to handle the resignation of a player.
+    /// * `on_offer_draw` - A callback function to handle a player offering a draw.
+    /// * `on_answer_draw` - A callback function to handle the player accepting or
+    ///   declining a draw offer.
+    ///
+    /// # Returns
+    ///
+    /// An `Html` representation of the actions of the player.
+    fn render_game_actions(
+        &self,
+        color: Color,
+        on_resign: Callback<Color>,
+        on_offer_draw: Callback<Color>,
+        on_answer_draw: Callback<(Color, bool)>,
+    ) -> Html {
+        if self.is_end() {
+            return html! {};
+        }
+
+        html! {
+            <div class={classes!("actions")}>
+                <button class={classes!("action")} onclick={Callback::from(move |_| on_resign.emit(color))}>
+                    {"resign"}
+                </button>
+                {match self.get_draw_offer() {
+                    None => html! {
+                        <button class={classes!("action")} onclick={Callback::from(move |_| on_offer_draw.emit(color))}>
+                            {"offer draw"}
+                        </button>
+                    },
+                    Some(offering) if offering == color => html! {
+                        <span class={classes!("setting")}>{"draw offered"}</span>
+                    },
+                    Some(_) => {
+                        let on_decline: Callback<(Color, bool)> = on_answer_draw.clone();
+                        html! {
+                            <>
+                                <button class={classes!("action")} onclick={Callback::from(move |_| on_answer_draw.emit((color, true)))}>
+                                    {"accept draw"}
+                                </button>
+                                <button class={classes!("action")} onclick={Callback::from(move |_| on_decline.emit((color, false)))}>
+                                    {"decline draw"}
+                                </button>
+                            </>
+                        }
+                    }
+                }}
+            </div>
+        }
+    }
+
+    /// Renders the button to claim a draw, shown only when a draw can be claimed.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_claim_draw` - A callback function to handle the claim.
+    ///
+    /// # Returns
+    ///
+    /// An `Html` representation of the claim button.
+    fn render_claim_draw(&self, on_claim_draw: Callback<()>) -> Html {
+        match self.get_claimable_draw() {
+            Some(outcome) => html! {
+                <button class={classes!("action")} onclick={Callback::from(move |_| on_claim_draw.emit(()))}>
+                    {format!("claim draw ({})", outcome.get_description())}
+                </button>
+            },
+            None => html! {},
+        }
+    }
+
+    /// Renders the auto-queen setting.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_auto_queen` - A callback function to handle toggling the setting.
+    ///
+    /// # Returns
+    ///
+    /// An `Html` representation of the auto-queen checkbox.
+    fn render_auto_queen(&self, on_auto_queen: Callback<bool>) -> Html {
+        let auto_queen: bool = self.get_auto_queen();
+        let on_change: Callback<Event> = Callback::from(move |_| on_auto_queen.emit(!auto_queen));
+
+        html! {
+            <label class={classes!("setting")}>
+                <input type="checkbox" checked={auto_queen} onchange={on_change} />
+                {"auto queen"}
+            </label>
+        }
+    }
+
+    /// Renders the board.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_click` - A callback function to handle click events on the board cells.
+    ///
+    /// # Returns
+    ///
+    /// An `Html` representation of the board.
+    fn render_board(&self, on_click: Callback<Position>) -> Html {
+        html! {
+            <div class={classes!("board")}>
+                {for (0..self.get_size()).map(|row_idx| {
+                    html! {
+                        <div class="row">
+                            {for (0..self.get_size()).map(|col_idx| {
+                                let cell: &Cell = self.get_cell(Position::new(row_idx, col_idx));
+                                let on_click = {
+                                    let on_click = on_click.clone();
+                                    Callback::from(move |_| on_click.emit(Position::new(row_idx, col_idx)))
+                                };
+                                let cell_classes = classes!(
+                                    if cell.get_is_selected() { "cell-move" } else { "" },
+                                    if cell.get_is_check() { "cell-check" } else { "" },
+                                    if cell.get_color() == Color::White { "cell cell-white" } else { "cell cell-black" }
+                                );
+                                html! {
+                                    <div class={cell_classes} onclick={on_click}>
+                                        if cell.get_piece().is_some() {
+                                            <img src={cell.get_piece().unwrap().get_svg()} height="60px" />
+                                        }
+                                    </div>
+                                }
+                            })}
+                        </div>
+                    }
+                })}
+            </div>
+        }
+    }
+
+    /// Renders the score.
+    ///
+    /// # Arguments
+    ///
+    /// * `score` - The score to display.
+    ///
+    /// # Returns
+    ///
+    /// An `Html` representation of the score.
+    fn render_score(&self, score: u8, label: &str) -> Html {
+        html! {
+            <div class={classes!("score")}>
+                {format!("{} : {}", label, score)}
+            </div>
+        }
+    }
+
+    /// Renders the clock of a player, shown only when the game has a time control.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color of the player.
+    ///
+    /// # Returns
+    ///
+    /// An `Html` representation of the clock.
+    fn render_clock(&self, color: Color) -> Html {
+        let remaining: Duration = match self.get_remaining_time(color) {
+            Some(remaining) => remaining,
+            None => return html! {},
+        };
+        let running: bool =
+            !self.is_clock_paused() && !self.is_end() && self.get_color_turn() == color;
+        let clock_classes = classes!(
+            "clock",
+            if running { "clock-running" } else { "" },
+            if remaining.is_zero() {
+                "clock-flag"
+            } else {
+                ""
+            }
+        );
+
+        html! {
+            <div class={clock_classes}>
+                {format_time(remaining)}
+            </div>
+        }
+    }
+
+    /// Renders the button to pause or resume the clocks, shown only while a timed game
+    /// is being played.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_pause` - A callback function to handle pausing or resuming the clocks.
+    ///
+    /// # Returns
+    ///
+    /// An `Html` representation of the pause button.
+    fn render_pause(&self, on_pause: Callback<()>) -> Html {
+        if self.get_clock().is_none() || self.is_end() {
+            return html! {};
+        }
+
+        html! {
+            <button class={classes!("action")} onclick={Callback::from(move |_| on_pause.emit(()))}>
+                {if self.is_clock_paused() { "resume" } else { "pause" }}
+            </button>
+        }
+    }
+
+    /// Renders the notation.
+    ///
+    /// # Returns
+    ///
+    /// An `Html` representation of the notation.
+    fn render_notation(&self) -> Html {
+        html! {
+            <div class={classes!("notation")}>
+                {for self.get_notations().chunks(2).enumerate().map(|(index, chunk)| {
+                    let white_move = chunk.first().unwrap_or(&String::new()).clone();
+                    let black_move = chunk.get(1).unwrap_or(&String::new()).clone();
+                    let color_line  = classes!(if index % 2 == 0 { "notation-line notation-line-white" } else { "notation-line notation-line-black" });
+                    html! {
+                        <div class={color_line}>
+                            <div class={classes!("notation-column")}>{format!("{}",index+1)}</div>
+                            <div class={classes!("notation-column")}>{white_move}</div>
+                            <div class={classes!("notation-column")}>{black_move}</div>
+                        </div>
+                    }
+                })}
+            </div>
+        }
+    }
+}
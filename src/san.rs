@@ -0,0 +1,266 @@
+use std::fmt;
+
+use crate::{
+    board::Board, color::Color, kind::Kind, moves::Move, piece::Piece, position::Position,
+    shift::Shift,
+};
+
+/// Represents the reasons a SAN (Standard Algebraic Notation) string can be rejected.
+///
+/// # Variants
+///
+/// - `Invalid`: The string is not well formed SAN.
+/// - `Illegal`: No legal move in the position matches the string.
+/// - `Ambiguous`: Several legal moves match the string.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SanError {
+    Invalid(String),
+    Illegal(String),
+    Ambiguous(String),
+}
+
+impl fmt::Display for SanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SanError::Invalid(san) => write!(f, "'{}' is not valid SAN", san),
+            SanError::Illegal(san) => write!(f, "'{}' is not a legal move", san),
+            SanError::Ambiguous(san) => write!(f, "'{}' matches several legal moves", san),
+        }
+    }
+}
+
+impl std::error::Error for SanError {}
+
+/// Gets the SAN letter of a piece kind.
+///
+/// # Arguments
+///
+/// * `kind` - The kind of the piece.
+///
+/// # Returns
+///
+/// A `char` containing the uppercase letter, or a space for pawns.
+fn get_letter(kind: Kind) -> char {
+    Piece::new(kind, Color::White).get_symbol()
+}
+
+/// Gets the piece kind of a SAN letter.
+///
+/// # Arguments
+///
+/// * `letter` - An uppercase piece letter.
+///
+/// # Returns
+///
+/// An `Option<Kind>` containing the kind, or `None` if the letter is not a piece.
+fn get_kind(letter: char) -> Option<Kind> {
+    match letter {
+        'N' => Some(Kind::Knight),
+        'B' => Some(Kind::Bishop),
+        'R' => Some(Kind::Rook),
+        'Q' => Some(Kind::Queen),
+        'K' => Some(Kind::King),
+        _ => None,
+    }
+}
+
+impl Board {
+    /// Converts a legal move of the side to move into Standard Algebraic Notation.
+    ///
+    /// # Arguments
+    ///
+    /// * `chess_move` - The move to convert.
+    ///
+    /// # Returns
+    ///
+    /// A `String` such as `Nbd7`, `exd6`, `e8=N+` or `O-O#`.
+    pub fn get_san(&self, chess_move: Move) -> String {
+        let mut san: String =
+            self.get_san_without_suffix(chess_move.get_from(), chess_move.get_to());
+        if let Some(kind) = chess_move.get_promotion() {
+            san.push('=');
+            san.push(get_letter(kind));
+        }
+        san.push_str(self.play_move(chess_move).get_check_suffix());
+        san
+    }
+
+    /// Converts a move into Standard Algebraic Notation, leaving out the promotion
+    /// and the check or checkmate suffix.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The starting position of the piece.
+    /// * `to` - The ending position of the piece.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the castling, piece letter, disambiguation, capture and target.
+    pub(crate) fn get_san_without_suffix(&self, from: Position, to: Position) -> String {
+        if self.is_castling(from, to) {
+            return if to.get_col() > from.get_col() {
+                "O-O".to_string()
+            } else {
+                "O-O-O".to_string()
+            };
+        }
+
+        let kind: Kind = self.get_cell(from).get_piece_kind();
+        let is_capture: bool =
+            self.get_cell(to).get_piece().is_some() || self.is_en_passant(from, to);
+        let mut san: String = String::new();
+
+        if kind == Kind::Pawn {
+            if is_capture {
+                san.push_str(&from.get_notation()[..1]);
+            }
+        } else {
+            san.push(get_letter(kind));
+
+            let others: Vec<Position> = self
+                .legal_moves()
+                .into_iter()
+                .filter(|other| {
+                    other.get_to() == to
+                        && other.get_from() != from
+                        && other.get_piece().get_kind() == kind
+                })
+                .map(|other| other.get_from())
+                .collect();
+
+            if !others.is_empty() {
+                let notation: String = from.get_notation();
+                if others.iter().all(|other| other.get_col() != from.get_col()) {
+                    san.push_str(&notation[..1]);
+                } else if others.iter().all(|other| other.get_row() != from.get_row()) {
+                    san.push_str(&notation[1..]);
+                } else {
+                    san.push_str(&notation);
+                }
+            }
+        }
+
+        if is_capture {
+            san.push('x');
+        }
+        san.push_str(&to.get_notation());
+        san
+    }
+
+    /// Gets the SAN suffix describing the status of the side to move.
+    ///
+    /// # Returns
+    ///
+    /// `#` if it is checkmated, `+` if it is in check, and an empty string otherwise.
+    pub(crate) fn get_check_suffix(&self) -> &'static str {
+        let color: Color = self.get_color_turn();
+        let is_check: bool = match self.get_position_king(color) {
+            Some(position_king) => Shift::new().is_in_check(self, position_king, color),
+            None => false,
+        };
+
+        if !is_check {
+            ""
+        } else if self.has_legal_moves(color) {
+            "+"
+        } else {
+            "#"
+        }
+    }
+
+    /// Resolves a SAN string to a legal move of the side to move.
+    ///
+    /// Check, checkmate and annotation symbols are ignored, castling accepts both
+    /// letters and zeros, and the promotion may be written with or without `=`.
+    ///
+    /// # Arguments
+    ///
+    /// * `san` - A string slice such as `Nf3`, `exd5`, `R1e2`, `e8=Q` or `O-O-O`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the move, or a `SanError` if no single legal move matches.
+    pub fn parse_san(&self, san: &str) -> Result<Move, SanError> {
+        let text: &str = san.trim().trim_end_matches(['+', '#', '!', '?']);
+        let invalid = || SanError::Invalid(san.to_string());
+
+        let candidates: Vec<Move> = match text {
+            "O-O" | "0-0" | "O-O-O" | "0-0-0" => {
+                let is_king_side: bool = text.len() == 3;
+                self.legal_moves()
+                    .into_iter()
+                    .filter(|chess_move| {
+                        chess_move.is_castling()
+                            && (chess_move.get_to().get_col() > chess_move.get_from().get_col())
+                                == is_king_side
+                    })
+                    .collect()
+            }
+            _ => {
+                let mut chars: Vec<char> = text.chars().collect();
+
+                let promotion: Option<Kind> = match chars.last().copied().and_then(get_kind) {
+                    Some(kind) => {
+                        chars.pop();
+                        if chars.last() == Some(&'=') {
+                            chars.pop();
+                        }
+                        Some(kind)
+                    }
+                    None => None,
+                };
+
+                let kind: Kind = match chars.first().copied().and_then(get_kind) {
+                    Some(kind) => {
+                        chars.remove(0);
+                        kind
+                    }
+                    None => Kind::Pawn,
+                };
+
+                if chars.len() < 2 {
+                    return Err(invalid());
+                }
+                let target: String = chars.split_off(chars.len() - 2).into_iter().collect();
+                let to: Position = Position::from_notation(&target).ok_or_else(invalid)?;
+
+                if chars.last() == Some(&'x') {
+                    chars.pop();
+                }
+
+                let mut file: Option<usize> = None;
+                let mut rank: Option<usize> = None;
+                for char in chars {
+                    match char {
+                        'a'..='h' if file.is_none() => file = Some((char as u8 - b'a') as usize),
+                        '1'..='8' if rank.is_none() => rank = Some((b'8' - char as u8) as usize),
+                        _ => return Err(invalid()),
+                    }
+                }
+
+                if promotion.is_some() && kind != Kind::Pawn {
+                    return Err(invalid());
+                }
+
+                self.legal_moves()
+                    .into_iter()
+                    .filter(|chess_move| {
+                        let from: Position = chess_move.get_from();
+                        chess_move.get_to() == to
+                            && chess_move.get_piece().get_kind() == kind
+                            && !chess_move.is_castling()
+                            && chess_move.get_promotion() == promotion
+                            && file.is_none_or(|file| from.get_col() == file)
+                            && rank.is_none_or(|rank| from.get_row() == rank)
+                    })
+                    .collect()
+            }
+        };
+
+        match candidates.len() {
+            0 => Err(SanError::Illegal(san.to_string())),
+            1 => Ok(candidates[0]),
+            _ => Err(SanError::Ambiguous(san.to_string())),
+        }
+    }
+}
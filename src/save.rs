@@ -0,0 +1,964 @@
+use std::{collections::HashMap, fmt, sync::Arc, time::Duration};
+
+use crate::{
+    board::Board,
+    clock::{SystemTimeSource, TimeControl, TimeControlError, TimeSource},
+    color::Color,
+    event::GameEvent,
+    moves::Move,
+    outcome::{GameResult, Outcome, Termination},
+    pgn::{parse_pgn, play_san, PgnError, PgnGame},
+};
+
+/// The version of the save format written by `SavedGame::serialize`.
+pub const SAVE_VERSION: u32 = 2;
+
+/// The version of the schema of the records written by `GameRecord`.
+pub const RECORD_VERSION: u32 = 1;
+
+/// The first word of a save, followed by its version.
+const SAVE_HEADER: &str = "chess-save";
+
+/// The name given to games saved as plain PGN, before saves had a header.
+const DEFAULT_NAME: &str = "Saved game";
+
+/// The level of the computer given to games saved as plain PGN.
+const DEFAULT_LEVEL: u32 = 3;
+
+/// The key of the game being played in a store.
+const CURRENT_KEY: &str = "chess.current";
+
+/// The key of the list of saved games in a store.
+const INDEX_KEY: &str = "chess.games";
+
+/// Represents the reasons a saved game cannot be read or stored.
+///
+/// # Variants
+///
+/// - `UnsupportedVersion`: The save was written by a newer version of the format.
+/// - `InvalidLine`: A line of the save is malformed or cannot be applied to the game.
+/// - `InvalidRecord`: A game record does not describe a game which can be replayed.
+/// - `MissingGame`: The save holds no game.
+/// - `Pgn`: The moves of the game cannot be read or replayed.
+/// - `NotFound`: No saved game has the given identifier.
+/// - `Storage`: The store refused to write the save.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SaveError {
+    UnsupportedVersion(u32),
+    InvalidLine(String),
+    InvalidRecord(String),
+    MissingGame,
+    Pgn(PgnError),
+    NotFound(u32),
+    Storage(String),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::UnsupportedVersion(version) => {
+                write!(f, "unsupported save version {}", version)
+            }
+            SaveError::InvalidLine(line) => write!(f, "invalid line '{}'", line),
+            SaveError::InvalidRecord(message) => write!(f, "invalid game record: {}", message),
+            SaveError::MissingGame => write!(f, "the save holds no game"),
+            SaveError::Pgn(error) => write!(f, "invalid game: {}", error),
+            SaveError::NotFound(id) => write!(f, "no saved game {}", id),
+            SaveError::Storage(message) => write!(f, "cannot store the game: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<PgnError> for SaveError {
+    fn from(error: PgnError) -> SaveError {
+        SaveError::Pgn(error)
+    }
+}
+
+impl From<TimeControlError> for SaveError {
+    fn from(error: TimeControlError) -> SaveError {
+        SaveError::InvalidLine(error.to_string())
+    }
+}
+
+/// Represents a game with the settings it was played with, as saved between two
+/// visits of the page.
+#[derive(Clone)]
+pub struct SavedGame {
+    /// The name of the game shown in the list of saved games.
+    name: String,
+    /// The game, with its clocks and events.
+    board: Board,
+    /// The color played by the computer, or `None` for two players.
+    computer: Option<Color>,
+    /// The level of the computer.
+    level: u32,
+}
+
+impl SavedGame {
+    /// Creates a saved game.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the game.
+    /// * `board` - The game.
+    /// * `computer` - The color played by the computer, or `None` for two players.
+    /// * `level` - The level of the computer.
+    ///
+    /// # Returns
+    ///
+    /// A new `SavedGame` instance.
+    pub fn new(name: &str, board: Board, computer: Option<Color>, level: u32) -> SavedGame {
+        SavedGame {
+            name: get_single_line(name),
+            board,
+            computer,
+            level,
+        }
+    }
+
+    /// Gets the name of the game.
+    ///
+    /// # Returns
+    ///
+    /// A string slice containing the name.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Sets the name of the game. Line breaks are replaced by spaces.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The new name.
+    pub fn set_name(&mut self, name: &str) {
+        self.name = get_single_line(name);
+    }
+
+    /// Gets the game.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `Board` of the game.
+    pub fn get_board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Gets the color played by the computer.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Color>` containing the color of the computer, or `None` for two players.
+    pub fn get_computer(&self) -> Option<Color> {
+        self.computer
+    }
+
+    /// Gets the level of the computer.
+    ///
+    /// # Returns
+    ///
+    /// The level as a `u32`.
+    pub fn get_level(&self) -> u32 {
+        self.level
+    }
+
+    /// Writes the game in the current version of the save format: a header line,
+    /// one line per setting, clock and event, an empty line and the PGN of the game.
+    /// Running clocks are written with the time of the save.
+    /// The game should not have a move waiting for its promotion piece.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the save.
+    pub fn serialize(&self) -> String {
+        let board: &Board = &self.board;
+        let mut lines: Vec<String> = vec![
+            format!("{} {}", SAVE_HEADER, SAVE_VERSION),
+            format!("name {}", self.name),
+            format!("auto-queen {}", board.get_auto_queen()),
+            format!(
+                "computer {} {}",
+                self.computer.map_or("none", get_color_name),
+                self.level
+            ),
+        ];
+
+        if let Some(clock) = board.get_clock() {
+            let mut line: String = format!(
+                "clock {} {} {}",
+                clock.get_time_control(),
+                get_millis(board.get_remaining_time(Color::White)),
+                get_millis(board.get_remaining_time(Color::Black))
+            );
+            // A running clock keeps the time of the save, so the time spent until
+            // the game is reopened is charged to the player to move.
+            if !board.is_clock_paused() && !board.is_end() {
+                line.push_str(&format!(" {}", board.get_time_source().now_ms()));
+            }
+            lines.push(line);
+        }
+        for (ply, event) in board.get_events() {
+            let (name, color): (&str, Color) = match event {
+                GameEvent::DrawOffered(color) => ("offer", *color),
+                GameEvent::DrawAccepted(color) => ("accept", *color),
+                GameEvent::DrawDeclined(color) => ("decline", *color),
+                GameEvent::Resigned(color) => ("resign", *color),
+            };
+            lines.push(format!("event {} {} {}", ply, name, get_color_name(color)));
+        }
+        match board.get_outcome() {
+            Some(Outcome::ThreefoldRepetition) | Some(Outcome::FiftyMoveRule) => {
+                lines.push("ending claimed".to_string());
+            }
+            Some(Outcome::Abandonment(winner)) => {
+                lines.push(format!("ending abandoned {}", get_color_name(!winner)));
+            }
+            _ => {}
+        }
+
+        format!("{}\n\n{}", lines.join("\n"), board.to_pgn())
+    }
+
+    /// Reads a save written by any version of the format. Text without a header is
+    /// read as the PGN of a game saved before the format had versions.
+    ///
+    /// The moves are replayed with the events between them, so the game is restored
+    /// with its history and can be taken back. Clocks are restored paused, the time
+    /// since a save made while they were running taken from the player to move.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The save.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the saved game, or a `SaveError` if it cannot be read.
+    pub fn deserialize(text: &str) -> Result<SavedGame, SaveError> {
+        SavedGame::deserialize_with_time_source(text, Arc::new(SystemTimeSource))
+    }
+
+    /// Reads a save like `deserialize`, with the clocks of the game reading the
+    /// given source of time.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The save.
+    /// * `time_source` - The source of the time, such as a `MockTimeSource` in tests.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the saved game, or a `SaveError` if it cannot be read.
+    pub fn deserialize_with_time_source(
+        text: &str,
+        time_source: Arc<dyn TimeSource>,
+    ) -> Result<SavedGame, SaveError> {
+        let text: &str = text.trim_start();
+        let (header, pgn): (&str, &str) = match text.strip_prefix(SAVE_HEADER) {
+            Some(_) => text.split_once("\n\n").unwrap_or((text, "")),
+            None => ("", text),
+        };
+        let mut lines: std::str::Lines<'_> = header.lines();
+        let version: u32 = match lines.next() {
+            Some(line) => line
+                .strip_prefix(SAVE_HEADER)
+                .and_then(|version| version.trim().parse().ok())
+                .ok_or_else(|| SaveError::InvalidLine(line.to_string()))?,
+            None => 0,
+        };
+        if version > SAVE_VERSION {
+            return Err(SaveError::UnsupportedVersion(version));
+        }
+
+        let games: Vec<PgnGame> = parse_pgn(pgn)?;
+        let game: &PgnGame = games.first().ok_or(SaveError::MissingGame)?;
+        let mut saved: SavedGame =
+            SavedGame::new(DEFAULT_NAME, game.get_initial_board()?, None, DEFAULT_LEVEL);
+        saved.board.set_time_source(time_source.clone());
+        // As when importing a PGN game, the moves are replayed past the draws which
+        // need no claim, which are then applied to the last position.
+        saved.board.set_automatic_draws(false);
+        let mut events: Vec<(usize, GameEvent, String)> = Vec::new();
+        let mut clock: Option<(TimeControl, Duration, Duration, Option<u64>)> = None;
+        let mut ending: Option<String> = None;
+
+        for line in lines {
+            let invalid = || SaveError::InvalidLine(line.to_string());
+            let (key, value): (&str, &str) = line.split_once(' ').unwrap_or((line, ""));
+            let fields: Vec<&str> = value.split_whitespace().collect();
+            match (key, fields.as_slice()) {
+                ("name", _) => saved.set_name(value),
+                ("auto-queen", [auto_queen]) => {
+                    saved
+                        .board
+                        .set_auto_queen(auto_queen.parse().map_err(|_| invalid())?);
+                }
+                ("computer", [color, level]) => {
+                    saved.computer = match *color {
+                        "none" => None,
+                        color => Some(parse_color(color).ok_or_else(invalid)?),
+                    };
+                    saved.level = level.parse().map_err(|_| invalid())?;
+                }
+                ("clock", [time_control, white, black, saved_at @ ..]) if saved_at.len() <= 1 => {
+                    let parse_millis = |millis: &str| millis.parse().map(Duration::from_millis);
+                    clock = Some((
+                        TimeControl::parse(time_control)?,
+                        parse_millis(white).map_err(|_| invalid())?,
+                        parse_millis(black).map_err(|_| invalid())?,
+                        match saved_at.first() {
+                            Some(saved_at) => Some(saved_at.parse().map_err(|_| invalid())?),
+                            None => None,
+                        },
+                    ));
+                }
+                ("event", [ply, name, color]) => {
+                    let color: Color = parse_color(color).ok_or_else(invalid)?;
+                    let event: GameEvent = match *name {
+                        "offer" => GameEvent::DrawOffered(color),
+                        "accept" => GameEvent::DrawAccepted(color),
+                        "decline" => GameEvent::DrawDeclined(color),
+                        "resign" => GameEvent::Resigned(color),
+                        _ => return Err(invalid()),
+                    };
+                    events.push((ply.parse().map_err(|_| invalid())?, event, line.to_string()));
+                }
+                ("ending", _) => ending = Some(line.to_string()),
+                _ => return Err(invalid()),
+            }
+        }
+
+        let mut events: std::iter::Peekable<std::vec::IntoIter<(usize, GameEvent, String)>> =
+            events.into_iter().peekable();
+        for ply in 0..=game.get_moves().len() {
+            while let Some((_, event, line)) = events.next_if(|(event_ply, _, _)| *event_ply == ply)
+            {
+                if !apply_event(&mut saved.board, event) {
+                    return Err(SaveError::InvalidLine(line));
+                }
+            }
+            if let Some(pgn_move) = game.get_moves().get(ply) {
+                play_san(&mut saved.board, pgn_move.get_san(), ply + 1)?;
+            }
+        }
+        if let Some((_, _, line)) = events.next() {
+            return Err(SaveError::InvalidLine(line));
+        }
+        saved.board.set_automatic_draws(true);
+        if let Some(line) = ending {
+            apply_ending(&mut saved.board, &line)?;
+        }
+        if let Some((time_control, mut white, mut black, saved_at)) = clock {
+            if let Some(saved_at) = saved_at {
+                let elapsed: Duration =
+                    Duration::from_millis(time_source.now_ms().saturating_sub(saved_at));
+                match saved.board.get_color_turn() {
+                    Color::White => white = white.saturating_sub(elapsed),
+                    Color::Black => black = black.saturating_sub(elapsed),
+                }
+            }
+            saved.board.restore_clock(time_control, white, black);
+            saved.board.check_flag();
+        }
+        Ok(saved)
+    }
+}
+
+/// Represents a complete game in a stable form meant for serialization: the
+/// initial position, the tags, the moves in UCI notation, the events, the way the
+/// game ended and its clocks. With the `serde` feature, a `Board` is serialized as
+/// its record.
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameRecord {
+    /// The version of the schema of the record.
+    version: u32,
+    /// The FEN of the position the game started from.
+    fen: String,
+    /// The PGN tags of the game, in their order, without the `Result` tag.
+    tags: Vec<(String, String)>,
+    /// The moves in UCI notation, such as `e7e8q`.
+    moves: Vec<String>,
+    /// The events of the game.
+    events: Vec<RecordedEvent>,
+    /// The result of the game.
+    result: GameResult,
+    /// The reason the game ended, or `None` while it is being played.
+    termination: Option<Termination>,
+    /// The way the game ended, or `None` while it is being played.
+    outcome: Option<Outcome>,
+    /// The clocks of the game, or `None` for a game without time control.
+    clock: Option<RecordedClock>,
+}
+
+/// Represents an event of a game record with the number of half moves played
+/// before it.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct RecordedEvent {
+    /// The number of half moves played before the event.
+    ply: usize,
+    /// The event.
+    event: GameEvent,
+}
+
+/// Represents the clocks of a game record.
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct RecordedClock {
+    /// The time control of the game.
+    time_control: TimeControl,
+    /// The time left to white in milliseconds.
+    white_ms: u64,
+    /// The time left to black in milliseconds.
+    black_ms: u64,
+}
+
+impl GameRecord {
+    /// Gets the version of the schema of the record.
+    ///
+    /// # Returns
+    ///
+    /// The version as a `u32`.
+    pub fn get_version(&self) -> u32 {
+        self.version
+    }
+
+    /// Gets the FEN of the position the game started from.
+    ///
+    /// # Returns
+    ///
+    /// A string slice containing the initial FEN.
+    pub fn get_fen(&self) -> &str {
+        &self.fen
+    }
+
+    /// Gets the moves of the game.
+    ///
+    /// # Returns
+    ///
+    /// A slice containing the moves in UCI notation.
+    pub fn get_moves(&self) -> &[String] {
+        &self.moves
+    }
+
+    /// Gets the result of the game.
+    ///
+    /// # Returns
+    ///
+    /// The `GameResult` of the game.
+    pub fn get_result(&self) -> GameResult {
+        self.result
+    }
+
+    /// Gets the reason the game ended.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Termination>` containing the reason, or `None` while the game is
+    /// being played.
+    pub fn get_termination(&self) -> Option<Termination> {
+        self.termination
+    }
+}
+
+impl From<&Board> for GameRecord {
+    fn from(board: &Board) -> GameRecord {
+        let clock: Option<RecordedClock> = board.get_clock().map(|clock| RecordedClock {
+            time_control: clock.get_time_control().clone(),
+            white_ms: get_millis(board.get_remaining_time(Color::White)) as u64,
+            black_ms: get_millis(board.get_remaining_time(Color::Black)) as u64,
+        });
+
+        GameRecord {
+            version: RECORD_VERSION,
+            fen: board.get_initial_fen().to_string(),
+            tags: board
+                .get_pgn_tags()
+                .get_tags()
+                .iter()
+                .filter(|(name, _)| name != "Result")
+                .cloned()
+                .collect(),
+            moves: board.get_moves().iter().map(Move::get_uci).collect(),
+            events: board
+                .get_events()
+                .iter()
+                .map(|(ply, event)| RecordedEvent {
+                    ply: *ply,
+                    event: *event,
+                })
+                .collect(),
+            result: board.get_result(),
+            termination: board.get_termination(),
+            outcome: board.get_outcome(),
+            clock,
+        }
+    }
+}
+
+impl From<Board> for GameRecord {
+    fn from(board: Board) -> GameRecord {
+        GameRecord::from(&board)
+    }
+}
+
+impl TryFrom<GameRecord> for Board {
+    type Error = SaveError;
+
+    /// Replays a game record. The clocks are restored paused.
+    fn try_from(record: GameRecord) -> Result<Board, SaveError> {
+        if record.version > RECORD_VERSION {
+            return Err(SaveError::UnsupportedVersion(record.version));
+        }
+        let invalid = |message: String| SaveError::InvalidRecord(message);
+        let mut board: Board =
+            Board::from_fen(&record.fen).map_err(|error| invalid(error.to_string()))?;
+        for (name, value) in record.tags.iter() {
+            board.set_pgn_tag(name, value);
+        }
+        board.set_automatic_draws(false);
+
+        let mut events: std::iter::Peekable<std::slice::Iter<'_, RecordedEvent>> =
+            record.events.iter().peekable();
+        for ply in 0..=record.moves.len() {
+            while let Some(recorded) = events.next_if(|recorded| recorded.ply == ply) {
+                if !apply_event(&mut board, recorded.event) {
+                    return Err(invalid(format!(
+                        "{} after {} half moves",
+                        recorded.event.get_description(),
+                        ply
+                    )));
+                }
+            }
+            if let Some(uci) = record.moves.get(ply) {
+                let chess_move: Move = board
+                    .parse_uci(uci)
+                    .ok_or_else(|| invalid(format!("illegal move {}", uci)))?;
+                board
+                    .make_move(chess_move)
+                    .map_err(|error| invalid(format!("{}: {}", uci, error)))?;
+            }
+        }
+        if let Some(recorded) = events.next() {
+            return Err(invalid(format!(
+                "{} after {} half moves",
+                recorded.event.get_description(),
+                recorded.ply
+            )));
+        }
+        board.set_automatic_draws(true);
+
+        match record.outcome {
+            Some(Outcome::ThreefoldRepetition) | Some(Outcome::FiftyMoveRule) => board.claim_draw(),
+            Some(Outcome::Abandonment(winner)) => board.abandon(!winner),
+            _ => {}
+        }
+        if let Some(clock) = record.clock {
+            board.restore_clock(
+                clock.time_control,
+                Duration::from_millis(clock.white_ms),
+                Duration::from_millis(clock.black_ms),
+            );
+            board.check_flag();
+        }
+        if board.get_outcome() != record.outcome {
+            return Err(invalid("the game does not end as recorded".to_string()));
+        }
+        Ok(board)
+    }
+}
+
+/// Replays an event on a board, checking that it can happen there.
+///
+/// # Arguments
+///
+/// * `board` - The board of the game.
+/// * `event` - The event to replay.
+///
+/// # Returns
+///
+/// `true` if the event has been replayed, `false` if it cannot happen.
+fn apply_event(board: &mut Board, event: GameEvent) -> bool {
+    match event {
+        GameEvent::DrawOffered(color) => board.offer_draw(color),
+        GameEvent::DrawAccepted(color) => board.accept_draw(color),
+        GameEvent::DrawDeclined(color) => board.decline_draw(color),
+        GameEvent::Resigned(color) => board.resign(color),
+    }
+}
+
+/// Ends a game the way its save tells, for endings which moves and events do not
+/// replay.
+///
+/// # Arguments
+///
+/// * `board` - The board of the game.
+/// * `line` - The line, such as `ending claimed` or `ending abandoned black`.
+///
+/// # Returns
+///
+/// A `Result` which is a `SaveError` if the ending is unknown or cannot happen.
+fn apply_ending(board: &mut Board, line: &str) -> Result<(), SaveError> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    match fields.as_slice() {
+        ["ending", "claimed"] => board.claim_draw(),
+        ["ending", "abandoned", color] => {
+            let color: Color =
+                parse_color(color).ok_or_else(|| SaveError::InvalidLine(line.to_string()))?;
+            board.abandon(color);
+        }
+        _ => return Err(SaveError::InvalidLine(line.to_string())),
+    }
+    if board.is_end() {
+        Ok(())
+    } else {
+        Err(SaveError::InvalidLine(line.to_string()))
+    }
+}
+
+/// Gets the name of a color as written in a save.
+///
+/// # Arguments
+///
+/// * `color` - The color.
+///
+/// # Returns
+///
+/// A string slice containing `white` or `black`.
+fn get_color_name(color: Color) -> &'static str {
+    match color {
+        Color::White => "white",
+        Color::Black => "black",
+    }
+}
+
+/// Reads the name of a color as written in a save.
+///
+/// # Arguments
+///
+/// * `name` - The name of the color.
+///
+/// # Returns
+///
+/// An `Option<Color>` containing the color, or `None` if the name is unknown.
+fn parse_color(name: &str) -> Option<Color> {
+    match name {
+        "white" => Some(Color::White),
+        "black" => Some(Color::Black),
+        _ => None,
+    }
+}
+
+/// Gets a time left on a clock in milliseconds.
+///
+/// # Arguments
+///
+/// * `time` - The time left, if the game has clocks.
+///
+/// # Returns
+///
+/// The number of milliseconds, zero without clocks.
+fn get_millis(time: Option<Duration>) -> u128 {
+    time.map_or(0, |time| time.as_millis())
+}
+
+/// Replaces the line breaks of a name by spaces, so it fits on one line of a save.
+///
+/// # Arguments
+///
+/// * `name` - The name.
+///
+/// # Returns
+///
+/// A `String` containing the name on a single line.
+fn get_single_line(name: &str) -> String {
+    name.lines()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .trim()
+        .to_string()
+}
+
+/// Represents a place where saves are kept as text under string keys, such as the
+/// local storage of the browser.
+pub trait SaveStore {
+    /// Reads the text kept under a key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` containing the text, or `None` if the key is unknown.
+    fn get_item(&self, key: &str) -> Option<String>;
+
+    /// Keeps a text under a key, replacing the previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key.
+    /// * `value` - The text.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is a `SaveError` if the text cannot be kept.
+    fn set_item(&mut self, key: &str, value: &str) -> Result<(), SaveError>;
+
+    /// Forgets the text kept under a key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key.
+    fn remove_item(&mut self, key: &str);
+}
+
+/// Represents a store kept in memory, used to test the library of saved games.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStore {
+    /// The texts by key.
+    items: HashMap<String, String>,
+}
+
+impl MemoryStore {
+    /// Creates an empty store.
+    ///
+    /// # Returns
+    ///
+    /// A new `MemoryStore` instance.
+    pub fn new() -> MemoryStore {
+        MemoryStore::default()
+    }
+}
+
+impl SaveStore for MemoryStore {
+    fn get_item(&self, key: &str) -> Option<String> {
+        self.items.get(key).cloned()
+    }
+
+    fn set_item(&mut self, key: &str, value: &str) -> Result<(), SaveError> {
+        self.items.insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
+    fn remove_item(&mut self, key: &str) {
+        self.items.remove(key);
+    }
+}
+
+/// Represents the local storage of the browser.
+#[cfg(feature = "web")]
+pub struct LocalStorage {
+    /// The storage of the page.
+    storage: web_sys::Storage,
+}
+
+#[cfg(feature = "web")]
+impl LocalStorage {
+    /// Opens the local storage of the page.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<LocalStorage>` containing the storage, or `None` if the browser
+    /// does not allow it.
+    pub fn new() -> Option<LocalStorage> {
+        let storage: web_sys::Storage = web_sys::window()?.local_storage().ok()??;
+        Some(LocalStorage { storage })
+    }
+}
+
+#[cfg(feature = "web")]
+impl SaveStore for LocalStorage {
+    fn get_item(&self, key: &str) -> Option<String> {
+        self.storage.get_item(key).ok().flatten()
+    }
+
+    fn set_item(&mut self, key: &str, value: &str) -> Result<(), SaveError> {
+        self.storage
+            .set_item(key, value)
+            .map_err(|error| SaveError::Storage(format!("{:?}", error)))
+    }
+
+    fn remove_item(&mut self, key: &str) {
+        let _ = self.storage.remove_item(key);
+    }
+}
+
+/// Represents the games kept in a store: the game being played, restored when the
+/// page is opened again, and a list of games saved by name.
+pub struct GameLibrary<S: SaveStore> {
+    /// The store keeping the games.
+    store: S,
+}
+
+impl<S: SaveStore> GameLibrary<S> {
+    /// Creates a library of the games kept in a store.
+    ///
+    /// # Arguments
+    ///
+    /// * `store` - The store keeping the games.
+    ///
+    /// # Returns
+    ///
+    /// A new `GameLibrary` instance.
+    pub fn new(store: S) -> GameLibrary<S> {
+        GameLibrary { store }
+    }
+
+    /// Keeps the game being played, replacing the previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `game` - The game being played.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is a `SaveError` if the game cannot be stored.
+    pub fn save_current(&mut self, game: &SavedGame) -> Result<(), SaveError> {
+        self.store.set_item(CURRENT_KEY, &game.serialize())
+    }
+
+    /// Reads the game that was being played.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the game, or `None` if none was kept, or a `SaveError`
+    /// if it cannot be read.
+    pub fn load_current(&self) -> Result<Option<SavedGame>, SaveError> {
+        self.store
+            .get_item(CURRENT_KEY)
+            .map(|text| SavedGame::deserialize(&text))
+            .transpose()
+    }
+
+    /// Gets the list of saved games.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<(u32, String)>` containing the identifier and the name of each game,
+    /// oldest first.
+    pub fn get_games(&self) -> Vec<(u32, String)> {
+        self.store
+            .get_item(INDEX_KEY)
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| {
+                let (id, name): (&str, &str) = line.split_once(' ')?;
+                Some((id.parse().ok()?, name.to_string()))
+            })
+            .collect()
+    }
+
+    /// Adds a game to the list of saved games.
+    ///
+    /// # Arguments
+    ///
+    /// * `game` - The game to save.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the identifier of the saved game, or a `SaveError` if
+    /// it cannot be stored.
+    pub fn add_game(&mut self, game: &SavedGame) -> Result<u32, SaveError> {
+        let mut games: Vec<(u32, String)> = self.get_games();
+        let id: u32 = games.iter().map(|(id, _)| id + 1).max().unwrap_or(1);
+
+        self.store.set_item(&get_game_key(id), &game.serialize())?;
+        games.push((id, game.get_name().to_string()));
+        self.set_games(&games)?;
+        Ok(id)
+    }
+
+    /// Reads a saved game.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The identifier of the game.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the game, or a `SaveError` if it is unknown or cannot
+    /// be read.
+    pub fn open_game(&self, id: u32) -> Result<SavedGame, SaveError> {
+        let text: String = self
+            .store
+            .get_item(&get_game_key(id))
+            .ok_or(SaveError::NotFound(id))?;
+        SavedGame::deserialize(&text)
+    }
+
+    /// Renames a saved game.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The identifier of the game.
+    /// * `name` - The new name.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is a `SaveError` if the game is unknown or cannot be stored.
+    pub fn rename_game(&mut self, id: u32, name: &str) -> Result<(), SaveError> {
+        let mut game: SavedGame = self.open_game(id)?;
+        game.set_name(name);
+        self.store.set_item(&get_game_key(id), &game.serialize())?;
+
+        let mut games: Vec<(u32, String)> = self.get_games();
+        for (game_id, game_name) in games.iter_mut() {
+            if *game_id == id {
+                *game_name = game.get_name().to_string();
+            }
+        }
+        self.set_games(&games)
+    }
+
+    /// Deletes a saved game.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The identifier of the game.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is a `SaveError` if the list of games cannot be stored.
+    pub fn delete_game(&mut self, id: u32) -> Result<(), SaveError> {
+        let games: Vec<(u32, String)> = self
+            .get_games()
+            .into_iter()
+            .filter(|(game_id, _)| *game_id != id)
+            .collect();
+        self.store.remove_item(&get_game_key(id));
+        self.set_games(&games)
+    }
+
+    /// Stores the list of saved games.
+    ///
+    /// # Arguments
+    ///
+    /// * `games` - The identifier and the name of each game.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is a `SaveError` if the list cannot be stored.
+    fn set_games(&mut self, games: &[(u32, String)]) -> Result<(), SaveError> {
+        let index: Vec<String> = games
+            .iter()
+            .map(|(id, name)| format!("{} {}", id, name))
+            .collect();
+        self.store.set_item(INDEX_KEY, &index.join("\n"))
+    }
+}
+
+/// Gets the key of a saved game in a store.
+///
+/// # Arguments
+///
+/// * `id` - The identifier of the game.
+///
+/// # Returns
+///
+/// A `String` such as `chess.game.3`.
+fn get_game_key(id: u32) -> String {
+    format!("chess.game.{}", id)
+}
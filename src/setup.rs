@@ -0,0 +1,366 @@
+use crate::{
+    bitboard::{get_pawn_attacks, get_squares, Bitboards},
+    castling::CastlingRights,
+    color::Color,
+    kind::Kind,
+    moves::Move,
+    piece::Piece,
+    position::Position,
+    shift::Shift,
+    zobrist::{get_castling_key, get_en_passant_key, get_piece_key, get_turn_key},
+};
+
+/// Represents the part of a position the rules of movement depend on: the
+/// placement of the pieces, the side to move, the castling rights and the en
+/// passant square.
+///
+/// Unlike `Board`, it carries no game record and is cheap to copy, which makes it
+/// suited to looking many moves ahead.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Setup {
+    /// The placement of the pieces.
+    bitboards: Bitboards,
+    /// The color of the player whose turn it is.
+    color_turn: Color,
+    /// The castling rights still available to both players.
+    castling_rights: CastlingRights,
+    /// The square a pawn can capture en passant on the next move, if any.
+    en_passant: Option<Position>,
+}
+
+impl Setup {
+    /// Creates a new `Setup`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bitboards` - The placement of the pieces.
+    /// * `color_turn` - The color of the side to move.
+    /// * `castling_rights` - The castling rights of both players.
+    /// * `en_passant` - The en passant square, if any.
+    ///
+    /// # Returns
+    ///
+    /// A new `Setup` instance.
+    pub fn new(
+        bitboards: Bitboards,
+        color_turn: Color,
+        castling_rights: CastlingRights,
+        en_passant: Option<Position>,
+    ) -> Setup {
+        Setup {
+            bitboards,
+            color_turn,
+            castling_rights,
+            en_passant,
+        }
+    }
+
+    /// Gets the placement of the pieces.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `Bitboards` of the position.
+    pub fn get_bitboards(&self) -> &Bitboards {
+        &self.bitboards
+    }
+
+    /// Gets the color of the side to move.
+    ///
+    /// # Returns
+    ///
+    /// The color of the player whose turn it is.
+    pub fn get_color_turn(&self) -> Color {
+        self.color_turn
+    }
+
+    /// Gets the castling rights of both players.
+    ///
+    /// # Returns
+    ///
+    /// The `CastlingRights` of the position.
+    pub fn get_castling_rights(&self) -> CastlingRights {
+        self.castling_rights
+    }
+
+    /// Gets the en passant square.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Position>` containing the en passant square, if any.
+    pub fn get_en_passant(&self) -> Option<Position> {
+        self.en_passant
+    }
+
+    /// Computes the Zobrist key of the position from scratch, following the
+    /// Polyglot scheme.
+    ///
+    /// # Returns
+    ///
+    /// The 64-bit key of the position.
+    pub fn hash(&self) -> u64 {
+        self.get_placement_key() ^ self.get_en_passant_key()
+    }
+
+    /// Computes the key identifying the position for repetition purposes. Unlike
+    /// `hash`, the en passant file only counts when the capture is legal, so it
+    /// matches the keys of `Board::get_position_keys`.
+    ///
+    /// # Returns
+    ///
+    /// The 64-bit key of the position.
+    pub fn get_position_key(&self) -> u64 {
+        self.get_placement_key() ^ self.get_legal_en_passant_key()
+    }
+
+    /// Computes the Zobrist key of the pieces, the side to move and the castling
+    /// rights, leaving out the en passant file.
+    ///
+    /// # Returns
+    ///
+    /// The 64-bit key of the position without its en passant file.
+    fn get_placement_key(&self) -> u64 {
+        let mut key: u64 = get_turn_key(self.color_turn) ^ get_castling_key(self.castling_rights);
+
+        for square in get_squares(self.bitboards.get_occupied()) {
+            if let Some(piece) = self.bitboards.get_piece(square) {
+                key ^= get_piece_key(piece, square);
+            }
+        }
+        key
+    }
+
+    /// Gets the Zobrist key of the en passant file. As in Polyglot, the file only
+    /// counts when a pawn of the side to move stands next to the pawn that has just
+    /// moved two squares, whether or not the capture is legal.
+    ///
+    /// # Returns
+    ///
+    /// The key of the en passant file, or 0 when no pawn can capture.
+    pub(crate) fn get_en_passant_key(&self) -> u64 {
+        match self.en_passant {
+            Some(en_passant)
+                if get_pawn_attacks(en_passant.get_square(), !self.color_turn)
+                    & self.bitboards.get_pieces(Kind::Pawn, self.color_turn)
+                    != 0 =>
+            {
+                get_en_passant_key(en_passant.get_col())
+            }
+            _ => 0,
+        }
+    }
+
+    /// Gets the Zobrist key of the en passant file when a pawn of the side to move
+    /// can legally capture en passant.
+    ///
+    /// # Returns
+    ///
+    /// The key of the en passant file, or 0 when no capture is legal.
+    pub(crate) fn get_legal_en_passant_key(&self) -> u64 {
+        let en_passant: Position = match self.en_passant {
+            Some(en_passant) if self.get_en_passant_key() != 0 => en_passant,
+            _ => return 0,
+        };
+        let target: u64 = 1 << en_passant.get_square();
+        let pawns: u64 = get_pawn_attacks(en_passant.get_square(), !self.color_turn)
+            & self.bitboards.get_pieces(Kind::Pawn, self.color_turn);
+
+        if get_squares(pawns).any(|square| Shift::get_legal_targets(self, square) & target != 0) {
+            get_en_passant_key(en_passant.get_col())
+        } else {
+            0
+        }
+    }
+
+    /// Checks if the king of the side to move is attacked.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the side to move is in check, `false` otherwise.
+    pub fn is_check(&self) -> bool {
+        match self.bitboards.get_king_square(self.color_turn) {
+            Some(square) => self.bitboards.is_attacked(square, !self.color_turn),
+            None => false,
+        }
+    }
+
+    /// Describes a move from one position to another, without checking that it is
+    /// legal.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The starting position of the piece.
+    /// * `to` - The ending position of the piece.
+    /// * `promotion` - The kind of piece a pawn is promoted to, if any.
+    ///
+    /// # Returns
+    ///
+    /// A `Move` with the moved and captured pieces filled in.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no piece at `from`.
+    pub fn get_move(&self, from: Position, to: Position, promotion: Option<Kind>) -> Move {
+        let piece: Piece = self
+            .bitboards
+            .get_piece(from.get_square())
+            .expect("a piece stands on the starting square");
+        let is_castling: bool =
+            piece.get_kind() == Kind::King && from.get_col().abs_diff(to.get_col()) == 2;
+        let is_en_passant: bool = piece.get_kind() == Kind::Pawn
+            && from.get_col() != to.get_col()
+            && self.bitboards.get_piece(to.get_square()).is_none();
+        let captured_position: Position = if is_en_passant {
+            Position::new(from.get_row(), to.get_col())
+        } else {
+            to
+        };
+
+        Move::new(
+            from,
+            to,
+            piece,
+            self.bitboards.get_piece(captured_position.get_square()),
+            promotion,
+            is_castling,
+            is_en_passant,
+        )
+    }
+
+    /// Returns every legal move of the side to move, with one move per promotion piece.
+    ///
+    /// # Returns
+    ///
+    /// A vector containing the legal moves.
+    pub fn legal_moves(&self) -> Vec<Move> {
+        let mut legal_moves: Vec<Move> = Vec::new();
+
+        for square in get_squares(self.bitboards.get_color(self.color_turn)) {
+            self.push_legal_moves_from(square, &mut legal_moves);
+        }
+        legal_moves
+    }
+
+    /// Returns the legal moves of the piece standing on a position, if it belongs
+    /// to the side to move.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position of the piece.
+    ///
+    /// # Returns
+    ///
+    /// A vector containing the legal moves of the piece.
+    pub fn legal_moves_from(&self, position: Position) -> Vec<Move> {
+        let mut legal_moves: Vec<Move> = Vec::new();
+        self.push_legal_moves_from(position.get_square(), &mut legal_moves);
+        legal_moves
+    }
+
+    /// Checks if the side to move has at least one legal move.
+    ///
+    /// # Returns
+    ///
+    /// `true` if any piece of the side to move can move, `false` otherwise.
+    pub fn has_legal_moves(&self) -> bool {
+        get_squares(self.bitboards.get_color(self.color_turn))
+            .any(|square| Shift::get_legal_targets(self, square) != 0)
+    }
+
+    /// Adds the legal moves of the piece standing on a square to a list.
+    ///
+    /// # Arguments
+    ///
+    /// * `square` - The index of the square of the piece.
+    /// * `legal_moves` - The list to extend.
+    fn push_legal_moves_from(&self, square: usize, legal_moves: &mut Vec<Move>) {
+        let from: Position = Position::from_square(square);
+
+        for target in get_squares(Shift::get_legal_targets(self, square)) {
+            let to: Position = Position::from_square(target);
+            let chess_move: Move = self.get_move(from, to, None);
+
+            if chess_move.get_piece().get_kind() == Kind::Pawn && !(8..56).contains(&target) {
+                for kind in [Kind::Queen, Kind::Rook, Kind::Bishop, Kind::Knight] {
+                    let mut promotion: Move = chess_move;
+                    promotion.set_promotion(Some(kind));
+                    legal_moves.push(promotion);
+                }
+            } else {
+                legal_moves.push(chess_move);
+            }
+        }
+    }
+
+    /// Plays a move and hands the turn to the opponent, without checking that the
+    /// move is legal.
+    ///
+    /// # Arguments
+    ///
+    /// * `chess_move` - The move to play.
+    ///
+    /// # Returns
+    ///
+    /// A new `Setup` in the position reached after the move.
+    pub fn play(&self, chess_move: Move) -> Setup {
+        let mut setup: Setup = *self;
+        let (from, to): (Position, Position) = (chess_move.get_from(), chess_move.get_to());
+        let piece: Piece = chess_move.get_piece();
+
+        setup.bitboards.set_piece(
+            chess_move.get_captured_position().get_square(),
+            Piece::none(),
+        );
+        setup.bitboards.set_piece(from.get_square(), Piece::none());
+        setup.bitboards.set_piece(
+            to.get_square(),
+            match chess_move.get_promotion() {
+                Some(kind) => Piece::new(kind, piece.get_color()),
+                None => piece,
+            },
+        );
+
+        if chess_move.is_castling() {
+            let (rook_from, rook_to): (usize, usize) = get_castling_rook_squares(from, to);
+            let rook: Piece = Piece::new(Kind::Rook, piece.get_color());
+            setup.bitboards.set_piece(rook_from, Piece::none());
+            setup.bitboards.set_piece(rook_to, rook);
+        }
+
+        setup.castling_rights.update(from);
+        setup.castling_rights.update(to);
+        setup.en_passant =
+            if piece.get_kind() == Kind::Pawn && from.get_row().abs_diff(to.get_row()) == 2 {
+                Some(Position::new(
+                    (from.get_row() + to.get_row()) / 2,
+                    from.get_col(),
+                ))
+            } else {
+                None
+            };
+        setup.color_turn = !self.color_turn;
+        setup
+    }
+}
+
+/// Gets the squares of the rook involved in a castling.
+///
+/// # Arguments
+///
+/// * `from` - The starting position of the king.
+/// * `to` - The ending position of the king.
+///
+/// # Returns
+///
+/// A tuple containing the square of the rook before and after the castling.
+pub(crate) fn get_castling_rook_squares(from: Position, to: Position) -> (usize, usize) {
+    let (rook_from, rook_to): (usize, usize) = if to.get_col() > from.get_col() {
+        (7, to.get_col() - 1)
+    } else {
+        (0, to.get_col() + 1)
+    };
+
+    (
+        Position::new(from.get_row(), rook_from).get_square(),
+        Position::new(from.get_row(), rook_to).get_square(),
+    )
+}
@@ -1,16 +1,33 @@
-use crate::{board::Board, cell::Cell, color::Color, kind::Kind, position::Position};
-
-/// Represents the possible moves and checks for a piece on the board.
+use crate::{
+    bitboard::{
+        get_bishop_attacks, get_king_attacks, get_knight_attacks, get_pawn_attacks,
+        get_queen_attacks, get_rook_attacks, get_squares, Bitboards,
+    },
+    board::Board,
+    cell::Cell,
+    color::Color,
+    kind::Kind,
+    piece::Piece,
+    position::Position,
+    setup::Setup,
+};
+
+/// Represents the possible moves for a piece on the board.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Shift {
     /// A vector of positions representing the possible moves for a piece.
     possible_moves: Vec<Position>,
-    /// A vector of positions representing the possible checks for a piece.
-    possible_checks: Vec<Position>,
+}
+
+impl Default for Shift {
+    fn default() -> Self {
+        Shift::new()
+    }
 }
 
 impl Shift {
-    /// Creates a new `Shift` instance with empty possible moves and checks.
+    /// Creates a new `Shift` instance with empty possible moves.
     ///
     /// # Returns
     ///
@@ -18,61 +35,33 @@ impl Shift {
     pub fn new() -> Shift {
         Shift {
             possible_moves: Vec::new(),
-            possible_checks: Vec::new(),
         }
     }
 
-    /// Sets the possible moves for a given piece on the board.
+    /// Sets the legal moves for a given piece on the board.
+    ///
+    /// Moves that would leave the king of the piece attacked are filtered out, so
+    /// pinned pieces stay on their line and a check must be answered.
     ///
     /// # Arguments
     ///
     /// * `board` - A reference to the game board.
     /// * `cell` - The cell containing the piece for which to set possible moves.
-    pub fn set_possible_moves(&mut self, board: Board, cell: Cell) -> () {
+    pub fn set_possible_moves(&mut self, board: &Board, cell: Cell) {
         self.clear();
 
         if let Some(piece) = cell.get_piece() {
-            let moves: Vec<Position> = match piece.get_kind() {
-                Kind::Pawn => self.get_pawn_possible_moves(&board, cell),
-                Kind::Knight => self.get_knight_possible_moves(&board, cell),
-                Kind::Bishop => self.get_bishop_possible_moves(&board, cell),
-                Kind::Rook => self.get_rook_possible_moves(&board, cell),
-                Kind::Queen => self.get_queen_possible_moves(&board, cell),
-                Kind::King => self.get_king_possible_moves(&board, cell),
-                Kind::None => Vec::new(),
-            };
-            self.possible_moves.extend(moves);
-        }
-    }
-
-    /// Sets the possible checks for a given color on the board.
-    ///
-    /// # Arguments
-    ///
-    /// * `board` - A reference to the game board.
-    /// * `color` - The color for which to set possible checks.
-    fn set_possible_checks(&mut self, board: Board, color: Color) -> () {
-        self.clear();
-        for row in 0..board.get_size() {
-            for col in 0..board.get_size() {
-                let current_position: Position = Position::new(row, col);
-                if let Some(piece) = board.get_cell(current_position).get_piece() {
-                    if piece.get_color() != color {
-                        let cell: Cell = board.get_cell(current_position).clone();
-                        let kind_enemy: Kind = cell.get_piece_kind();
-                        let moves: Vec<Position> = match kind_enemy {
-                            Kind::Pawn => self.get_pawn_possible_attacks(&board, cell.clone()),
-                            Kind::Knight => self.get_knight_possible_moves(&board, cell.clone()),
-                            Kind::Bishop => self.get_bishop_possible_moves(&board, cell.clone()),
-                            Kind::Rook => self.get_rook_possible_moves(&board, cell.clone()),
-                            Kind::Queen => self.get_queen_possible_moves(&board, cell.clone()),
-                            Kind::King => self.get_king_possible_moves(&board, cell.clone()),
-                            Kind::None => Vec::new(),
-                        };
-                        self.possible_checks.extend(moves);
-                    }
-                }
-            }
+            let (row, col) = cell.get_position();
+            let setup: Setup = Setup::new(
+                board.get_bitboards(),
+                piece.get_color(),
+                board.get_castling_rights(),
+                board.get_en_passant(),
+            );
+            let targets: u64 =
+                Shift::get_legal_targets(&setup, Position::new(row, col).get_square());
+            self.possible_moves
+                .extend(get_squares(targets).map(Position::from_square));
         }
     }
 
@@ -85,460 +74,214 @@ impl Shift {
         self.possible_moves.clone()
     }
 
-    /// Returns the possible checks.
-    ///
-    /// # Returns
-    ///
-    /// A vector of positions representing the possible checks.
-    fn get_possible_checks(&self) -> Vec<Position> {
-        self.possible_checks.clone()
-    }
-
-    /// Clears the possible moves and checks.
-    fn clear(&mut self) -> () {
+    /// Clears the possible moves.
+    fn clear(&mut self) {
         self.possible_moves.clear();
-        self.possible_checks.clear();
     }
 
-    /// Returns the possible attacks for a pawn.
+    /// Checks if a position is attacked by the opponent of a color.
     ///
     /// # Arguments
     ///
     /// * `board` - A reference to the game board.
-    /// * `cell` - The cell containing the pawn.
+    /// * `position` - The position to check.
+    /// * `color` - The color of the player whose square it is.
     ///
     /// # Returns
     ///
-    /// A vector of positions representing the possible attacks of the pawn.
-    fn get_pawn_possible_attacks(&mut self, board: &Board, cell: Cell) -> Vec<Position> {
-        let (row, col) = cell.get_position();
-        let color: Color = cell.get_piece_color();
-        let mut possible_moves: Vec<Position> = Vec::new();
-
-        match color {
-            Color::White => {
-                if col > 0 && self.is_piece_there(board, Position::new(row - 1, col), !color) {
-                    possible_moves.push(Position::new(row - 1, col - 1));
-                }
-                if col < board.get_size() - 1
-                    && self.is_piece_there(board, Position::new(row - 1, col + 1), !color)
-                {
-                    possible_moves.push(Position::new(row - 1, col + 1));
-                }
-            }
-            Color::Black => {
-                if col > 0 && self.is_piece_there(board, Position::new(row + 1, col - 1), !color) {
-                    possible_moves.push(Position::new(row + 1, col - 1));
-                }
-                if col < board.get_size() - 1
-                    && self.is_piece_there(board, Position::new(row + 1, col + 1), !color)
-                {
-                    possible_moves.push(Position::new(row + 1, col + 1))
-                }
-            }
-        }
-
-        possible_moves
+    /// `true` if a piece of the other color attacks the position, `false` otherwise.
+    pub fn is_in_check(&self, board: &Board, position: Position, color: Color) -> bool {
+        board
+            .get_bitboards()
+            .is_attacked(position.get_square(), !color)
     }
 
-    /// Returns the possible moves for a pawn.
+    /// Returns the squares the piece on a square can legally move to, when it
+    /// belongs to the side to move.
     ///
     /// # Arguments
     ///
-    /// * `board` - A reference to the game board.
-    /// * `cell` - The cell containing the pawn.
+    /// * `setup` - The position to generate the moves in.
+    /// * `square` - The index of the square of the piece.
     ///
     /// # Returns
     ///
-    /// A vector of positions representing the possible moves of the pawn.
-    fn get_pawn_possible_moves(&mut self, board: &Board, cell: Cell) -> Vec<Position> {
-        // TODO: add en passant
-        let (row, col) = cell.get_position();
-        let color: Color = cell.get_piece_color();
-        let mut possible_moves: Vec<Position> = Vec::new();
-
-        match color {
-            Color::White => {
-                // first move
-                if row == 6
-                    && col < board.get_size()
-                    && !self.is_piece_there(board, Position::new(row - 1, col), color)
-                    && !self.is_piece_there(board, Position::new(row - 2, col), color)
-                    && !self.is_piece_there(board, Position::new(row - 1, col), !color)
-                    && !self.is_piece_there(board, Position::new(row - 2, col), !color)
-                {
-                    possible_moves.push(Position::new(row - 2, col));
-                }
-                // forward
-                if row > 0
-                    && !self.is_piece_there(board, Position::new(row - 1, col), color)
-                    && !self.is_piece_there(board, Position::new(row - 1, col), !color)
-                {
-                    possible_moves.push(Position::new(row - 1, col));
-                }
-                // attack left
-                if row > 0
-                    && col > 0
-                    && !self.is_piece_there(board, Position::new(row - 1, col - 1), color)
-                    && board
-                        .get_cell(Position::new(row - 1, col - 1))
-                        .get_piece()
-                        .is_some()
-                    && board
-                        .get_cell(Position::new(row - 1, col - 1))
-                        .get_piece_color()
-                        == !color
-                {
-                    possible_moves.push(Position::new(row - 1, col - 1));
-                }
-                // attack right
-                if row > 0
-                    && col + 1 < board.get_size()
-                    && !self.is_piece_there(board, Position::new(row - 1, col + 1), color)
-                    && board
-                        .get_cell(Position::new(row - 1, col + 1))
-                        .get_piece()
-                        .is_some()
-                    && board
-                        .get_cell(Position::new(row - 1, col + 1))
-                        .get_piece_color()
-                        == !color
-                {
-                    possible_moves.push(Position::new(row - 1, col + 1));
-                }
-                possible_moves
+    /// The bitboard of the legal destinations, empty if the square holds no piece
+    /// of the side to move.
+    pub(crate) fn get_legal_targets(setup: &Setup, square: usize) -> u64 {
+        let bitboards: &Bitboards = setup.get_bitboards();
+        let color: Color = setup.get_color_turn();
+        let piece: Piece = match bitboards.get_piece(square) {
+            Some(piece) if piece.get_color() == color => piece,
+            _ => return 0,
+        };
+
+        let targets: u64 = match piece.get_kind() {
+            Kind::Pawn => Shift::get_pawn_targets(setup, square),
+            Kind::King => {
+                (get_king_attacks(square) & !bitboards.get_color(color))
+                    | Shift::get_castling_targets(setup, square)
             }
-            Color::Black => {
-                // first move
-                if row == 1
-                    && !self.is_piece_there(board, Position::new(row + 1, col), color)
-                    && !self.is_piece_there(board, Position::new(row + 1, col), !color)
-                    && !self.is_piece_there(board, Position::new(row + 2, col), color)
-                    && !self.is_piece_there(board, Position::new(row + 2, col), !color)
-                {
-                    possible_moves.push(Position::new(row + 2, col));
-                }
-                // forward
-                if row + 1 < board.get_size()
-                    && !self.is_piece_there(board, Position::new(row + 1, col), color)
-                    && !self.is_piece_there(board, Position::new(row + 1, col), !color)
-                {
-                    possible_moves.push(Position::new(row + 1, col));
-                }
-                // attack left
-                if row > 0
-                    && col > 0
-                    && !self.is_piece_there(board, Position::new(row + 1, col - 1), color)
-                    && board
-                        .get_cell(Position::new(row + 1, col - 1))
-                        .get_piece()
-                        .is_some()
-                    && board
-                        .get_cell(Position::new(row + 1, col - 1))
-                        .get_piece_color()
-                        == !color
-                {
-                    possible_moves.push(Position::new(row + 1, col - 1));
-                }
-                // attack right
-                if row > 0
-                    && col + 1 < board.get_size()
-                    && !self.is_piece_there(board, Position::new(row + 1, col + 1), color)
-                    && board
-                        .get_cell(Position::new(row + 1, col + 1))
-                        .get_piece()
-                        .is_some()
-                    && board
-                        .get_cell(Position::new(row + 1, col + 1))
-                        .get_piece_color()
-                        == !color
-                {
-                    possible_moves.push(Position::new(row + 1, col + 1));
-                }
-                possible_moves
+            kind => {
+                Shift::get_attacks(kind, square, bitboards.get_occupied())
+                    & !bitboards.get_color(color)
             }
-        }
+        };
+
+        get_squares(targets)
+            .filter(|target| !Shift::is_king_exposed(setup, piece, square, *target))
+            .fold(0, |legal_targets, target| legal_targets | 1 << target)
     }
 
-    /// Returns the possible moves for a knight.
+    /// Returns the squares attacked by a knight, bishop, rook or queen.
     ///
     /// # Arguments
     ///
-    /// * `board` - A reference to the game board.
-    /// * `cell` - The cell containing the knight.
+    /// * `kind` - The kind of the piece.
+    /// * `square` - The index of the square of the piece.
+    /// * `occupied` - The bitboard of every occupied square.
     ///
     /// # Returns
     ///
-    /// A vector of positions representing the possible moves of the knight.
-    fn get_knight_possible_moves(&mut self, board: &Board, cell: Cell) -> Vec<Position> {
-        let (row, col) = cell.get_position();
-        let color: Color = cell.get_piece_color();
-        let mut possible_moves: Vec<Position> = Vec::new();
-
-        let knight_moves: [(isize, isize); 8] = [
-            (-1, -2),
-            (1, -2),
-            (-1, 2),
-            (1, 2),
-            (-2, -1),
-            (2, -1),
-            (-2, 1),
-            (2, 1),
-        ];
-
-        for (r, c) in knight_moves.iter() {
-            let new_row: isize = row as isize + r;
-            let new_col: isize = col as isize + c;
-            if new_row >= 0
-                && new_row < board.get_size() as isize
-                && new_col >= 0
-                && new_col < board.get_size() as isize
-            {
-                let position: Position = Position::new(new_row as usize, new_col as usize);
-                if !self.is_piece_there(board, position, color) {
-                    possible_moves.push(position);
-                }
-            }
+    /// The bitboard of the attacked squares, empty for other kinds.
+    fn get_attacks(kind: Kind, square: usize, occupied: u64) -> u64 {
+        match kind {
+            Kind::Knight => get_knight_attacks(square),
+            Kind::Bishop => get_bishop_attacks(square, occupied),
+            Kind::Rook => get_rook_attacks(square, occupied),
+            Kind::Queen => get_queen_attacks(square, occupied),
+            _ => 0,
         }
-        possible_moves
     }
 
-    /// Returns the possible moves for a bishop.
+    /// Returns the pseudo-legal destinations of a pawn: one or two squares forward,
+    /// the diagonal captures and the en passant capture.
     ///
     /// # Arguments
     ///
-    /// * `board` - A reference to the game board.
-    /// * `cell` - The cell containing the bishop.
+    /// * `setup` - The position to generate the moves in.
+    /// * `square` - The index of the square of the pawn.
     ///
     /// # Returns
     ///
-    /// A vector of positions representing the possible moves of the bishop.
-    fn get_bishop_possible_moves(&mut self, board: &Board, cell: Cell) -> Vec<Position> {
-        let (row, col) = cell.get_position();
-        let color: Color = cell.get_piece_color();
-        let mut possible_moves: Vec<Position> = Vec::new();
-
-        // top-left
-        for i in 1..=row.min(col) {
-            let position: Position = Position::new(row - i, col - i);
-            if self.is_piece_there(board, position, color) {
-                break;
-            } else {
-                possible_moves.push(position);
-                if board.get_cell(position).get_piece().is_some() {
-                    break;
-                }
-            }
-        }
-
-        // top-right
-        for i in 1..=row.min(board.get_size() - 1 - col) {
-            let position: Position = Position::new(row - i, col + i);
-            if self.is_piece_there(board, position, color) {
-                break;
-            } else {
-                possible_moves.push(position);
-                if board.get_cell(position).get_piece().is_some() {
-                    break;
-                }
+    /// The bitboard of the destinations.
+    fn get_pawn_targets(setup: &Setup, square: usize) -> u64 {
+        let bitboards: &Bitboards = setup.get_bitboards();
+        let color: Color = setup.get_color_turn();
+        let empty: u64 = !bitboards.get_occupied();
+        let (step, start_rank, en_passant_rank): (isize, usize, usize) = match color {
+            Color::White => (8, 1, 5),
+            Color::Black => (-8, 6, 2),
+        };
+
+        let mut targets: u64 = get_pawn_attacks(square, color) & bitboards.get_color(!color);
+
+        let forward: isize = square as isize + step;
+        if (0..64).contains(&forward) && empty & (1 << forward) != 0 {
+            targets |= 1 << forward;
+
+            let double: isize = forward + step;
+            if square / 8 == start_rank && empty & (1 << double) != 0 {
+                targets |= 1 << double;
             }
         }
 
-        // bottom-left
-        for i in 1..=(board.get_size() - 1 - row).min(col) {
-            let pos: Position = Position::new(row + i, col - i);
-            if self.is_piece_there(board, pos, color) {
-                break;
-            } else {
-                possible_moves.push(pos);
-                if board.get_cell(pos).get_piece().is_some() {
-                    break;
-                }
+        if let Some(en_passant) = setup.get_en_passant() {
+            let en_passant: usize = en_passant.get_square();
+            if en_passant / 8 == en_passant_rank {
+                targets |= get_pawn_attacks(square, color) & (1 << en_passant);
             }
         }
 
-        // bottom-right
-        for i in 1..=(board.get_size() - 1 - row).min(board.get_size() - 1 - col) {
-            let pos: Position = Position::new(row + i, col + i);
-            if self.is_piece_there(board, pos, color) {
-                break;
-            } else {
-                possible_moves.push(pos);
-                if board.get_cell(pos).get_piece().is_some() {
-                    break;
-                }
-            }
-        }
-        possible_moves
+        targets
     }
 
-    /// Returns the possible moves for a rook.
+    /// Returns the destinations of the king for each castling available.
+    ///
+    /// Castling requires the matching right, the rook on its corner, empty squares
+    /// between the king and the rook, and a king that is neither in check nor
+    /// passing through or landing on an attacked square.
     ///
     /// # Arguments
     ///
-    /// * `board` - A reference to the game board.
-    /// * `cell` - The cell containing the rook.
+    /// * `setup` - The position to generate the moves in.
+    /// * `square` - The index of the square of the king.
     ///
     /// # Returns
     ///
-    /// A vector of positions representing the possible moves of the rook.
-    fn get_rook_possible_moves(&mut self, board: &Board, cell: Cell) -> Vec<Position> {
-        let (row, col) = cell.get_position();
-        let color: Color = cell.get_piece_color();
-        let mut possible_moves: Vec<Position> = Vec::new();
-
-        // up
-        for r in (0..row).rev() {
-            let position: Position = Position::new(r, col);
-            if self.is_piece_there(board, position, color) {
-                break;
-            } else {
-                possible_moves.push(position);
-                if board.get_cell(position).get_piece().is_some() {
-                    break;
-                }
-            }
+    /// The bitboard of the destinations of the king.
+    fn get_castling_targets(setup: &Setup, square: usize) -> u64 {
+        let bitboards: &Bitboards = setup.get_bitboards();
+        let color: Color = setup.get_color_turn();
+        let home: usize = match color {
+            Color::White => 4,
+            Color::Black => 60,
+        };
+
+        if square != home || bitboards.is_attacked(home, !color) {
+            return 0;
         }
 
-        // down
-        for r in row + 1..board.get_size() {
-            let position: Position = Position::new(r, col);
-            if self.is_piece_there(board, position, color) {
-                break;
-            } else {
-                possible_moves.push(position);
-                if board.get_cell(position).get_piece().is_some() {
-                    break;
-                }
-            }
+        let rooks: u64 = bitboards.get_pieces(Kind::Rook, color);
+        let is_empty = |squares: &[usize]| -> bool {
+            squares
+                .iter()
+                .all(|square| bitboards.get_occupied() & (1 << square) == 0)
+        };
+        let is_safe = |squares: &[usize]| -> bool {
+            squares
+                .iter()
+                .all(|square| !bitboards.is_attacked(*square, !color))
+        };
+        let mut targets: u64 = 0;
+
+        // king side
+        if setup.get_castling_rights().get_king_side(color)
+            && rooks & (1 << (home + 3)) != 0
+            && is_empty(&[home + 1, home + 2])
+            && is_safe(&[home + 1, home + 2])
+        {
+            targets |= 1 << (home + 2);
         }
 
-        // left
-        for c in (0..col).rev() {
-            let position: Position = Position::new(row, c);
-            if self.is_piece_there(board, position, color) {
-                break;
-            } else {
-                possible_moves.push(position);
-                if board.get_cell(position).get_piece().is_some() {
-                    break;
-                }
-            }
+        // queen side
+        if setup.get_castling_rights().get_queen_side(color)
+            && rooks & (1 << (home - 4)) != 0
+            && is_empty(&[home - 1, home - 2, home - 3])
+            && is_safe(&[home - 1, home - 2])
+        {
+            targets |= 1 << (home - 2);
         }
 
-        // right
-        for c in col + 1..board.get_size() {
-            let position: Position = Position::new(row, c);
-            if self.is_piece_there(board, position, color) {
-                break;
-            } else {
-                possible_moves.push(position);
-                if board.get_cell(position).get_piece().is_some() {
-                    break;
-                }
-            }
-        }
-
-        possible_moves
+        targets
     }
 
-    /// Returns the possible moves for a queen.
+    /// Checks if a move would leave the king of the moving side attacked.
     ///
     /// # Arguments
     ///
-    /// * `board` - A reference to the game board.
-    /// * `cell` - The cell containing the queen.
+    /// * `setup` - The position the move is played in.
+    /// * `piece` - The piece that moves.
+    /// * `from` - The index of the starting square.
+    /// * `to` - The index of the ending square.
     ///
     /// # Returns
     ///
-    /// A vector of positions representing the possible moves of the queen.
-    fn get_queen_possible_moves(&mut self, board: &Board, cell: Cell) -> Vec<Position> {
-        let mut possible_moves: Vec<Position> = Vec::new();
-        possible_moves.extend(self.get_bishop_possible_moves(board, cell));
-        possible_moves.extend(self.get_rook_possible_moves(board, cell));
-        possible_moves
-    }
+    /// `true` if the king is attacked once the move is played, `false` otherwise.
+    fn is_king_exposed(setup: &Setup, piece: Piece, from: usize, to: usize) -> bool {
+        let mut bitboards: Bitboards = *setup.get_bitboards();
+        let color: Color = piece.get_color();
 
-    /// Returns the possible moves for a king.
-    ///
-    /// # Arguments
-    ///
-    /// * `board` - A reference to the game board.
-    /// * `cell` - The cell containing the king.
-    ///
-    /// # Returns
-    ///
-    /// A vector of positions representing the possible moves of the king.
-    fn get_king_possible_moves(&mut self, board: &Board, cell: Cell) -> Vec<Position> {
-        let (row, col) = cell.get_position();
-        let color: Color = cell.get_piece_color();
-        let mut possible_moves: Vec<Position> = Vec::new();
-
-        let king_moves: [(isize, isize); 8] = [
-            (-1, -1),
-            (-1, 0),
-            (-1, 1),
-            (0, -1),
-            (0, 1),
-            (1, -1),
-            (1, 0),
-            (1, 1),
-        ];
-
-        for (r, c) in king_moves.iter() {
-            let new_row: isize = row as isize + r;
-            let new_col: isize = col as isize + c;
-
-            if new_row >= 0
-                && new_row < board.get_size() as isize
-                && new_col >= 0
-                && new_col < board.get_size() as isize
-                && !self.is_piece_there(
-                    board,
-                    Position::new(new_row as usize, new_col as usize),
-                    color,
-                )
-                && !self.clone().is_in_check(
-                    board,
-                    Position::new(new_row as usize, new_col as usize),
-                    color,
-                )
-            {
-                possible_moves.push(Position::new(new_row as usize, new_col as usize));
-            }
+        if piece.get_kind() == Kind::Pawn && from % 8 != to % 8 && bitboards.get_piece(to).is_none()
+        {
+            bitboards.set_piece(from / 8 * 8 + to % 8, Piece::none());
         }
-        possible_moves
-    }
-
-    /// Checks if a given position is in check for a specified color.
-    ///
-    /// # Arguments
-    ///
-    /// * `board` - A reference to the game board.
-    /// * `position` - The position to check.
-    /// * `color` - The color of the player to check for.
-    ///
-    /// # Returns
-    ///
-    /// `true` if the position is in check for the specified color, `false` otherwise.
-    pub fn is_in_check(mut self, board: &Board, position: Position, color: Color) -> bool {
-        self.set_possible_checks(board.clone(), color);
-        self.get_possible_checks().contains(&position)
-    }
+        bitboards.set_piece(from, Piece::none());
+        bitboards.set_piece(to, piece);
 
-    /// Checks if there is a piece at the given position and if it matches the given color.
-    ///
-    /// # Arguments
-    ///
-    /// * `board` - A reference to the game board.
-    /// * `position` - The position to check.
-    /// * `color` - The color to match.
-    ///
-    /// # Returns
-    ///
-    /// `true` if there is a piece at the given position and it matches the given color, `false` otherwise.
-    fn is_piece_there(&mut self, board: &Board, position: Position, color: Color) -> bool {
-        board.get_cell(position).get_piece().is_some()
-            && board.get_cell(position).get_piece_color() == color
+        match bitboards.get_king_square(color) {
+            Some(king) => bitboards.is_attacked(king, !color),
+            None => false,
+        }
     }
 }
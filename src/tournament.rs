@@ -0,0 +1,1170 @@
+use std::{
+    fmt,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    board::Board,
+    clock::TimeControl,
+    color::Color,
+    engine::{Engine, Score, SearchInfo, SearchLimits, DEFAULT_HASH_SIZE},
+    moves::Move,
+    outcome::Outcome,
+    uci::{BestMove, GoParams, ScoreBound, UciEngine, UciError, UciInfo},
+};
+
+/// The name of the built-in engine in player specifications and game headers.
+const BUILTIN_NAME: &str = "builtin";
+
+/// The time given to a move when the game has no clocks.
+const UNTIMED_MOVETIME: Duration = Duration::from_secs(1);
+
+/// The time an external engine is given past its clock before it is stopped.
+const UCI_MARGIN: Duration = Duration::from_secs(1);
+
+/// The centipawn value given to a mate when it is compared with the adjudication
+/// thresholds.
+const MATE_VALUE: i32 = 100_000;
+
+/// The quantile of the normal distribution leaving 2.5% on each side, which gives
+/// the 95% confidence interval of the Elo difference.
+const CONFIDENCE_QUANTILE: f64 = 1.959964;
+
+/// Represents the reasons a match cannot be set up or played.
+///
+/// # Variants
+///
+/// - `InvalidPlayer`: A player specification cannot be read.
+/// - `NoOpening`: The list of openings is empty.
+/// - `Engine`: An engine process failed, with the name of the engine.
+#[derive(Debug)]
+pub enum MatchError {
+    InvalidPlayer(String),
+    NoOpening,
+    Engine(String, UciError),
+}
+
+impl fmt::Display for MatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatchError::InvalidPlayer(spec) => write!(f, "invalid player '{}'", spec),
+            MatchError::NoOpening => write!(f, "no opening to play"),
+            MatchError::Engine(name, error) => write!(f, "{}: {}", name, error),
+        }
+    }
+}
+
+impl std::error::Error for MatchError {}
+
+/// Represents the answer of a player asked to move.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlayerMove {
+    /// The move in long algebraic notation, or `None` if the player found none.
+    uci: Option<String>,
+    /// The score of the position for the player, if known.
+    score: Option<Score>,
+}
+
+impl PlayerMove {
+    /// Creates the answer of a player.
+    ///
+    /// # Arguments
+    ///
+    /// * `uci` - The move in long algebraic notation, if any.
+    /// * `score` - The score of the position for the player, if known.
+    ///
+    /// # Returns
+    ///
+    /// A new `PlayerMove` instance.
+    pub fn new(uci: Option<String>, score: Option<Score>) -> PlayerMove {
+        PlayerMove { uci, score }
+    }
+
+    /// Gets the move in long algebraic notation.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<&str>` containing the move, if any.
+    pub fn get_uci(&self) -> Option<&str> {
+        self.uci.as_deref()
+    }
+
+    /// Gets the score of the position for the player.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Score>` containing the score, if known.
+    pub fn get_score(&self) -> Option<Score> {
+        self.score
+    }
+}
+
+/// Represents a player of a match, which chooses the moves of one side.
+pub trait Player {
+    /// Gets the name of the player, written in the headers of the games.
+    ///
+    /// # Returns
+    ///
+    /// A string slice containing the name.
+    fn get_name(&self) -> &str;
+
+    /// Prepares the player for a new game.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is a `MatchError` if the player cannot be reached.
+    fn new_game(&mut self) -> Result<(), MatchError>;
+
+    /// Chooses a move for the side to move, in the time left on its clock.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - The game being played, with its clocks.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the answer of the player, or a `MatchError` if the
+    /// player cannot be reached.
+    fn play(&mut self, board: &Board) -> Result<PlayerMove, MatchError>;
+}
+
+/// Represents the built-in engine playing with its own limits.
+struct BuiltinPlayer {
+    /// The name of the player.
+    name: String,
+    /// The engine searching the moves.
+    engine: Engine,
+    /// The limits added to the time given by the clock.
+    limits: SearchLimits,
+}
+
+impl Player for BuiltinPlayer {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn new_game(&mut self) -> Result<(), MatchError> {
+        self.engine.clear();
+        Ok(())
+    }
+
+    fn play(&mut self, board: &Board) -> Result<PlayerMove, MatchError> {
+        let color: Color = board.get_color_turn();
+        let limits: SearchLimits = match (board.get_clock(), board.get_remaining_time(color)) {
+            (Some(clock), Some(remaining)) => self.limits.with_clock(
+                remaining,
+                clock.get_time_control().get_increment().get_duration(),
+                clock.get_moves_to_go(color),
+            ),
+            _ => self.limits.with_movetime(UNTIMED_MOVETIME),
+        };
+        let result: Option<SearchInfo> = self.engine.search(board, limits, |_| {});
+
+        Ok(PlayerMove::new(
+            result
+                .as_ref()
+                .and_then(SearchInfo::get_best_move)
+                .map(|chess_move: Move| chess_move.get_uci()),
+            result.map(|info| info.get_score()),
+        ))
+    }
+}
+
+/// Represents an external engine spoken to through UCI.
+struct UciPlayer {
+    /// The name the engine gave during the handshake.
+    name: String,
+    /// The engine process.
+    engine: UciEngine,
+}
+
+impl UciPlayer {
+    /// Wraps an error of the engine with its name.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The error of the engine.
+    ///
+    /// # Returns
+    ///
+    /// The `MatchError` naming the engine.
+    fn get_error(&self, error: UciError) -> MatchError {
+        MatchError::Engine(self.name.clone(), error)
+    }
+}
+
+impl Player for UciPlayer {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn new_game(&mut self) -> Result<(), MatchError> {
+        self.engine
+            .new_game()
+            .map_err(|error| self.get_error(error))
+    }
+
+    fn play(&mut self, board: &Board) -> Result<PlayerMove, MatchError> {
+        let color: Color = board.get_color_turn();
+        let params: GoParams = match board.get_clock() {
+            Some(clock) => GoParams::new().with_clock(
+                board.get_remaining_time(Color::White).unwrap_or_default(),
+                board.get_remaining_time(Color::Black).unwrap_or_default(),
+                clock.get_time_control().get_increment().get_duration(),
+                clock.get_moves_to_go(color),
+            ),
+            None => GoParams::new().with_movetime(UNTIMED_MOVETIME),
+        };
+        let timeout: Duration =
+            board.get_remaining_time(color).unwrap_or(UNTIMED_MOVETIME) + UCI_MARGIN;
+        let mut score: Option<Score> = None;
+
+        self.engine
+            .set_position(board)
+            .map_err(|error| self.get_error(error))?;
+        // An engine still silent after its clock and `stop` has lost on time, which
+        // the flag of the game records once no move is returned.
+        let best_move: BestMove = match self.engine.go(&params, timeout, |info: &UciInfo| {
+            if info.get_bound() == Some(ScoreBound::Exact) {
+                score = info.get_score().or(score);
+            }
+        }) {
+            Ok(best_move) => best_move,
+            Err(UciError::Timeout(_)) => return Ok(PlayerMove::new(None, None)),
+            Err(error) => return Err(self.get_error(error)),
+        };
+
+        Ok(PlayerMove::new(
+            best_move.get_best_move().map(str::to_string),
+            score,
+        ))
+    }
+}
+
+/// Represents the way a player of a match is created.
+///
+/// # Variants
+///
+/// - `Builtin`: The built-in engine, with a name, limits added to the clock and
+///   the size of its transposition table in megabytes.
+/// - `Uci`: An external engine, with the path of its executable.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PlayerConfig {
+    Builtin {
+        name: String,
+        limits: SearchLimits,
+        hash_size: usize,
+    },
+    Uci {
+        path: String,
+    },
+}
+
+impl PlayerConfig {
+    /// Reads a player specification: `builtin`, optionally followed by a colon and
+    /// comma separated settings among `depth`, `nodes`, `hash` and `name`, such as
+    /// `builtin:depth=4,hash=32`, or else the path of a UCI engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `spec` - The specification of the player.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the configuration, or a `MatchError` if a setting of the
+    /// built-in engine is invalid.
+    pub fn parse(spec: &str) -> Result<PlayerConfig, MatchError> {
+        let settings: &str = match spec.strip_prefix(BUILTIN_NAME) {
+            Some("") => "",
+            Some(settings) => match settings.strip_prefix(':') {
+                Some(settings) => settings,
+                None => {
+                    return Ok(PlayerConfig::Uci {
+                        path: spec.to_string(),
+                    })
+                }
+            },
+            None => {
+                return Ok(PlayerConfig::Uci {
+                    path: spec.to_string(),
+                })
+            }
+        };
+
+        let error = || MatchError::InvalidPlayer(spec.to_string());
+        let mut name: String = if settings.is_empty() {
+            BUILTIN_NAME.to_string()
+        } else {
+            format!("{} {}", BUILTIN_NAME, settings)
+        };
+        let mut limits: SearchLimits = SearchLimits::new();
+        let mut hash_size: usize = DEFAULT_HASH_SIZE;
+
+        for setting in settings.split(',').filter(|setting| !setting.is_empty()) {
+            let (key, value): (&str, &str) = setting.split_once('=').ok_or_else(error)?;
+            match key {
+                "depth" => limits = limits.with_depth(value.parse().map_err(|_| error())?),
+                "nodes" => limits = limits.with_nodes(value.parse().map_err(|_| error())?),
+                "hash" => hash_size = value.parse().map_err(|_| error())?,
+                "name" => name = value.to_string(),
+                _ => return Err(error()),
+            }
+        }
+
+        Ok(PlayerConfig::Builtin {
+            name,
+            limits,
+            hash_size,
+        })
+    }
+
+    /// Creates the player, starting the engine process of a UCI engine.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the player, or a `MatchError` if the engine cannot be
+    /// started.
+    pub fn create(&self) -> Result<Box<dyn Player>, MatchError> {
+        match self {
+            PlayerConfig::Builtin {
+                name,
+                limits,
+                hash_size,
+            } => {
+                let mut engine: Engine = Engine::new();
+                engine.set_hash_size(*hash_size);
+                Ok(Box::new(BuiltinPlayer {
+                    name: name.clone(),
+                    engine,
+                    limits: *limits,
+                }))
+            }
+            PlayerConfig::Uci { path } => {
+                let engine: UciEngine = UciEngine::spawn(path, &[])
+                    .map_err(|error| MatchError::Engine(path.clone(), error))?;
+                Ok(Box::new(UciPlayer {
+                    name: engine.get_name().to_string(),
+                    engine,
+                }))
+            }
+        }
+    }
+}
+
+/// Represents the rules ending a game before the end given by the rules of chess.
+/// Scores are read from the point of view of the player who gave them.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Adjudication {
+    /// The score and the number of consecutive moves at or below its opposite
+    /// after which a player loses.
+    resign: Option<(i32, u32)>,
+    /// The move number from which draws are adjudicated, the score and the number
+    /// of consecutive moves of each player within it after which the game is drawn.
+    draw: Option<(u32, i32, u32)>,
+    /// The move number after which the game is drawn.
+    max_moves: Option<u32>,
+}
+
+impl Adjudication {
+    /// Creates rules that never end a game early.
+    ///
+    /// # Returns
+    ///
+    /// A new `Adjudication` instance.
+    pub fn new() -> Adjudication {
+        Adjudication::default()
+    }
+
+    /// Makes a player lose once its score stays at or below a threshold.
+    ///
+    /// # Arguments
+    ///
+    /// * `score` - The size of the disadvantage in centipawns.
+    /// * `moves` - The number of consecutive moves of the player with that score.
+    ///
+    /// # Returns
+    ///
+    /// The rules with the resignation set.
+    pub fn with_resign(mut self, score: i32, moves: u32) -> Adjudication {
+        self.resign = Some((score.abs(), moves.max(1)));
+        self
+    }
+
+    /// Draws the game once both scores stay close to zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `move_number` - The first move number at which a draw can be adjudicated.
+    /// * `score` - The largest score in centipawns, either way, counting as even.
+    /// * `moves` - The number of consecutive moves of each player with such a score.
+    ///
+    /// # Returns
+    ///
+    /// The rules with the draw set.
+    pub fn with_draw(mut self, move_number: u32, score: i32, moves: u32) -> Adjudication {
+        self.draw = Some((move_number, score.abs(), moves.max(1)));
+        self
+    }
+
+    /// Draws the game once it reaches a move number.
+    ///
+    /// # Arguments
+    ///
+    /// * `moves` - The number of moves of each player after which the game is drawn.
+    ///
+    /// # Returns
+    ///
+    /// The rules with the limit set.
+    pub fn with_max_moves(mut self, moves: u32) -> Adjudication {
+        self.max_moves = Some(moves);
+        self
+    }
+}
+
+/// Represents a game played during a match.
+#[derive(Clone)]
+pub struct MatchGame {
+    /// The game, with its PGN tags set.
+    board: Board,
+    /// The winner, or `None` for a draw.
+    winner: Option<Color>,
+    /// The reason the game ended, such as `checkmate` or `time forfeit`.
+    reason: String,
+}
+
+impl MatchGame {
+    /// Gets the game, whose PGN tags name the players and the result.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `Board` of the game.
+    pub fn get_board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Gets the winner of the game.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Color>` containing the winner, or `None` for a draw.
+    pub fn get_winner(&self) -> Option<Color> {
+        self.winner
+    }
+
+    /// Gets the reason the game ended.
+    ///
+    /// # Returns
+    ///
+    /// A string slice such as `checkmate` or `adjudication`.
+    pub fn get_reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// Gets the result of the game as in PGN.
+    ///
+    /// # Returns
+    ///
+    /// A string slice containing `1-0`, `0-1` or `1/2-1/2`.
+    pub fn get_result(&self) -> &'static str {
+        match self.winner {
+            Some(Color::White) => "1-0",
+            Some(Color::Black) => "0-1",
+            None => "1/2-1/2",
+        }
+    }
+}
+
+/// Represents the ends of a game decided by the match rather than the rules.
+///
+/// # Variants
+///
+/// - `Adjudication`: The game is given to a player, or drawn, from the scores or the
+///   length of the game.
+/// - `RulesInfraction`: A player has played an illegal move or no move.
+enum Verdict {
+    Adjudication(Option<Color>),
+    RulesInfraction(Color),
+}
+
+/// Plays one game between two players.
+///
+/// # Arguments
+///
+/// * `white` - The player of the white pieces.
+/// * `black` - The player of the black pieces.
+/// * `opening` - The game to continue from.
+/// * `time_control` - The time control of the game.
+/// * `adjudication` - The rules ending the game early.
+///
+/// # Returns
+///
+/// A `Result` containing the finished game, or a `MatchError` if a player cannot be
+/// reached.
+pub fn play_game(
+    white: &mut dyn Player,
+    black: &mut dyn Player,
+    opening: &Board,
+    time_control: &TimeControl,
+    adjudication: Adjudication,
+) -> Result<MatchGame, MatchError> {
+    let mut board: Board = opening.clone();
+    let mut resign_counts: [u32; 2] = [0, 0];
+    let mut draw_count: u32 = 0;
+
+    white.new_game()?;
+    black.new_game()?;
+    board.set_pgn_tag("White", white.get_name());
+    board.set_pgn_tag("Black", black.get_name());
+    board.set_time_control(Some(time_control.clone()));
+
+    let verdict: Option<Verdict> = loop {
+        board.claim_draw();
+        if board.is_end() {
+            break None;
+        }
+        if adjudication
+            .max_moves
+            .is_some_and(|max_moves| board.get_fullmove_number() > max_moves)
+        {
+            break Some(Verdict::Adjudication(None));
+        }
+
+        let color: Color = board.get_color_turn();
+        let player: &mut dyn Player = match color {
+            Color::White => &mut *white,
+            Color::Black => &mut *black,
+        };
+        let answer: PlayerMove = player.play(&board)?;
+        if board.check_flag() {
+            break None;
+        }
+
+        let chess_move: Option<Move> = answer.get_uci().and_then(|uci| board.parse_uci(uci));
+        match chess_move.map(|chess_move| board.make_move(chess_move)) {
+            Some(Ok(())) => {}
+            _ => break Some(Verdict::RulesInfraction(color)),
+        }
+
+        let score: Option<i32> = answer.get_score().map(get_centipawns);
+        let index: usize = match color {
+            Color::White => 0,
+            Color::Black => 1,
+        };
+        if let Some((threshold, moves)) = adjudication.resign {
+            resign_counts[index] = match score {
+                Some(score) if score <= -threshold => resign_counts[index] + 1,
+                _ => 0,
+            };
+            if resign_counts[index] >= moves {
+                break Some(Verdict::Adjudication(Some(!color)));
+            }
+        }
+        if let Some((move_number, threshold, moves)) = adjudication.draw {
+            draw_count = match score {
+                Some(score)
+                    if score.abs() <= threshold && board.get_fullmove_number() >= move_number =>
+                {
+                    draw_count + 1
+                }
+                _ => 0,
+            };
+            if draw_count >= 2 * moves {
+                break Some(Verdict::Adjudication(None));
+            }
+        }
+    };
+
+    let (winner, reason, termination): (Option<Color>, String, &str) = match verdict {
+        None => {
+            let outcome: Outcome = board.get_outcome().expect("the game has ended");
+            (
+                outcome.get_winner(),
+                outcome.get_description(),
+                outcome.get_termination().get_pgn_tag(),
+            )
+        }
+        Some(Verdict::Adjudication(winner)) => (winner, "adjudication".to_string(), "adjudication"),
+        Some(Verdict::RulesInfraction(color)) => {
+            (Some(!color), "illegal move".to_string(), "rules infraction")
+        }
+    };
+    let mut game: MatchGame = MatchGame {
+        board,
+        winner,
+        reason,
+    };
+    let result: &str = game.get_result();
+    game.board.set_pgn_tag("Result", result);
+    game.board.set_pgn_tag("Termination", termination);
+    Ok(game)
+}
+
+/// Converts a score to centipawns, giving mates a value beyond any evaluation.
+///
+/// # Arguments
+///
+/// * `score` - The score to convert.
+///
+/// # Returns
+///
+/// The score in centipawns.
+fn get_centipawns(score: Score) -> i32 {
+    match score {
+        Score::Centipawns(centipawns) => centipawns,
+        Score::Mate(moves) if moves > 0 => MATE_VALUE - moves,
+        Score::Mate(moves) => -MATE_VALUE - moves,
+    }
+}
+
+/// Converts an expected score to an Elo difference with the logistic model.
+///
+/// # Arguments
+///
+/// * `score` - The expected score, strictly between 0 and 1.
+///
+/// # Returns
+///
+/// The Elo difference giving that score.
+fn get_elo(score: f64) -> f64 {
+    400.0 * (score / (1.0 - score)).log10()
+}
+
+/// Converts an Elo difference to an expected score with the logistic model.
+///
+/// # Arguments
+///
+/// * `elo` - The Elo difference.
+///
+/// # Returns
+///
+/// The expected score, between 0 and 1.
+fn get_expected_score(elo: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf(-elo / 400.0))
+}
+
+/// Represents the results of a match from the point of view of its first player.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MatchStats {
+    /// The number of games won.
+    wins: u32,
+    /// The number of games drawn.
+    draws: u32,
+    /// The number of games lost.
+    losses: u32,
+}
+
+impl MatchStats {
+    /// Creates results from the number of games of each kind.
+    ///
+    /// # Arguments
+    ///
+    /// * `wins` - The number of games won.
+    /// * `draws` - The number of games drawn.
+    /// * `losses` - The number of games lost.
+    ///
+    /// # Returns
+    ///
+    /// A new `MatchStats` instance.
+    pub fn new(wins: u32, draws: u32, losses: u32) -> MatchStats {
+        MatchStats {
+            wins,
+            draws,
+            losses,
+        }
+    }
+
+    /// Counts a finished game.
+    ///
+    /// # Arguments
+    ///
+    /// * `winner` - The winner of the game, or `None` for a draw.
+    /// * `color` - The color the first player had in the game.
+    pub fn add_game(&mut self, winner: Option<Color>, color: Color) {
+        match winner {
+            Some(winner) if winner == color => self.wins += 1,
+            Some(_) => self.losses += 1,
+            None => self.draws += 1,
+        }
+    }
+
+    /// Gets the number of games won.
+    ///
+    /// # Returns
+    ///
+    /// The number of wins.
+    pub fn get_wins(&self) -> u32 {
+        self.wins
+    }
+
+    /// Gets the number of games drawn.
+    ///
+    /// # Returns
+    ///
+    /// The number of draws.
+    pub fn get_draws(&self) -> u32 {
+        self.draws
+    }
+
+    /// Gets the number of games lost.
+    ///
+    /// # Returns
+    ///
+    /// The number of losses.
+    pub fn get_losses(&self) -> u32 {
+        self.losses
+    }
+
+    /// Gets the number of games played.
+    ///
+    /// # Returns
+    ///
+    /// The number of games.
+    pub fn get_games(&self) -> u32 {
+        self.wins + self.draws + self.losses
+    }
+
+    /// Gets the average score, counting one point for a win and half for a draw.
+    ///
+    /// # Returns
+    ///
+    /// The score between 0 and 1, or 0.5 before the first game.
+    pub fn get_score(&self) -> f64 {
+        if self.get_games() == 0 {
+            return 0.5;
+        }
+        (self.wins as f64 + self.draws as f64 / 2.0) / self.get_games() as f64
+    }
+
+    /// Gets the variance of the score of one game.
+    ///
+    /// # Returns
+    ///
+    /// The variance, 0 before the first game.
+    fn get_variance(&self) -> f64 {
+        if self.get_games() == 0 {
+            return 0.0;
+        }
+        let score: f64 = self.get_score();
+        (self.wins as f64 * (1.0 - score).powi(2)
+            + self.draws as f64 * (0.5 - score).powi(2)
+            + self.losses as f64 * score.powi(2))
+            / self.get_games() as f64
+    }
+
+    /// Gets the Elo difference between the first and the second player.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<f64>` containing the difference, or `None` if no game has been
+    /// played or one player has scored every point.
+    pub fn get_elo_difference(&self) -> Option<f64> {
+        let score: f64 = self.get_score();
+        if self.get_games() == 0 || score <= 0.0 || score >= 1.0 {
+            return None;
+        }
+        Some(get_elo(score))
+    }
+
+    /// Gets the margin of the Elo difference at a 95% confidence level.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<f64>` containing the margin, or `None` if the interval reaches a
+    /// score of 0 or 1, where the Elo difference is infinite.
+    pub fn get_elo_error(&self) -> Option<f64> {
+        self.get_elo_difference()?;
+        let score: f64 = self.get_score();
+        let deviation: f64 =
+            CONFIDENCE_QUANTILE * (self.get_variance() / self.get_games() as f64).sqrt();
+        if score - deviation <= 0.0 || score + deviation >= 1.0 {
+            return None;
+        }
+        Some((get_elo(score + deviation) - get_elo(score - deviation)) / 2.0)
+    }
+
+    /// Gets the log-likelihood ratio of two hypotheses on the Elo difference, using
+    /// the normal approximation of the score.
+    ///
+    /// # Arguments
+    ///
+    /// * `elo0` - The Elo difference of the null hypothesis.
+    /// * `elo1` - The Elo difference of the alternative hypothesis.
+    ///
+    /// # Returns
+    ///
+    /// The ratio, positive when the results favour the alternative hypothesis, and 0
+    /// while the scores do not vary.
+    pub fn get_llr(&self, elo0: f64, elo1: f64) -> f64 {
+        let variance: f64 = self.get_variance();
+        if variance == 0.0 {
+            return 0.0;
+        }
+        let score0: f64 = get_expected_score(elo0);
+        let score1: f64 = get_expected_score(elo1);
+        self.get_games() as f64 * (score1 - score0) * (2.0 * self.get_score() - score0 - score1)
+            / (2.0 * variance)
+    }
+}
+
+/// Represents the decision of a sequential probability ratio test.
+///
+/// # Variants
+///
+/// - `Continue`: More games are needed.
+/// - `AcceptH0`: The Elo difference is at most the one of the null hypothesis.
+/// - `AcceptH1`: The Elo difference is at least the one of the alternative hypothesis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SprtResult {
+    Continue,
+    AcceptH0,
+    AcceptH1,
+}
+
+impl fmt::Display for SprtResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SprtResult::Continue => write!(f, "no decision yet"),
+            SprtResult::AcceptH0 => write!(f, "H0 accepted"),
+            SprtResult::AcceptH1 => write!(f, "H1 accepted"),
+        }
+    }
+}
+
+/// Represents a sequential probability ratio test between two Elo differences,
+/// which stops a match as soon as its results are conclusive.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sprt {
+    /// The Elo difference of the null hypothesis.
+    elo0: f64,
+    /// The Elo difference of the alternative hypothesis.
+    elo1: f64,
+    /// The probability of accepting the alternative hypothesis when it is false.
+    alpha: f64,
+    /// The probability of accepting the null hypothesis when it is false.
+    beta: f64,
+}
+
+impl Sprt {
+    /// Creates a test.
+    ///
+    /// # Arguments
+    ///
+    /// * `elo0` - The Elo difference of the null hypothesis.
+    /// * `elo1` - The Elo difference of the alternative hypothesis.
+    /// * `alpha` - The probability of a false positive.
+    /// * `beta` - The probability of a false negative.
+    ///
+    /// # Returns
+    ///
+    /// A new `Sprt` instance.
+    pub fn new(elo0: f64, elo1: f64, alpha: f64, beta: f64) -> Sprt {
+        Sprt {
+            elo0,
+            elo1,
+            alpha,
+            beta,
+        }
+    }
+
+    /// Gets the log-likelihood ratio below which the null hypothesis is accepted.
+    ///
+    /// # Returns
+    ///
+    /// The lower bound of the test.
+    pub fn get_lower_bound(&self) -> f64 {
+        (self.beta / (1.0 - self.alpha)).ln()
+    }
+
+    /// Gets the log-likelihood ratio above which the alternative hypothesis is
+    /// accepted.
+    ///
+    /// # Returns
+    ///
+    /// The upper bound of the test.
+    pub fn get_upper_bound(&self) -> f64 {
+        ((1.0 - self.beta) / self.alpha).ln()
+    }
+
+    /// Gets the log-likelihood ratio of the results.
+    ///
+    /// # Arguments
+    ///
+    /// * `stats` - The results of the match.
+    ///
+    /// # Returns
+    ///
+    /// The ratio, to compare with the bounds.
+    pub fn get_llr(&self, stats: &MatchStats) -> f64 {
+        stats.get_llr(self.elo0, self.elo1)
+    }
+
+    /// Decides whether the results are conclusive.
+    ///
+    /// # Arguments
+    ///
+    /// * `stats` - The results of the match.
+    ///
+    /// # Returns
+    ///
+    /// The `SprtResult` of the test.
+    pub fn get_result(&self, stats: &MatchStats) -> SprtResult {
+        let llr: f64 = self.get_llr(stats);
+        if llr >= self.get_upper_bound() {
+            SprtResult::AcceptH1
+        } else if llr <= self.get_lower_bound() {
+            SprtResult::AcceptH0
+        } else {
+            SprtResult::Continue
+        }
+    }
+}
+
+/// Represents the settings of a match.
+#[derive(Clone)]
+pub struct MatchSettings {
+    /// The largest number of games to play.
+    games: u32,
+    /// The time control of every game.
+    time_control: TimeControl,
+    /// The games to continue from, each played twice with the colors swapped.
+    openings: Vec<Board>,
+    /// The rules ending games early.
+    adjudication: Adjudication,
+    /// The test stopping the match early, if any.
+    sprt: Option<Sprt>,
+}
+
+impl MatchSettings {
+    /// Creates the settings of a match played from the starting position.
+    ///
+    /// # Arguments
+    ///
+    /// * `games` - The number of games to play.
+    /// * `time_control` - The time control of every game.
+    ///
+    /// # Returns
+    ///
+    /// A new `MatchSettings` instance.
+    pub fn new(games: u32, time_control: TimeControl) -> MatchSettings {
+        MatchSettings {
+            games,
+            time_control,
+            openings: vec![Board::new().initialize()],
+            adjudication: Adjudication::new(),
+            sprt: None,
+        }
+    }
+
+    /// Sets the games to continue from. Each opening is played twice in a row, once
+    /// with each player on the white side, and the list starts over when it runs out.
+    ///
+    /// # Arguments
+    ///
+    /// * `openings` - The games to continue from.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the settings with the openings set, or
+    /// `MatchError::NoOpening` if the list is empty.
+    pub fn with_openings(mut self, openings: Vec<Board>) -> Result<MatchSettings, MatchError> {
+        if openings.is_empty() {
+            return Err(MatchError::NoOpening);
+        }
+        self.openings = openings;
+        Ok(self)
+    }
+
+    /// Sets the rules ending games early.
+    ///
+    /// # Arguments
+    ///
+    /// * `adjudication` - The rules.
+    ///
+    /// # Returns
+    ///
+    /// The settings with the rules set.
+    pub fn with_adjudication(mut self, adjudication: Adjudication) -> MatchSettings {
+        self.adjudication = adjudication;
+        self
+    }
+
+    /// Sets the test stopping the match once its results are conclusive.
+    ///
+    /// # Arguments
+    ///
+    /// * `sprt` - The test.
+    ///
+    /// # Returns
+    ///
+    /// The settings with the test set.
+    pub fn with_sprt(mut self, sprt: Sprt) -> MatchSettings {
+        self.sprt = Some(sprt);
+        self
+    }
+
+    /// Gets the largest number of games to play.
+    ///
+    /// # Returns
+    ///
+    /// The number of games.
+    pub fn get_games(&self) -> u32 {
+        self.games
+    }
+
+    /// Gets the test stopping the match early.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Sprt>` containing the test, if any.
+    pub fn get_sprt(&self) -> Option<Sprt> {
+        self.sprt
+    }
+}
+
+/// Represents a match between two players, played one game at a time.
+pub struct EngineMatch {
+    /// The first player, whose point of view the results take.
+    first: Box<dyn Player>,
+    /// The second player.
+    second: Box<dyn Player>,
+    /// The settings of the match.
+    settings: MatchSettings,
+    /// The results so far.
+    stats: MatchStats,
+    /// The date of the match, written in the headers of its games.
+    date: String,
+}
+
+impl EngineMatch {
+    /// Creates a match where no game has been played yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `first` - The first player, who has white in the first game.
+    /// * `second` - The second player.
+    /// * `settings` - The settings of the match.
+    ///
+    /// # Returns
+    ///
+    /// A new `EngineMatch` instance.
+    pub fn new(
+        first: Box<dyn Player>,
+        second: Box<dyn Player>,
+        settings: MatchSettings,
+    ) -> EngineMatch {
+        EngineMatch {
+            first,
+            second,
+            settings,
+            stats: MatchStats::default(),
+            date: get_date(),
+        }
+    }
+
+    /// Gets the results so far, from the point of view of the first player.
+    ///
+    /// # Returns
+    ///
+    /// The `MatchStats` of the match.
+    pub fn get_stats(&self) -> MatchStats {
+        self.stats
+    }
+
+    /// Gets the name of the first player.
+    ///
+    /// # Returns
+    ///
+    /// A string slice containing the name.
+    pub fn get_first_name(&self) -> &str {
+        self.first.get_name()
+    }
+
+    /// Gets the name of the second player.
+    ///
+    /// # Returns
+    ///
+    /// A string slice containing the name.
+    pub fn get_second_name(&self) -> &str {
+        self.second.get_name()
+    }
+
+    /// Checks whether the match is over, because every game has been played or the
+    /// test of the settings has reached a decision.
+    ///
+    /// # Returns
+    ///
+    /// `true` if no game remains to be played, `false` otherwise.
+    pub fn is_finished(&self) -> bool {
+        self.stats.get_games() >= self.settings.games
+            || self
+                .settings
+                .sprt
+                .is_some_and(|sprt| sprt.get_result(&self.stats) != SprtResult::Continue)
+    }
+
+    /// Plays the next game of the match.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the game, `None` if the match is over, or a
+    /// `MatchError` if a player cannot be reached.
+    pub fn play_next(&mut self) -> Result<Option<MatchGame>, MatchError> {
+        if self.is_finished() {
+            return Ok(None);
+        }
+        let round: u32 = self.stats.get_games();
+        let opening: &Board =
+            &self.settings.openings[(round / 2) as usize % self.settings.openings.len()];
+        let first_color: Color = if round.is_multiple_of(2) {
+            Color::White
+        } else {
+            Color::Black
+        };
+        let (white, black): (&mut dyn Player, &mut dyn Player) = match first_color {
+            Color::White => (self.first.as_mut(), self.second.as_mut()),
+            Color::Black => (self.second.as_mut(), self.first.as_mut()),
+        };
+
+        let mut game: MatchGame = play_game(
+            white,
+            black,
+            opening,
+            &self.settings.time_control,
+            self.settings.adjudication,
+        )?;
+        game.board.set_pgn_tag("Event", "Engine match");
+        game.board.set_pgn_tag("Date", &self.date);
+        game.board.set_pgn_tag("Round", &(round + 1).to_string());
+        self.stats.add_game(game.winner, first_color);
+        Ok(Some(game))
+    }
+}
+
+/// Gets the current date in the format of the PGN `Date` tag.
+///
+/// # Returns
+///
+/// A `String` such as `2024.03.17`.
+fn get_date() -> String {
+    let days: i64 = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| (elapsed.as_secs() / 86_400) as i64);
+
+    // Converts days since 1970-01-01 to a civil date, with years starting in March.
+    let shifted: i64 = days + 719_468;
+    let era: i64 = shifted.div_euclid(146_097);
+    let day_of_era: i64 = shifted.rem_euclid(146_097);
+    let year_of_era: i64 =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year: i64 = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index: i64 = (5 * day_of_year + 2) / 153;
+    let day: i64 = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month: i64 = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year: i64 = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{:04}.{:02}.{:02}", year, month, day)
+}
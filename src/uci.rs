@@ -0,0 +1,737 @@
+use std::{
+    fmt,
+    io::{self, BufRead, BufReader, Write},
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+    sync::mpsc::{self, Receiver, RecvTimeoutError, Sender},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{board::Board, engine::Score, fen::STARTING_FEN, moves::Move};
+
+/// The time an engine is given to answer `uci` and `isready`.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The time an engine is given to answer `stop` once a search is out of time.
+const STOP_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The time an engine is given to exit after `quit`.
+const QUIT_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Represents the reasons talking to a UCI engine can fail.
+///
+/// # Variants
+///
+/// - `Spawn`: The engine executable could not be started.
+/// - `Io`: A command could not be sent to the engine.
+/// - `Timeout`: The engine did not answer a command in time.
+/// - `Exited`: The engine closed its output.
+#[derive(Debug)]
+pub enum UciError {
+    Spawn(io::Error),
+    Io(io::Error),
+    Timeout(String),
+    Exited,
+}
+
+impl fmt::Display for UciError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UciError::Spawn(error) => write!(f, "cannot start the engine: {}", error),
+            UciError::Io(error) => write!(f, "cannot talk to the engine: {}", error),
+            UciError::Timeout(command) => write!(f, "the engine did not answer '{}'", command),
+            UciError::Exited => write!(f, "the engine has exited"),
+        }
+    }
+}
+
+impl std::error::Error for UciError {}
+
+/// Represents how the score of an `info` line bounds the real score.
+///
+/// # Variants
+///
+/// - `Exact`: The score is exact.
+/// - `Lower`: The real score is at least the score given.
+/// - `Upper`: The real score is at most the score given.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScoreBound {
+    Exact,
+    Lower,
+    Upper,
+}
+
+/// Represents the search progress reported by an `info` line. Every field is
+/// optional, as engines only send the ones they want.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UciInfo {
+    /// The depth searched, in half moves.
+    depth: Option<u32>,
+    /// The deepest half move reached, quiescence included.
+    seldepth: Option<u32>,
+    /// The score of the position for the side to move.
+    score: Option<Score>,
+    /// How the score bounds the real score.
+    bound: Option<ScoreBound>,
+    /// The number of nodes searched.
+    nodes: Option<u64>,
+    /// The number of nodes searched per second.
+    nps: Option<u64>,
+    /// The time searched, in milliseconds.
+    time: Option<u64>,
+    /// The rank of the line when several are searched.
+    multipv: Option<u32>,
+    /// The best line found, in long algebraic notation.
+    pv: Vec<String>,
+    /// A free text sent by the engine.
+    string: Option<String>,
+}
+
+impl UciInfo {
+    /// Gets the depth searched.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<u32>` containing the depth, if sent.
+    pub fn get_depth(&self) -> Option<u32> {
+        self.depth
+    }
+
+    /// Gets the deepest half move reached.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<u32>` containing the selective depth, if sent.
+    pub fn get_seldepth(&self) -> Option<u32> {
+        self.seldepth
+    }
+
+    /// Gets the score of the position for the side to move.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Score>` containing the score, if sent.
+    pub fn get_score(&self) -> Option<Score> {
+        self.score
+    }
+
+    /// Gets how the score bounds the real score.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<ScoreBound>` containing the bound, if a score was sent.
+    pub fn get_bound(&self) -> Option<ScoreBound> {
+        self.bound
+    }
+
+    /// Gets the number of nodes searched.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<u64>` containing the node count, if sent.
+    pub fn get_nodes(&self) -> Option<u64> {
+        self.nodes
+    }
+
+    /// Gets the number of nodes searched per second.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<u64>` containing the speed, if sent.
+    pub fn get_nps(&self) -> Option<u64> {
+        self.nps
+    }
+
+    /// Gets the time searched.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<u64>` containing the time in milliseconds, if sent.
+    pub fn get_time(&self) -> Option<u64> {
+        self.time
+    }
+
+    /// Gets the rank of the line.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<u32>` containing the rank, if sent.
+    pub fn get_multipv(&self) -> Option<u32> {
+        self.multipv
+    }
+
+    /// Gets the best line found.
+    ///
+    /// # Returns
+    ///
+    /// A slice of the moves of the line in long algebraic notation.
+    pub fn get_pv(&self) -> &[String] {
+        &self.pv
+    }
+
+    /// Gets the free text sent by the engine.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<&str>` containing the text, if sent.
+    pub fn get_string(&self) -> Option<&str> {
+        self.string.as_deref()
+    }
+
+    /// Reads the best line as moves played from a position. The line stops at the
+    /// first move that is not legal.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - The position the line starts from.
+    ///
+    /// # Returns
+    ///
+    /// A vector containing the moves of the line.
+    pub fn get_pv_moves(&self, board: &Board) -> Vec<Move> {
+        let mut board: Board = board.clone();
+        let mut moves: Vec<Move> = Vec::new();
+
+        for uci in self.pv.iter() {
+            match board.parse_uci(uci) {
+                Some(chess_move) if board.make_move(chess_move).is_ok() => moves.push(chess_move),
+                _ => break,
+            }
+        }
+        moves
+    }
+}
+
+/// Represents the answer of an engine to `go`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BestMove {
+    /// The move chosen, in long algebraic notation, `None` without a legal move.
+    best_move: Option<String>,
+    /// The answer the engine expects, if any.
+    ponder: Option<String>,
+}
+
+impl BestMove {
+    /// Gets the move chosen.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<&str>` containing the move, `None` when there is no legal move.
+    pub fn get_best_move(&self) -> Option<&str> {
+        self.best_move.as_deref()
+    }
+
+    /// Gets the answer the engine expects.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<&str>` containing the move, if sent.
+    pub fn get_ponder(&self) -> Option<&str> {
+        self.ponder.as_deref()
+    }
+}
+
+/// Represents a line sent by an engine.
+///
+/// # Variants
+///
+/// - `Id`: The name or author of the engine.
+/// - `UciOk`: The end of the answer to `uci`.
+/// - `ReadyOk`: The answer to `isready`.
+/// - `Option`: An option the engine supports, left unparsed.
+/// - `Info`: The progress of the search.
+/// - `BestMove`: The end of the search.
+/// - `Unknown`: Any other line.
+#[derive(Clone, Debug, PartialEq)]
+pub enum UciMessage {
+    Id { name: String, value: String },
+    UciOk,
+    ReadyOk,
+    Option(String),
+    Info(UciInfo),
+    BestMove(BestMove),
+    Unknown(String),
+}
+
+/// Parses a line sent by an engine.
+///
+/// # Arguments
+///
+/// * `line` - The line, without its line break.
+///
+/// # Returns
+///
+/// The `UciMessage` the line stands for.
+pub fn parse_message(line: &str) -> UciMessage {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+
+    match tokens.first().copied() {
+        Some("id") if tokens.len() > 2 => UciMessage::Id {
+            name: tokens[1].to_string(),
+            value: tokens[2..].join(" "),
+        },
+        Some("uciok") => UciMessage::UciOk,
+        Some("readyok") => UciMessage::ReadyOk,
+        Some("option") => UciMessage::Option(tokens[1..].join(" ")),
+        Some("info") => UciMessage::Info(parse_info(&tokens[1..])),
+        Some("bestmove") => UciMessage::BestMove(BestMove {
+            best_move: tokens
+                .get(1)
+                .filter(|uci| !matches!(**uci, "0000" | "(none)"))
+                .map(|uci| uci.to_string()),
+            ponder: match tokens.get(2) {
+                Some(&"ponder") => tokens.get(3).map(|uci| uci.to_string()),
+                _ => None,
+            },
+        }),
+        _ => UciMessage::Unknown(line.to_string()),
+    }
+}
+
+/// Parses the fields of an `info` line. Unknown fields are skipped, and `pv` and
+/// `string` take the rest of the line.
+///
+/// # Arguments
+///
+/// * `tokens` - The words following `info`.
+///
+/// # Returns
+///
+/// The `UciInfo` read from the line.
+fn parse_info(tokens: &[&str]) -> UciInfo {
+    let mut info: UciInfo = UciInfo::default();
+    let mut index: usize = 0;
+    let number = |index: usize| -> Option<u64> { tokens.get(index + 1)?.parse().ok() };
+
+    while index < tokens.len() {
+        match tokens[index] {
+            "depth" => info.depth = number(index).map(|depth| depth as u32),
+            "seldepth" => info.seldepth = number(index).map(|depth| depth as u32),
+            "nodes" => info.nodes = number(index),
+            "nps" => info.nps = number(index),
+            "time" => info.time = number(index),
+            "multipv" => info.multipv = number(index).map(|rank| rank as u32),
+            "score" => {
+                let value: Option<i32> = tokens.get(index + 2).and_then(|value| value.parse().ok());
+                info.score = match (tokens.get(index + 1), value) {
+                    (Some(&"cp"), Some(value)) => Some(Score::Centipawns(value)),
+                    (Some(&"mate"), Some(value)) => Some(Score::Mate(value)),
+                    _ => None,
+                };
+                info.bound = info.score.map(|_| ScoreBound::Exact);
+                index += 2;
+            }
+            "lowerbound" => info.bound = Some(ScoreBound::Lower),
+            "upperbound" => info.bound = Some(ScoreBound::Upper),
+            "pv" => {
+                info.pv = tokens[index + 1..]
+                    .iter()
+                    .map(|uci| uci.to_string())
+                    .collect();
+                break;
+            }
+            "string" => {
+                info.string = Some(tokens[index + 1..].join(" "));
+                break;
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    info
+}
+
+/// Represents the parameters of a `go` command.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GoParams {
+    /// The deepest iteration to search, in half moves.
+    depth: Option<u32>,
+    /// The number of nodes to search.
+    nodes: Option<u64>,
+    /// The time to search.
+    movetime: Option<Duration>,
+    /// The time left on the clocks of white and black.
+    clock: Option<(Duration, Duration)>,
+    /// The time added after each move of white and black.
+    increment: Option<(Duration, Duration)>,
+    /// The number of moves before the next time control.
+    moves_to_go: Option<u32>,
+}
+
+impl GoParams {
+    /// Creates parameters that search until the engine is told to stop.
+    ///
+    /// # Returns
+    ///
+    /// A new `GoParams` instance without any limit.
+    pub fn new() -> GoParams {
+        GoParams::default()
+    }
+
+    /// Sets the deepest iteration to search.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth` - The depth in half moves.
+    ///
+    /// # Returns
+    ///
+    /// The parameters with the depth set.
+    pub fn with_depth(mut self, depth: u32) -> GoParams {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// Sets the number of nodes to search.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes` - The number of nodes.
+    ///
+    /// # Returns
+    ///
+    /// The parameters with the node count set.
+    pub fn with_nodes(mut self, nodes: u64) -> GoParams {
+        self.nodes = Some(nodes);
+        self
+    }
+
+    /// Sets the time to search.
+    ///
+    /// # Arguments
+    ///
+    /// * `movetime` - The time to search.
+    ///
+    /// # Returns
+    ///
+    /// The parameters with the time set.
+    pub fn with_movetime(mut self, movetime: Duration) -> GoParams {
+        self.movetime = Some(movetime);
+        self
+    }
+
+    /// Sets the clocks, letting the engine manage its own time.
+    ///
+    /// # Arguments
+    ///
+    /// * `white` - The time left to white.
+    /// * `black` - The time left to black.
+    /// * `increment` - The time added after each move of each player.
+    /// * `moves_to_go` - The number of moves before the next time control, if any.
+    ///
+    /// # Returns
+    ///
+    /// The parameters with the clocks set.
+    pub fn with_clock(
+        mut self,
+        white: Duration,
+        black: Duration,
+        increment: Duration,
+        moves_to_go: Option<u32>,
+    ) -> GoParams {
+        self.clock = Some((white, black));
+        self.increment = Some((increment, increment));
+        self.moves_to_go = moves_to_go;
+        self
+    }
+
+    /// Writes the parameters as a `go` command.
+    ///
+    /// # Returns
+    ///
+    /// A `String` such as `go depth 10` or `go wtime 60000 btime 60000 winc 0 binc 0`.
+    pub fn get_command(&self) -> String {
+        let mut command: String = "go".to_string();
+
+        if let Some(depth) = self.depth {
+            command.push_str(&format!(" depth {}", depth));
+        }
+        if let Some(nodes) = self.nodes {
+            command.push_str(&format!(" nodes {}", nodes));
+        }
+        if let Some(movetime) = self.movetime {
+            command.push_str(&format!(" movetime {}", movetime.as_millis()));
+        }
+        if let Some((white, black)) = self.clock {
+            command.push_str(&format!(
+                " wtime {} btime {}",
+                white.as_millis(),
+                black.as_millis()
+            ));
+        }
+        if let Some((white, black)) = self.increment {
+            command.push_str(&format!(
+                " winc {} binc {}",
+                white.as_millis(),
+                black.as_millis()
+            ));
+        }
+        if let Some(moves_to_go) = self.moves_to_go {
+            command.push_str(&format!(" movestogo {}", moves_to_go));
+        }
+        if self.depth.is_none()
+            && self.nodes.is_none()
+            && self.movetime.is_none()
+            && self.clock.is_none()
+        {
+            command.push_str(" infinite");
+        }
+        command
+    }
+}
+
+/// Represents a running UCI engine executable.
+pub struct UciEngine {
+    /// The engine process.
+    child: Child,
+    /// The standard input of the engine.
+    stdin: ChildStdin,
+    /// The lines written by the engine, read by a separate thread.
+    lines: Receiver<String>,
+    /// The name the engine gave.
+    name: String,
+    /// The author the engine gave.
+    author: String,
+}
+
+impl UciEngine {
+    /// Starts an engine and performs the handshake.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the engine executable.
+    /// * `args` - The arguments given to the executable.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the ready engine, or a `UciError` if it cannot be
+    /// started or does not answer.
+    pub fn spawn(path: &str, args: &[String]) -> Result<UciEngine, UciError> {
+        let mut child: Child = Command::new(path)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(UciError::Spawn)?;
+        let stdin: ChildStdin = child.stdin.take().ok_or(UciError::Exited)?;
+        let stdout: ChildStdout = child.stdout.take().ok_or(UciError::Exited)?;
+
+        let (sender, lines): (Sender<String>, Receiver<String>) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut engine: UciEngine = UciEngine {
+            child,
+            stdin,
+            lines,
+            name: path.to_string(),
+            author: String::new(),
+        };
+        engine.send("uci")?;
+        loop {
+            match engine.receive(HANDSHAKE_TIMEOUT, "uci")? {
+                UciMessage::Id { name, value } if name == "name" => engine.name = value,
+                UciMessage::Id { name, value } if name == "author" => engine.author = value,
+                UciMessage::UciOk => break,
+                _ => {}
+            }
+        }
+        engine.wait_ready()?;
+        Ok(engine)
+    }
+
+    /// Gets the name the engine gave.
+    ///
+    /// # Returns
+    ///
+    /// A string slice containing the name, or the path of the executable if none
+    /// was given.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the author the engine gave.
+    ///
+    /// # Returns
+    ///
+    /// A string slice containing the author, empty if none was given.
+    pub fn get_author(&self) -> &str {
+        &self.author
+    }
+
+    /// Sends a command to the engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command, without its line break.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is a `UciError` if the engine cannot be reached.
+    pub fn send(&mut self, command: &str) -> Result<(), UciError> {
+        writeln!(self.stdin, "{}", command)
+            .and_then(|_| self.stdin.flush())
+            .map_err(UciError::Io)
+    }
+
+    /// Waits for the next line of the engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The longest time to wait.
+    /// * `command` - The command being answered, used in the error message.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the parsed line, or a `UciError` if the engine has
+    /// exited or did not answer in time.
+    fn receive(&self, timeout: Duration, command: &str) -> Result<UciMessage, UciError> {
+        let line: String = self
+            .lines
+            .recv_timeout(timeout)
+            .map_err(|error| match error {
+                RecvTimeoutError::Timeout => UciError::Timeout(command.to_string()),
+                RecvTimeoutError::Disconnected => UciError::Exited,
+            })?;
+        Ok(parse_message(&line))
+    }
+
+    /// Sends `isready` and waits for `readyok`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is a `UciError` if the engine does not answer.
+    pub fn wait_ready(&mut self) -> Result<(), UciError> {
+        self.send("isready")?;
+        while self.receive(HANDSHAKE_TIMEOUT, "isready")? != UciMessage::ReadyOk {}
+        Ok(())
+    }
+
+    /// Changes an option of the engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the option.
+    /// * `value` - The new value, or `None` for a button.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is a `UciError` if the engine does not answer.
+    pub fn set_option(&mut self, name: &str, value: Option<&str>) -> Result<(), UciError> {
+        match value {
+            Some(value) => self.send(&format!("setoption name {} value {}", name, value))?,
+            None => self.send(&format!("setoption name {}", name))?,
+        }
+        self.wait_ready()
+    }
+
+    /// Tells the engine that the next position belongs to a new game.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is a `UciError` if the engine does not answer.
+    pub fn new_game(&mut self) -> Result<(), UciError> {
+        self.send("ucinewgame")?;
+        self.wait_ready()
+    }
+
+    /// Sends the position of a game: its initial position and the moves played.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - The game.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is a `UciError` if the engine cannot be reached.
+    pub fn set_position(&mut self, board: &Board) -> Result<(), UciError> {
+        self.send(&get_position_command(board))
+    }
+
+    /// Searches the position last sent and waits for the best move. Once the
+    /// timeout passes, `stop` is sent and the engine is given a moment to answer.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters of the search. A search without limits runs
+    ///   until the timeout.
+    /// * `timeout` - The longest time to wait before sending `stop`.
+    /// * `on_info` - Called with each `info` line sent during the search.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the answer of the engine, or a `UciError` if it exits
+    /// or does not answer `stop` either.
+    pub fn go(
+        &mut self,
+        params: &GoParams,
+        timeout: Duration,
+        mut on_info: impl FnMut(&UciInfo),
+    ) -> Result<BestMove, UciError> {
+        let mut deadline: Instant = Instant::now() + timeout;
+        let mut stopped: bool = false;
+
+        self.send(&params.get_command())?;
+        loop {
+            let remaining: Duration = deadline.saturating_duration_since(Instant::now());
+            match self.receive(remaining, "go") {
+                Ok(UciMessage::Info(info)) => on_info(&info),
+                Ok(UciMessage::BestMove(best_move)) => return Ok(best_move),
+                Ok(_) => {}
+                Err(UciError::Timeout(_)) if !stopped => {
+                    self.send("stop")?;
+                    deadline = Instant::now() + STOP_TIMEOUT;
+                    stopped = true;
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+}
+
+impl Drop for UciEngine {
+    fn drop(&mut self) {
+        let _ = self.send("quit");
+        let mut waited: Duration = Duration::ZERO;
+        while waited < QUIT_TIMEOUT && matches!(self.child.try_wait(), Ok(None)) {
+            thread::sleep(Duration::from_millis(10));
+            waited += Duration::from_millis(10);
+        }
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Writes the `position` command of a game. The standard starting position is
+/// sent as `startpos`, any other as its FEN.
+///
+/// # Arguments
+///
+/// * `board` - The game.
+///
+/// # Returns
+///
+/// A `String` such as `position startpos moves e2e4 e7e5`.
+pub fn get_position_command(board: &Board) -> String {
+    let mut command: String = if board.get_initial_fen() == STARTING_FEN {
+        "position startpos".to_string()
+    } else {
+        format!("position fen {}", board.get_initial_fen())
+    };
+    let moves: Vec<Move> = board.get_moves();
+
+    if !moves.is_empty() {
+        command.push_str(" moves");
+        for chess_move in moves.iter() {
+            command.push(' ');
+            command.push_str(&chess_move.get_uci());
+        }
+    }
+    command
+}
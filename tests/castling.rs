@@ -0,0 +1,115 @@
+mod common;
+
+use chess::{board::Board, castling::CastlingRights, color::Color, kind::Kind};
+
+use common::{click, get_piece, play_squares};
+
+/// The moves clearing the squares between the white king and its rook on h1.
+const KING_SIDE_READY: &str = "e2e4 e7e5 g1f3 b8c6 f1c4 g8f6";
+
+/// The moves clearing the squares between the kings and their rooks on the a-file.
+const QUEEN_SIDE_READY: &str = "d2d4 d7d5 b1c3 b8c6 c1f4 c8f5 d1d2 d8d7";
+
+/// Tries to castle the white king, which stays on e1 when castling is refused.
+///
+/// # Arguments
+///
+/// * `board` - The board to play on.
+/// * `target` - The square the king moves to, `g1` or `c1`.
+///
+/// # Returns
+///
+/// `true` if the king has castled, `false` otherwise.
+fn try_castle(board: &mut Board, target: &str) -> bool {
+    click(board, "e1");
+    click(board, target);
+    get_piece(board, "e1").is_none()
+}
+
+#[test]
+fn kings_castle_on_both_sides() {
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, KING_SIDE_READY);
+    play_squares(&mut board, "e1g1");
+
+    assert!(get_piece(&board, "g1") == Some((Kind::King, Color::White)));
+    assert!(get_piece(&board, "f1") == Some((Kind::Rook, Color::White)));
+    assert!(get_piece(&board, "h1").is_none());
+    assert_eq!(board.get_notations().last().unwrap(), "O-O");
+    assert!(!board.get_castling_rights().get_king_side(Color::White));
+    assert!(!board.get_castling_rights().get_queen_side(Color::White));
+
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, QUEEN_SIDE_READY);
+    play_squares(&mut board, "e1c1 e8c8");
+
+    for (king, rook, color) in [("c1", "d1", Color::White), ("c8", "d8", Color::Black)] {
+        assert!(get_piece(&board, king) == Some((Kind::King, color)));
+        assert!(get_piece(&board, rook) == Some((Kind::Rook, color)));
+    }
+    assert!(get_piece(&board, "a1").is_none());
+    assert!(get_piece(&board, "a8").is_none());
+    assert_eq!(board.get_notations()[8..], ["O-O-O", "O-O-O"]);
+    assert!(board.get_castling_rights() == CastlingRights::none());
+}
+
+#[test]
+fn moving_the_king_or_a_rook_loses_castling_rights() {
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, KING_SIDE_READY);
+    play_squares(&mut board, "e1e2 f8c5 e2e1 d7d6");
+
+    assert!(!board.get_castling_rights().get_king_side(Color::White));
+    assert!(!board.get_castling_rights().get_queen_side(Color::White));
+    assert!(!try_castle(&mut board, "g1"));
+
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, KING_SIDE_READY);
+    play_squares(&mut board, "h1g1 f8c5 g1h1 d7d6");
+
+    assert!(!board.get_castling_rights().get_king_side(Color::White));
+    assert!(board.get_castling_rights().get_queen_side(Color::White));
+    assert!(board.get_castling_rights().get_king_side(Color::Black));
+    assert!(!try_castle(&mut board, "g1"));
+}
+
+#[test]
+fn castling_needs_empty_squares_between_king_and_rook() {
+    let mut board: Board = Board::new().initialize();
+    assert!(!try_castle(&mut board, "g1"));
+
+    // The knight on b1 blocks castling although the king does not cross b1.
+    play_squares(&mut board, "d2d4 d7d5 c1f4 c8f5 d1d2 d8d7");
+    assert!(!try_castle(&mut board, "c1"));
+    assert!(get_piece(&board, "a1") == Some((Kind::Rook, Color::White)));
+}
+
+#[test]
+fn castling_is_refused_out_of_check_or_across_attacked_squares() {
+    // The bishop on b4 gives check.
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, "g1f3 e7e6 g2g3 b7b6 f1g2 c8b7 d2d4 f8b4");
+    assert!(!try_castle(&mut board, "g1"));
+
+    // The bishop on a6 attacks f1, which the king crosses.
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, "g1h3 b7b6 g2g3 c8a6 f1g2 e7e6 e2e4 d7d6");
+    assert!(!try_castle(&mut board, "g1"));
+
+    // The bishop on c5 attacks g1, where the king lands.
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, "e2e4 e7e5 f2f4 f8c5 g1f3 d7d6 f1c4 c8g4");
+    assert!(!try_castle(&mut board, "g1"));
+    assert!(get_piece(&board, "h1") == Some((Kind::Rook, Color::White)));
+}
+
+#[test]
+fn capturing_a_rook_on_its_corner_loses_the_castling_right() {
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, "g2g3 b7b6 f1h3 c8b7 a2a3 b7h1");
+
+    assert!(!board.get_castling_rights().get_king_side(Color::White));
+    assert!(board.get_castling_rights().get_queen_side(Color::White));
+    assert!(board.get_castling_rights().get_king_side(Color::Black));
+    assert!(board.get_castling_rights().get_queen_side(Color::Black));
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use chess::{board::Board, color::Color, outcome::Outcome};
+
+use common::{click, get_piece, play_squares};
+
+#[test]
+fn fools_mate_is_checkmate() {
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, "f2f3 e7e5 g2g4");
+    assert!(board.get_outcome().is_none());
+    assert!(!board.is_end());
+
+    play_squares(&mut board, "d8h4");
+    assert!(board.is_end());
+    assert_eq!(board.get_outcome(), Some(Outcome::Checkmate(Color::Black)));
+    assert!(board.get_outcome().unwrap().get_winner() == Some(Color::Black));
+    assert!(!board.has_legal_moves(Color::White));
+
+    // No move can be played once the game is over.
+    click(&mut board, "a2");
+    click(&mut board, "a3");
+    assert!(get_piece(&board, "a2").is_some());
+}
+
+#[test]
+fn a_king_without_moves_and_out_of_check_is_stalemated() {
+    // The ten-move stalemate composed by Sam Loyd.
+    let mut board: Board = Board::new().initialize();
+    play_squares(
+        &mut board,
+        "e2e3 a7a5 d1h5 a8a6 h5a5 h7h5 h2h4 a6h6 a5c7 f7f6 \
+         c7d7 e8f7 d7b7 d8d3 b7b8 d3h7 b8c8 f7g6",
+    );
+    assert!(board.get_outcome().is_none());
+
+    play_squares(&mut board, "c8e6");
+    assert!(board.is_end());
+    assert_eq!(board.get_outcome(), Some(Outcome::Stalemate));
+    assert!(board.get_outcome().unwrap().get_winner().is_none());
+    assert!(!board.has_legal_moves(Color::Black));
+}
@@ -0,0 +1,251 @@
+use std::{sync::Arc, time::Duration};
+
+use chess::{
+    board::Board,
+    clock::{format_time, Clock, Increment, MockTimeSource, Stage, TimeControl, TimeControlError},
+    color::Color,
+    moves::MoveError,
+    outcome::{Outcome, Termination},
+    position::Position,
+};
+
+/// Creates a board whose clocks read a mock time, starting at zero.
+///
+/// # Arguments
+///
+/// * `fen` - The position of the board.
+/// * `time_control` - The time control of the game.
+///
+/// # Returns
+///
+/// The board and the time its clocks read.
+fn timed_board(fen: &str, time_control: &str) -> (Board, Arc<MockTimeSource>) {
+    let time: Arc<MockTimeSource> = Arc::new(MockTimeSource::new(0));
+    let mut board: Board = Board::from_fen(fen).unwrap();
+    board.set_time_source(time.clone());
+    board.set_time_control(Some(TimeControl::parse(time_control).unwrap()));
+    (board, time)
+}
+
+#[test]
+fn time_controls_are_read_and_written() {
+    let time_control: TimeControl = TimeControl::parse("40/5400+30:1800+30").unwrap();
+
+    assert_eq!(
+        time_control.get_stages(),
+        [
+            Stage::new(Some(40), Duration::from_secs(5400)),
+            Stage::new(None, Duration::from_secs(1800)),
+        ]
+    );
+    assert_eq!(
+        time_control.get_increment(),
+        Increment::Fischer(Duration::from_secs(30))
+    );
+    assert_eq!(time_control.to_string(), "40/5400:1800+30");
+    assert_eq!(TimeControl::parse("600d5").unwrap().to_string(), "600d5");
+    assert_eq!(TimeControl::parse("10+0.1").unwrap().to_string(), "10+0.1");
+    assert_eq!(time_control.to_pgn_tag(), "40/5400+30:1800+30");
+    assert_eq!(TimeControl::parse("40/60").unwrap().to_pgn_tag(), "40/60");
+    assert_eq!(TimeControl::parse("600d5").unwrap().to_pgn_tag(), "?");
+    assert_eq!(
+        TimeControl::parse("300:40/60"),
+        Err(TimeControlError::StageAfterRestOfGame)
+    );
+    assert_eq!(
+        TimeControl::parse("40/60+1:60+2"),
+        Err(TimeControlError::MixedIncrements)
+    );
+    assert!(TimeControl::parse("0+1").is_err());
+    assert!(TimeControl::parse("/60").is_err());
+    assert!(TimeControl::parse("fast").is_err());
+}
+
+#[test]
+fn increments_give_time_back() {
+    let mut fischer: Clock = Clock::new(TimeControl::parse("60+2").unwrap(), Color::White);
+    fischer.resume(0);
+    assert!(fischer.press(5_000));
+    assert_eq!(
+        fischer.get_remaining(Color::White, 5_000),
+        Duration::from_secs(57)
+    );
+
+    let mut bronstein: Clock = Clock::new(TimeControl::parse("60d2").unwrap(), Color::White);
+    bronstein.resume(0);
+    assert!(bronstein.press(1_500));
+    assert_eq!(
+        bronstein.get_remaining(Color::White, 1_500),
+        Duration::from_secs(60)
+    );
+    assert!(bronstein.press(1_500));
+    assert!(bronstein.press(6_500));
+    assert_eq!(
+        bronstein.get_remaining(Color::White, 6_500),
+        Duration::from_secs(57)
+    );
+    assert_eq!(
+        bronstein.get_remaining(Color::Black, 6_500),
+        Duration::from_secs(60)
+    );
+}
+
+#[test]
+fn stages_add_their_time_when_they_start() {
+    let mut clock: Clock = Clock::new(TimeControl::parse("2/10:2/5").unwrap(), Color::White);
+    clock.resume(0);
+
+    assert_eq!(clock.get_moves_to_go(Color::White), Some(2));
+    assert!(clock.press(4_000));
+    assert!(clock.press(4_000));
+    assert_eq!(clock.get_moves_to_go(Color::White), Some(1));
+    assert!(clock.press(8_000));
+    assert_eq!(
+        clock.get_remaining(Color::White, 8_000),
+        Duration::from_secs(7)
+    );
+    assert_eq!(clock.get_moves_to_go(Color::White), Some(2));
+    assert!(clock.press(8_000));
+    assert!(clock.press(8_000));
+    assert!(clock.press(8_000));
+    assert!(clock.press(8_000));
+    assert_eq!(
+        clock.get_remaining(Color::White, 8_000),
+        Duration::from_secs(12)
+    );
+    assert_eq!(
+        clock.get_remaining(Color::Black, 8_000),
+        Duration::from_secs(15)
+    );
+}
+
+#[test]
+fn paused_clocks_keep_their_time() {
+    let (mut board, time): (Board, Arc<MockTimeSource>) =
+        timed_board(chess::fen::STARTING_FEN, "60");
+
+    time.advance(3_000);
+    board.pause_clock();
+    time.advance(10_000);
+    assert!(board.is_clock_paused());
+    assert_eq!(
+        board.get_remaining_time(Color::White),
+        Some(Duration::from_secs(57))
+    );
+    assert_eq!(
+        board.make_move(board.parse_uci("e2e4").unwrap()),
+        Err(MoveError::ClockPaused)
+    );
+
+    board.resume_clock();
+    time.advance(2_000);
+    board.make_move(board.parse_uci("e2e4").unwrap()).unwrap();
+    assert_eq!(
+        board.get_remaining_time(Color::White),
+        Some(Duration::from_secs(55))
+    );
+    assert_eq!(
+        board.get_remaining_time(Color::Black),
+        Some(Duration::from_secs(60))
+    );
+    assert_eq!(board.get_pgn_tags().get("TimeControl"), Some("60"));
+    assert!(!board.can_undo());
+
+    board.set_time_control(Some(TimeControl::parse("40/5400+30:1800+30").unwrap()));
+    assert_eq!(
+        board.get_pgn_tags().get("TimeControl"),
+        Some("40/5400+30:1800+30")
+    );
+    board.set_time_control(Some(TimeControl::parse("600d5").unwrap()));
+    assert_eq!(board.get_pgn_tags().get("TimeControl"), Some("?"));
+}
+
+#[test]
+fn the_flag_falling_ends_the_game() {
+    let (mut board, time): (Board, Arc<MockTimeSource>) =
+        timed_board(chess::fen::STARTING_FEN, "5+1");
+
+    time.advance(4_000);
+    assert!(!board.check_flag());
+    board.make_move(board.parse_uci("e2e4").unwrap()).unwrap();
+    time.advance(6_000);
+    assert!(board.check_flag());
+    assert!(board.is_end());
+    assert_eq!(board.get_outcome(), Some(Outcome::Timeout(Color::White)));
+    assert_eq!(board.get_termination(), Some(Termination::Timeout));
+    assert_eq!(board.get_remaining_time(Color::Black), Some(Duration::ZERO));
+
+    board.handle_click(*board.get_cell(Position::new(1, 4)));
+    assert_eq!(
+        board.make_move(board.parse_uci("e7e5").unwrap()),
+        Err(MoveError::GameOver)
+    );
+}
+
+#[test]
+fn timeouts_against_a_lone_king_are_drawn() {
+    let (mut board, time): (Board, Arc<MockTimeSource>) =
+        timed_board("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1", "10");
+
+    time.advance(10_000);
+    assert!(board.check_flag());
+    assert_eq!(
+        board.get_outcome(),
+        Some(Outcome::TimeoutVsInsufficientMaterial)
+    );
+    assert_eq!(board.get_outcome().unwrap().get_winner(), None);
+
+    let (mut board, time): (Board, Arc<MockTimeSource>) =
+        timed_board("4k3/8/8/8/8/8/4P3/4K3 b - - 0 1", "10");
+    time.advance(10_000);
+    assert!(board.check_flag());
+    assert_eq!(board.get_outcome(), Some(Outcome::Timeout(Color::White)));
+}
+
+#[test]
+fn timeouts_are_lost_while_a_helpmate_remains() {
+    let cases: [(&str, Outcome); 7] = [
+        (
+            "3nk3/8/8/8/8/8/8/3NK3 w - - 0 1",
+            Outcome::Timeout(Color::Black),
+        ),
+        (
+            "3nk3/8/8/8/8/8/4P3/4K3 w - - 0 1",
+            Outcome::Timeout(Color::Black),
+        ),
+        (
+            "3bk3/8/8/8/8/8/8/3NK3 w - - 0 1",
+            Outcome::Timeout(Color::Black),
+        ),
+        (
+            "2b1k3/8/8/8/8/8/8/2B1K3 w - - 0 1",
+            Outcome::Timeout(Color::Black),
+        ),
+        (
+            "2b1k3/8/8/8/8/8/8/4KB2 w - - 0 1",
+            Outcome::TimeoutVsInsufficientMaterial,
+        ),
+        (
+            "3bk3/8/8/8/8/8/8/4K3 w - - 0 1",
+            Outcome::TimeoutVsInsufficientMaterial,
+        ),
+        (
+            "4k3/8/8/8/8/8/8/3NK3 w - - 0 1",
+            Outcome::TimeoutVsInsufficientMaterial,
+        ),
+    ];
+
+    for (fen, outcome) in cases {
+        let (mut board, time): (Board, Arc<MockTimeSource>) = timed_board(fen, "10");
+        time.advance(10_000);
+        assert!(board.check_flag());
+        assert_eq!(board.get_outcome(), Some(outcome), "{}", fen);
+    }
+}
+
+#[test]
+fn times_are_formatted_as_on_a_clock() {
+    assert_eq!(format_time(Duration::from_secs(5400)), "1:30:00");
+    assert_eq!(format_time(Duration::from_secs(299)), "4:59");
+    assert_eq!(format_time(Duration::from_millis(7_340)), "0:07.3");
+}
@@ -0,0 +1,77 @@
+// Each test file uses only some of these helpers.
+#![allow(dead_code)]
+
+use chess::{board::Board, color::Color, kind::Kind, position::Position};
+
+/// Gets the position of a square from its name.
+///
+/// # Arguments
+///
+/// * `name` - The name of the square, such as `e4`.
+///
+/// # Returns
+///
+/// The `Position` of the square.
+pub fn square(name: &str) -> Position {
+    let name: &[u8] = name.as_bytes();
+    Position::new((b'8' - name[1]) as usize, (name[0] - b'a') as usize)
+}
+
+/// Gets the piece standing on a square.
+///
+/// # Arguments
+///
+/// * `board` - The board to look at.
+/// * `name` - The name of the square, such as `e4`.
+///
+/// # Returns
+///
+/// An `Option` containing the kind and color of the piece, or `None` if the square
+/// is empty.
+pub fn get_piece(board: &Board, name: &str) -> Option<(Kind, Color)> {
+    board
+        .get_cell(square(name))
+        .get_piece()
+        .map(|piece| (piece.get_kind(), piece.get_color()))
+}
+
+/// Clicks a square of the board, as the player would.
+///
+/// # Arguments
+///
+/// * `board` - The board to click on.
+/// * `name` - The name of the square, such as `e4`.
+pub fn click(board: &mut Board, name: &str) {
+    board.handle_click(*board.get_cell(square(name)));
+}
+
+/// Plays moves by clicking their two squares, and checks each has been played.
+///
+/// # Arguments
+///
+/// * `board` - The board to play on.
+/// * `moves` - The moves separated by spaces, each written as its two squares
+///   such as `e2e4`.
+pub fn play_squares(board: &mut Board, moves: &str) {
+    for squares in moves.split_whitespace() {
+        click(board, &squares[..2]);
+        click(board, &squares[2..4]);
+        assert!(
+            get_piece(board, &squares[..2]).is_none(),
+            "{} has not been played",
+            squares
+        );
+    }
+}
+
+/// Plays moves written in SAN on a board.
+///
+/// # Arguments
+///
+/// * `board` - The board to play on.
+/// * `moves` - The moves to play.
+pub fn play(board: &mut Board, moves: &[&str]) {
+    for san in moves {
+        board.make_move(board.parse_san(san).unwrap()).unwrap();
+    }
+}
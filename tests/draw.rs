@@ -0,0 +1,245 @@
+mod common;
+
+use chess::{board::Board, outcome::Outcome};
+
+use common::play_squares;
+
+/// The pawn moves opening the files of the rooks, after which no pawn moves again.
+const OPEN_FILES: &str = "a2a4 a7a6 h2h4 h7h6";
+
+/// Moves of the white rooks visiting the nine placements on their first three
+/// squares before coming back to the first one.
+const ROOK_TOUR: [&str; 9] = [
+    "a1a2", "a2a3", "h1h2", "a3a1", "a1a2", "h2h3", "a2a3", "a3a1", "h3h1",
+];
+
+/// Moves of the black knights visiting four placements before coming back.
+const KNIGHT_TOUR: [&str; 4] = ["g8f6", "b8c6", "f6g8", "c6b8"];
+
+/// The knight moves bringing both players back to the starting position.
+const KNIGHT_SHUFFLE: &str = "g1f3 g8f6 f3g1 f6g8";
+
+/// A cooperative game trading every piece but the kings, ending with Kxe7.
+const TRADE_EVERYTHING: &str = "e2e3 b8a6 f1a6 b7a6 g1f3 g7g5 f3g5 a8b8 g5h7 b8b2 h7f8 \
+    b2a2 f8d7 a2c2 a1a6 h8h2 d7b6 a7b6 d1e2 h2g2 e3e4 g2f2 e2f2 c2c1 e1e2 d8d2 e2d2 \
+    c1h1 a6b6 c7b6 f2b6 h1b1 b6b1 f7f5 e4f5 c8f5 b1f5 e7e5 f5e5 g8e7 e5e7 e8e7";
+
+/// Plays full moves without captures or pawn moves, which never repeat a position
+/// more than twice in fifty moves.
+///
+/// # Arguments
+///
+/// * `board` - The board to play on, with the files of the rooks open.
+/// * `count` - The number of full moves to play.
+fn play_quiet_moves(board: &mut Board, count: usize) {
+    for index in 0..count {
+        play_squares(board, ROOK_TOUR[index % ROOK_TOUR.len()]);
+        play_squares(board, KNIGHT_TOUR[index % KNIGHT_TOUR.len()]);
+    }
+}
+
+#[test]
+fn fifty_moves_can_be_claimed() {
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, OPEN_FILES);
+    assert_eq!(board.get_halfmove_clock(), 0);
+    assert_eq!(board.get_fullmove_number(), 3);
+
+    play_quiet_moves(&mut board, 49);
+    play_squares(&mut board, ROOK_TOUR[49 % ROOK_TOUR.len()]);
+    assert_eq!(board.get_halfmove_clock(), 99);
+    assert!(board.get_claimable_draw().is_none());
+
+    play_squares(&mut board, KNIGHT_TOUR[49 % KNIGHT_TOUR.len()]);
+    assert_eq!(board.get_halfmove_clock(), 100);
+    assert_eq!(board.get_fullmove_number(), 53);
+    assert_eq!(board.get_claimable_draw(), Some(Outcome::FiftyMoveRule));
+    assert!(!board.is_end());
+
+    board.claim_draw();
+    assert_eq!(board.get_outcome(), Some(Outcome::FiftyMoveRule));
+}
+
+#[test]
+fn pawn_moves_and_captures_reset_the_fifty_moves() {
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, OPEN_FILES);
+    play_quiet_moves(&mut board, 10);
+    assert_eq!(board.get_halfmove_clock(), 20);
+
+    play_squares(&mut board, "e2e4");
+    assert_eq!(board.get_halfmove_clock(), 0);
+    play_squares(&mut board, "c6d4 f1c4");
+    assert_eq!(board.get_halfmove_clock(), 2);
+    play_squares(&mut board, "d4c2");
+    assert_eq!(board.get_halfmove_clock(), 0);
+}
+
+#[test]
+fn seventy_five_moves_end_the_game() {
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, OPEN_FILES);
+    play_quiet_moves(&mut board, 74);
+    play_squares(&mut board, ROOK_TOUR[74 % ROOK_TOUR.len()]);
+    assert!(!board.is_end());
+
+    play_squares(&mut board, KNIGHT_TOUR[74 % KNIGHT_TOUR.len()]);
+    assert_eq!(board.get_halfmove_clock(), 150);
+    assert_eq!(board.get_outcome(), Some(Outcome::SeventyFiveMoveRule));
+}
+
+#[test]
+fn threefold_repetition_can_be_claimed() {
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, KNIGHT_SHUFFLE);
+    assert!(board.get_claimable_draw().is_none());
+
+    play_squares(&mut board, KNIGHT_SHUFFLE);
+    assert_eq!(
+        board.get_claimable_draw(),
+        Some(Outcome::ThreefoldRepetition)
+    );
+    assert!(!board.is_end());
+
+    board.claim_draw();
+    assert_eq!(board.get_outcome(), Some(Outcome::ThreefoldRepetition));
+}
+
+#[test]
+fn fivefold_repetition_ends_the_game() {
+    let mut board: Board = Board::new().initialize();
+    for _ in 0..3 {
+        play_squares(&mut board, KNIGHT_SHUFFLE);
+    }
+    assert!(!board.is_end());
+
+    play_squares(&mut board, KNIGHT_SHUFFLE);
+    assert_eq!(board.get_outcome(), Some(Outcome::FivefoldRepetition));
+}
+
+#[test]
+fn positions_without_mating_material_are_drawn() {
+    let mut board: Board = Board::new().initialize();
+    assert!(!board.is_insufficient_material());
+
+    let (moves, last) = TRADE_EVERYTHING.rsplit_once(' ').unwrap();
+    play_squares(&mut board, moves);
+    assert!(!board.is_insufficient_material());
+    assert!(board.get_outcome().is_none());
+
+    play_squares(&mut board, last);
+    assert!(board.is_insufficient_material());
+    assert_eq!(board.get_outcome(), Some(Outcome::InsufficientMaterial));
+}
+
+#[test]
+fn castling_rights_make_positions_differ() {
+    // The kings come back to the same squares, but the first position could still
+    // castle, so the placement is repeated three times without being a repetition.
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, "e2e4 e7e5 g1f3 g8f6");
+    play_squares(&mut board, "e1e2 e8e7 e2e1 e7e8");
+    play_squares(&mut board, "e1e2 e8e7 e2e1 e7e8");
+    assert!(board.get_claimable_draw().is_none());
+
+    play_squares(&mut board, "e1e2 e8e7 e2e1 e7e8");
+    assert_eq!(
+        board.get_claimable_draw(),
+        Some(Outcome::ThreefoldRepetition)
+    );
+}
+
+#[test]
+fn en_passant_squares_count_only_when_the_capture_is_possible() {
+    // After d5, exd6 can be played, so the first position does not repeat.
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, "e2e4 a7a6 e4e5 d7d5");
+    play_squares(&mut board, KNIGHT_SHUFFLE);
+    play_squares(&mut board, KNIGHT_SHUFFLE);
+    assert!(board.get_claimable_draw().is_none());
+
+    play_squares(&mut board, KNIGHT_SHUFFLE);
+    assert_eq!(
+        board.get_claimable_draw(),
+        Some(Outcome::ThreefoldRepetition)
+    );
+
+    // After e4, no black pawn can capture on e3, so the position repeats.
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, "e2e4");
+    play_squares(&mut board, "g8f6 g1f3 f6g8 f3g1");
+    play_squares(&mut board, "g8f6 g1f3 f6g8 f3g1");
+    assert_eq!(
+        board.get_claimable_draw(),
+        Some(Outcome::ThreefoldRepetition)
+    );
+}
+
+#[test]
+fn pinned_pawns_cannot_make_en_passant_squares_count() {
+    let shuffle: &str = "b8c6 h1g1 c6b8 g1h1 b8c6 h1g1 c6b8 g1h1";
+
+    // The pawn on e4 is pinned to its king by the rook, so the position after d4
+    // repeats even though it has an en passant square.
+    let mut board: Board = Board::from_fen("1n2k3/8/8/8/4p3/8/3P4/4R2K w - - 0 1").unwrap();
+    play_squares(&mut board, "d2d4");
+    assert!(board.get_en_passant().is_some());
+    play_squares(&mut board, shuffle);
+    assert_eq!(
+        board.get_claimable_draw(),
+        Some(Outcome::ThreefoldRepetition)
+    );
+
+    // Without the rook, exd3 is legal and the first position does not repeat.
+    let mut board: Board = Board::from_fen("1n2k3/8/8/8/4p3/8/3P4/7K w - - 0 1").unwrap();
+    play_squares(&mut board, "d2d4");
+    play_squares(&mut board, shuffle);
+    assert!(board.get_claimable_draw().is_none());
+}
+
+#[test]
+fn insufficient_material_depends_on_the_minor_pieces() {
+    for fen in [
+        "4k3/8/8/8/8/8/8/4K3 w - - 0 1",
+        "4k3/8/8/8/8/8/8/4KN2 w - - 0 1",
+        "4k3/8/8/8/8/8/8/4KB2 w - - 0 1",
+        "2b1k3/8/8/8/8/8/8/4KB2 w - - 0 1",
+    ] {
+        assert!(
+            Board::from_fen(fen).unwrap().is_insufficient_material(),
+            "{}",
+            fen
+        );
+    }
+    for fen in [
+        "4k3/8/8/8/8/8/8/2B1KB2 w - - 0 1",
+        "3bk3/8/8/8/8/8/8/4KB2 w - - 0 1",
+        "4k3/8/8/8/8/8/8/4KNN1 w - - 0 1",
+        "4k3/8/8/8/8/8/4P3/4K3 w - - 0 1",
+    ] {
+        assert!(
+            !Board::from_fen(fen).unwrap().is_insufficient_material(),
+            "{}",
+            fen
+        );
+    }
+}
+
+#[test]
+fn automatic_draws_can_be_turned_off() {
+    let mut board: Board = Board::new().initialize();
+    board.set_automatic_draws(false);
+    assert!(!board.get_automatic_draws());
+    for _ in 0..5 {
+        play_squares(&mut board, KNIGHT_SHUFFLE);
+    }
+    assert!(!board.is_end());
+    assert_eq!(
+        board.get_claimable_draw(),
+        Some(Outcome::ThreefoldRepetition)
+    );
+
+    // Turning them back on applies the draw reached meanwhile.
+    board.set_automatic_draws(true);
+    assert_eq!(board.get_outcome(), Some(Outcome::FivefoldRepetition));
+}
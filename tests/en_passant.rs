@@ -0,0 +1,49 @@
+mod common;
+
+use chess::{board::Board, color::Color, kind::Kind};
+
+use common::{click, get_piece, play_squares, square};
+
+/// The moves bringing a white pawn to e5 next to the black pawn pushed to d5.
+const DOUBLE_PUSH: &str = "e2e4 a7a6 e4e5 d7d5";
+
+#[test]
+fn en_passant_removes_the_pawn_that_moved_two_squares() {
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, DOUBLE_PUSH);
+    assert!(board.get_en_passant() == Some(square("d6")));
+
+    play_squares(&mut board, "e5d6");
+
+    assert!(get_piece(&board, "d6") == Some((Kind::Pawn, Color::White)));
+    assert!(get_piece(&board, "d5").is_none());
+    assert!(get_piece(&board, "e5").is_none());
+    assert_eq!(board.get_white_score(), 1);
+    assert_eq!(board.get_black_score(), 0);
+    assert!(board.get_en_passant().is_none());
+}
+
+#[test]
+fn black_can_capture_en_passant_too() {
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, "a2a3 d7d5 a3a4 d5d4 e2e4 d4e3");
+
+    assert!(get_piece(&board, "e3") == Some((Kind::Pawn, Color::Black)));
+    assert!(get_piece(&board, "e4").is_none());
+    assert_eq!(board.get_black_score(), 1);
+}
+
+#[test]
+fn en_passant_is_only_offered_on_the_next_move() {
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, DOUBLE_PUSH);
+    play_squares(&mut board, "a2a3 a6a5");
+    assert!(board.get_en_passant().is_none());
+
+    click(&mut board, "e5");
+    click(&mut board, "d6");
+
+    assert!(get_piece(&board, "e5") == Some((Kind::Pawn, Color::White)));
+    assert!(get_piece(&board, "d5") == Some((Kind::Pawn, Color::Black)));
+    assert_eq!(board.get_white_score(), 0);
+}
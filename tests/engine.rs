@@ -0,0 +1,76 @@
+use chess::{
+    board::Board,
+    engine::{Engine, Score, SearchInfo, SliceResult},
+};
+
+/// Searches an iteration slice after slice until it is complete.
+///
+/// # Arguments
+///
+/// * `engine` - The engine searching.
+/// * `board` - The game to search.
+/// * `depth` - The depth of the iteration.
+/// * `nodes` - The number of nodes of each slice.
+///
+/// # Returns
+///
+/// The result of the iteration and the number of slices it took.
+fn search_in_slices(
+    engine: &mut Engine,
+    board: &Board,
+    depth: u32,
+    nodes: u64,
+) -> (Option<SearchInfo>, u32) {
+    let mut slices: u32 = 1;
+    loop {
+        match engine.search_slice(board, depth, nodes) {
+            SliceResult::Unfinished => slices += 1,
+            SliceResult::Finished(info) => return (info, slices),
+        }
+    }
+}
+
+#[test]
+fn iterations_searched_in_slices_match_whole_ones() {
+    let board: Board =
+        Board::from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3")
+            .unwrap();
+    let whole: SearchInfo = Engine::new().search_depth(&board, 3).unwrap();
+
+    let (sliced, slices): (Option<SearchInfo>, u32) =
+        search_in_slices(&mut Engine::new(), &board, 3, 1_000);
+    let sliced: SearchInfo = sliced.unwrap();
+    assert!(slices > 1);
+    assert_eq!(sliced.get_best_move(), whole.get_best_move());
+    assert_eq!(sliced.get_score(), whole.get_score());
+    assert!(sliced.get_nodes() >= 1_000);
+}
+
+#[test]
+fn tiny_slices_still_end_the_iteration() {
+    let board: Board = Board::from_fen("6k1/5ppp/8/8/8/8/5PPP/R5K1 w - - 0 1").unwrap();
+    let (info, slices): (Option<SearchInfo>, u32) =
+        search_in_slices(&mut Engine::new(), &board, 3, 1);
+
+    let info: SearchInfo = info.unwrap();
+    assert!(slices > 1);
+    assert_eq!(info.get_score(), Score::Mate(1));
+    assert_eq!(info.get_best_move(), board.parse_san("Ra8#").ok());
+}
+
+#[test]
+fn slices_of_another_position_start_over() {
+    let mut engine: Engine = Engine::new();
+    let board: Board = Board::new().initialize();
+    assert_eq!(engine.search_slice(&board, 5, 1), SliceResult::Unfinished);
+
+    let stalemate: Board = Board::from_fen("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").unwrap();
+    assert_eq!(
+        engine.search_slice(&stalemate, 5, 1),
+        SliceResult::Finished(None)
+    );
+    assert!(matches!(
+        engine.search_slice(&board, 1, 1_000_000),
+        SliceResult::Finished(Some(_))
+    ));
+}
@@ -0,0 +1,136 @@
+mod common;
+
+use chess::{
+    board::Board,
+    color::Color,
+    fen::{FenError, STARTING_FEN},
+};
+
+use common::play_squares;
+
+/// Reads a FEN which is expected to be rejected.
+///
+/// # Arguments
+///
+/// * `fen` - The FEN to read.
+///
+/// # Returns
+///
+/// The error describing what is wrong with the FEN.
+fn read_error(fen: &str) -> FenError {
+    match Board::from_fen(fen) {
+        Ok(_) => panic!("{} should be rejected", fen),
+        Err(error) => error,
+    }
+}
+
+#[test]
+fn fen_follows_the_game() {
+    let mut board: Board = Board::from_fen(STARTING_FEN).unwrap();
+    assert_eq!(board.to_fen(), Board::new().initialize().to_fen());
+
+    play_squares(&mut board, "e2e4");
+    assert_eq!(
+        board.to_fen(),
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+    );
+    play_squares(&mut board, "c7c5 g1f3 b8c6 e1e2");
+    let fen: String = board.to_fen();
+    assert_eq!(
+        fen,
+        "r1bqkbnr/pp1ppppp/2n5/2p5/4P3/5N2/PPPPKPPP/RNBQ1B1R b kq - 3 3"
+    );
+    assert_eq!(Board::from_fen(&fen).unwrap().to_fen(), fen);
+}
+
+#[test]
+fn malformed_fields_are_reported() {
+    assert_eq!(
+        read_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -"),
+        FenError::WrongFieldCount(4)
+    );
+    assert_eq!(
+        read_error("rnbqkbnr/pppppppp/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"),
+        FenError::WrongRankCount(7)
+    );
+    assert_eq!(
+        read_error("rnbqkbnr/pppppppp/8/8/7/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"),
+        FenError::WrongRankLength { rank: 4, length: 7 }
+    );
+    assert_eq!(
+        read_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNRR w KQkq - 0 1"),
+        FenError::WrongRankLength { rank: 1, length: 9 }
+    );
+    assert_eq!(
+        read_error("rnbqkbnr/pppppppp/8/8/3X4/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"),
+        FenError::UnknownPiece('X')
+    );
+    assert_eq!(
+        read_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR x KQkq - 0 1"),
+        FenError::InvalidSideToMove("x".to_string())
+    );
+    assert_eq!(
+        read_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkx - 0 1"),
+        FenError::InvalidCastling("KQkx".to_string())
+    );
+    assert_eq!(
+        read_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq e9 0 1"),
+        FenError::InvalidEnPassant("e9".to_string())
+    );
+    assert_eq!(
+        read_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - x 1"),
+        FenError::InvalidHalfmoveClock("x".to_string())
+    );
+    assert_eq!(
+        read_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 0"),
+        FenError::InvalidFullmoveNumber("0".to_string())
+    );
+}
+
+#[test]
+fn fields_must_match_the_pieces() {
+    assert_eq!(
+        read_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQ1BNR w kq - 0 1"),
+        FenError::MissingKing(Color::White)
+    );
+    assert_eq!(
+        read_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNk w KQ - 0 1"),
+        FenError::TooManyKings(Color::Black)
+    );
+    assert_eq!(
+        read_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNp w Qkq - 0 1"),
+        FenError::PawnOnBackRank
+    );
+    assert_eq!(
+        read_error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBN1 w KQkq - 0 1"),
+        FenError::InvalidCastling("KQkq".to_string())
+    );
+    assert_eq!(
+        read_error("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq d3 0 1"),
+        FenError::InvalidEnPassant("d3".to_string())
+    );
+    assert_eq!(
+        read_error("rnbqkbnr/pppppppp/8/8/4P3/8/PPPPBPPP/RNBQK1NR b KQkq e3 0 1"),
+        FenError::InvalidEnPassant("e3".to_string())
+    );
+    assert_eq!(
+        read_error("r1bqkbnr/pppnpppp/8/3p4/8/8/PPPPPPPP/RNBQKBNR w KQkq d6 0 1"),
+        FenError::InvalidEnPassant("d6".to_string())
+    );
+    assert_eq!(
+        read_error("4k3/8/8/8/8/8/8/4R2K w - - 0 1"),
+        FenError::OpponentInCheck
+    );
+}
+
+#[test]
+fn errors_describe_the_problem() {
+    assert_eq!(
+        FenError::WrongRankLength { rank: 4, length: 7 }.to_string(),
+        "rank 4 describes 7 squares instead of 8"
+    );
+    assert_eq!(
+        FenError::MissingKing(Color::White).to_string(),
+        "white has no king"
+    );
+}
@@ -0,0 +1,28 @@
+#!/bin/sh
+# A scripted UCI engine answering every search with the same lines, and echoing
+# the last position it was given so tests can check it.
+while read -r line; do
+    case "$line" in
+        uci)
+            echo "id name Fake Engine 1.0"
+            echo "id author Nobody"
+            echo "option name Hash type spin default 1 min 1 max 16"
+            echo "uciok"
+            ;;
+        isready)
+            echo "readyok"
+            ;;
+        position*)
+            position="$line"
+            ;;
+        go*)
+            echo "info string $position"
+            echo "info depth 1 score cp 13 nodes 20 pv e2e4"
+            echo "info depth 2 seldepth 4 multipv 1 score mate -2 upperbound nodes 400 nps 8000 time 50 pv e2e4 e7e5 g1f3"
+            echo "bestmove e2e4 ponder e7e5"
+            ;;
+        quit)
+            exit 0
+            ;;
+    esac
+done
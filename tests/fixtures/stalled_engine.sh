@@ -0,0 +1,25 @@
+#!/bin/sh
+# A scripted UCI engine that never ends a search by itself. It answers `stop` only
+# when started with the argument `stop`.
+while read -r line; do
+    case "$line" in
+        uci)
+            echo "id name Stalled Engine"
+            echo "uciok"
+            ;;
+        isready)
+            echo "readyok"
+            ;;
+        go*)
+            echo "info depth 1 score cp 5 pv d2d4"
+            ;;
+        stop)
+            if [ "$1" = "stop" ]; then
+                echo "bestmove d2d4"
+            fi
+            ;;
+        quit)
+            exit 0
+            ;;
+    esac
+done
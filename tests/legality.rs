@@ -0,0 +1,71 @@
+mod common;
+
+use chess::{board::Board, color::Color, kind::Kind};
+
+use common::{click, get_piece, play_squares};
+
+/// Tries to play a move that must be refused, and checks the piece has not moved.
+///
+/// # Arguments
+///
+/// * `board` - The board to play on.
+/// * `squares` - The move written as its two squares, such as `e2e4`.
+fn assert_refused(board: &mut Board, squares: &str) {
+    let piece: Option<(Kind, Color)> = get_piece(board, &squares[..2]);
+    click(board, &squares[..2]);
+    click(board, &squares[2..4]);
+    assert!(
+        get_piece(board, &squares[..2]) == piece,
+        "{} has been played",
+        squares
+    );
+}
+
+#[test]
+fn pinned_pieces_cannot_leave_the_line_of_the_pin() {
+    // The bishop on b5 pins the knight on c6 to the black king.
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, "e2e4 e7e5 g1f3 b8c6 f1b5 d7d6 d2d4");
+
+    for squares in ["c6a5", "c6b4", "c6d4", "c6e7", "c6b8"] {
+        assert_refused(&mut board, squares);
+    }
+    play_squares(&mut board, "c8d7");
+}
+
+#[test]
+fn a_king_in_check_must_get_out_of_it() {
+    // The queen on h5 checks the king along the diagonal, only g6 blocks it.
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, "e2e4 f7f5 d1h5");
+
+    for squares in ["a7a6", "g8f6", "e8f7", "f5e4"] {
+        assert_refused(&mut board, squares);
+    }
+    play_squares(&mut board, "g7g6");
+}
+
+#[test]
+fn kings_cannot_step_into_check() {
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, "e2e4 e7e5 e1e2 d8h4 e2e3 h4g5");
+
+    assert_refused(&mut board, "e3f4");
+    assert_refused(&mut board, "e3d4");
+    play_squares(&mut board, "e3d3");
+}
+
+#[test]
+fn en_passant_cannot_expose_the_king_along_the_rank() {
+    // Once both pawns leave the fifth rank, the rook on b5 would check the king on h5.
+    let mut board: Board = Board::new().initialize();
+    play_squares(
+        &mut board,
+        "e2e4 a7a5 e4e5 a8a6 e1e2 a6b6 e2f3 b6b5 f3g4 b8c6 g4h5 d7d5",
+    );
+    assert!(board.get_en_passant().is_some());
+
+    assert_refused(&mut board, "e5d6");
+    assert!(get_piece(&board, "d5") == Some((Kind::Pawn, Color::Black)));
+    play_squares(&mut board, "e5e6");
+}
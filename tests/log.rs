@@ -0,0 +1,40 @@
+mod common;
+
+use std::sync::Mutex;
+
+use chess::{
+    board::Board,
+    log::{clear_log_hook, set_log_hook},
+};
+
+use common::play;
+
+/// The messages received by the hook.
+static MESSAGES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Keeps a message logged by the game.
+///
+/// # Arguments
+///
+/// * `message` - The message logged.
+fn record(message: &str) {
+    MESSAGES.lock().unwrap().push(message.to_string());
+}
+
+#[test]
+fn messages_go_to_the_hook_until_it_is_cleared() {
+    let mut board: Board = Board::new().initialize();
+    set_log_hook(record);
+    play(&mut board, &["f3", "e5", "g4", "Qh4#"]);
+    let count: usize = MESSAGES.lock().unwrap().len();
+    assert!(MESSAGES
+        .lock()
+        .unwrap()
+        .contains(&"King is in check mate".to_string()));
+    assert!(MESSAGES.lock().unwrap().contains(&"Next turn".to_string()));
+
+    clear_log_hook();
+    let mut board: Board = Board::new().initialize();
+    play(&mut board, &["e4"]);
+    assert_eq!(MESSAGES.lock().unwrap().len(), count);
+}
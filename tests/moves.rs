@@ -0,0 +1,85 @@
+mod common;
+
+use chess::{
+    board::Board,
+    moves::{Move, MoveError},
+};
+
+use common::{play, square};
+
+#[test]
+fn legal_moves_are_listed_by_board_and_by_square() {
+    let board: Board = Board::new().initialize();
+    assert_eq!(board.legal_moves().len(), 20);
+    assert_eq!(board.legal_moves_from(square("g1")).len(), 2);
+    assert!(board.legal_moves_from(square("e1")).is_empty());
+    assert!(board.legal_moves_from(square("e7")).is_empty());
+}
+
+#[test]
+fn legal_moves_are_played() {
+    let mut board: Board = Board::new().initialize();
+    let chess_move: Move = board.get_move(square("e2"), square("e4"), None);
+    assert!(board.is_legal(chess_move));
+    assert!(!chess_move.is_capture());
+
+    assert_eq!(board.make_move(chess_move), Ok(()));
+    assert_eq!(board.get_notations(), ["e4"]);
+    assert_eq!(
+        board.to_fen(),
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+    );
+}
+
+#[test]
+fn illegal_moves_are_refused() {
+    let mut board: Board = Board::new().initialize();
+    for (from, to) in [("e2", "e5"), ("e7", "e5"), ("e1", "e2")] {
+        let chess_move: Move = board.get_move(square(from), square(to), None);
+        assert!(!board.is_legal(chess_move));
+        assert_eq!(
+            board.make_move(chess_move),
+            Err(MoveError::Illegal(chess_move))
+        );
+    }
+    assert_eq!(board.to_fen(), Board::new().initialize().to_fen());
+    assert!(board.get_notations().is_empty());
+}
+
+#[test]
+fn moves_are_refused_once_the_game_is_over() {
+    let mut board: Board = Board::new().initialize();
+    play(&mut board, &["f3", "e5", "g4", "Qh4#"]);
+
+    let chess_move: Move = board.get_move(square("a2"), square("a3"), None);
+    assert_eq!(board.make_move(chess_move), Err(MoveError::GameOver));
+    assert_eq!(board.get_notations().len(), 4);
+}
+
+#[test]
+fn moves_are_refused_while_a_promotion_is_pending() {
+    let mut board: Board = Board::from_fen("8/4P2k/8/8/8/8/8/K7 w - - 0 1").unwrap();
+    board.handle_click(*board.get_cell(square("e7")));
+    board.handle_click(*board.get_cell(square("e8")));
+
+    let chess_move: Move = board.get_move(square("a1"), square("a2"), None);
+    assert_eq!(
+        board.make_move(chess_move),
+        Err(MoveError::PromotionPending)
+    );
+}
+
+#[test]
+fn errors_describe_the_problem() {
+    let board: Board = Board::new().initialize();
+    let chess_move: Move = board.get_move(square("e2"), square("e5"), None);
+    assert_eq!(
+        MoveError::Illegal(chess_move).to_string(),
+        "e2e5 is not a legal move"
+    );
+    assert_eq!(MoveError::GameOver.to_string(), "the game has ended");
+    assert_eq!(
+        MoveError::PromotionPending.to_string(),
+        "a pawn is waiting for its promotion"
+    );
+}
@@ -0,0 +1,82 @@
+use chess::{board::Board, fen::STARTING_FEN, moves::Move};
+
+/// The reference positions of the Chess Programming Wiki with their node counts
+/// from depth 1 onwards.
+const POSITIONS: [(&str, &[u64]); 7] = [
+    (STARTING_FEN, &[20, 400, 8902, 197281]),
+    (
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        &[48, 2039, 97862],
+    ),
+    (
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        &[14, 191, 2812, 43238],
+    ),
+    (
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        &[6, 264, 9467],
+    ),
+    (
+        "r2q1rk1/pP1p2pp/Q4n2/bbp1p3/Np6/1B3NBn/pPPP1PPP/R3K2R b KQ - 0 1",
+        &[6, 264, 9467],
+    ),
+    (
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        &[44, 1486, 62379],
+    ),
+    (
+        "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10",
+        &[46, 2079, 89890],
+    ),
+];
+
+#[test]
+fn perft_matches_reference_positions() {
+    for (fen, expected) in POSITIONS {
+        let board: Board = Board::from_fen(fen).unwrap();
+        for (index, nodes) in expected.iter().enumerate() {
+            let depth: u32 = index as u32 + 1;
+            assert_eq!(board.perft(depth), *nodes, "depth {} of {}", depth, fen);
+        }
+    }
+}
+
+#[test]
+fn reference_positions_round_trip_through_fen() {
+    for (fen, _) in POSITIONS {
+        let board: Board = Board::from_fen(fen).unwrap();
+        assert_eq!(board.to_fen(), fen);
+        assert_eq!(board.get_initial_fen(), fen);
+    }
+}
+
+#[test]
+#[ignore = "slow, run with cargo test --release -- --ignored"]
+fn perft_matches_kiwipete_at_depth_four() {
+    let board: Board = Board::from_fen(POSITIONS[1].0).unwrap();
+    assert_eq!(board.perft(4), 4085603);
+}
+
+#[test]
+fn divide_splits_perft_by_root_move() {
+    let board: Board = Board::from_fen(POSITIONS[1].0).unwrap();
+    let moves: Vec<(Move, u64)> = board.divide(2);
+
+    assert_eq!(moves.len(), 48);
+    assert_eq!(moves.iter().map(|(_, nodes)| nodes).sum::<u64>(), 2039);
+
+    let castling: Vec<&(Move, u64)> = moves
+        .iter()
+        .filter(|(chess_move, _)| chess_move.is_castling())
+        .collect();
+    assert_eq!(castling.len(), 2);
+    assert!(castling.iter().all(|(_, nodes)| *nodes == 43));
+}
+
+#[test]
+fn perft_counts_each_promotion_piece() {
+    let board: Board = Board::from_fen("n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1").unwrap();
+
+    assert_eq!(board.perft(1), 24);
+    assert_eq!(board.perft(2), 496);
+}
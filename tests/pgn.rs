@@ -0,0 +1,294 @@
+mod common;
+
+use chess::{
+    board::Board,
+    color::Color,
+    fen::FenError,
+    outcome::Outcome,
+    pgn::{parse_pgn, PgnError, PgnGame, PgnMove},
+    san::SanError,
+};
+
+use common::play;
+
+/// The moves of Morphy's Opera Game, won by checkmate.
+const OPERA_GAME: [&str; 33] = [
+    "e4", "e5", "Nf3", "d6", "d4", "Bg4", "dxe5", "Bxf3", "Qxf3", "dxe5", "Bc4", "Nf6", "Qb3",
+    "Qe7", "Nc3", "c6", "Bg5", "b5", "Nxb5", "cxb5", "Bxb5+", "Nbd7", "O-O-O", "Rd8", "Rxd7",
+    "Rxd7", "Rd1", "Qe6", "Bxd7+", "Nxd7", "Qb8+", "Nxb8", "Rd8#",
+];
+
+#[test]
+fn exports_start_with_the_seven_tag_roster() {
+    let mut board: Board = Board::new().initialize();
+    board.set_pgn_tag("Annotator", "Club");
+    board.set_pgn_tag("White", "Alice");
+    board.set_pgn_tag("Event", "Club night");
+    play(&mut board, &["e4", "e5"]);
+
+    assert_eq!(
+        board.to_pgn(),
+        "[Event \"Club night\"]\n\
+         [Site \"?\"]\n\
+         [Date \"????.??.??\"]\n\
+         [Round \"?\"]\n\
+         [White \"Alice\"]\n\
+         [Black \"?\"]\n\
+         [Result \"*\"]\n\
+         [Annotator \"Club\"]\n\
+         \n\
+         1. e4 e5 *\n"
+    );
+}
+
+#[test]
+fn games_from_a_position_number_their_moves_from_it() {
+    let fen: &str = "4k3/8/8/8/8/8/4P3/4K3 b - - 0 12";
+    let mut board: Board = Board::from_fen(fen).unwrap();
+    play(&mut board, &["Kd7", "e4", "Ke6"]);
+
+    let pgn: String = board.to_pgn();
+    assert!(pgn.contains("[SetUp \"1\"]\n[FEN \"4k3/8/8/8/8/8/4P3/4K3 b - - 0 12\"]\n"));
+    assert!(pgn.ends_with("\n\n12... Kd7 13. e4 Ke6 *\n"));
+}
+
+#[test]
+fn movetext_is_wrapped_before_eighty_columns() {
+    let mut board: Board = Board::new().initialize();
+    play(&mut board, &OPERA_GAME);
+
+    let pgn: String = board.to_pgn();
+    let movetext: &str = pgn.split("\n\n").nth(1).unwrap();
+    assert_eq!(
+        movetext,
+        "1. e4 e5 2. Nf3 d6 3. d4 Bg4 4. dxe5 Bxf3 5. Qxf3 dxe5 6. Bc4 Nf6 7. Qb3 Qe7 8.\n\
+         Nc3 c6 9. Bg5 b5 10. Nxb5 cxb5 11. Bxb5+ Nbd7 12. O-O-O Rd8 13. Rxd7 Rxd7 14.\n\
+         Rd1 Qe6 15. Bxd7+ Nxd7 16. Qb8+ Nxb8 17. Rd8# 1-0\n"
+    );
+    assert!(movetext.lines().all(|line| line.len() < 80));
+}
+
+/// Reads a PGN text holding a single game.
+///
+/// # Arguments
+///
+/// * `text` - The PGN text.
+///
+/// # Returns
+///
+/// The game read from the text.
+fn read_game(text: &str) -> PgnGame {
+    let mut games: Vec<PgnGame> = parse_pgn(text).unwrap();
+    assert_eq!(games.len(), 1);
+    games.remove(0)
+}
+
+/// Reads a malformed PGN text.
+///
+/// # Arguments
+///
+/// * `text` - The PGN text.
+///
+/// # Returns
+///
+/// The line and the message of the syntax error.
+fn read_syntax_error(text: &str) -> (usize, String) {
+    match parse_pgn(text) {
+        Err(PgnError::Syntax { line, message }) => (line, message),
+        _ => panic!("{:?} should be a syntax error", text),
+    }
+}
+
+#[test]
+fn comments_and_nags_are_attached_to_their_moves() {
+    let game: PgnGame = read_game(
+        "{Played at the club} 1. e4 {best by test} e5 $1 2. Nf3!? ; a quiet move\n\
+         Nc6?? 3. Bb5 $14 $32 *",
+    );
+    let moves: &[PgnMove] = game.get_moves();
+
+    assert_eq!(game.get_comment(), Some("Played at the club"));
+    assert_eq!(moves.len(), 5);
+    assert_eq!(moves[0].get_san(), "e4");
+    assert_eq!(moves[0].get_comments(), ["best by test"]);
+    assert_eq!(moves[1].get_nags(), [1]);
+    assert_eq!(moves[2].get_san(), "Nf3");
+    assert_eq!(moves[2].get_nags(), [5]);
+    assert_eq!(moves[2].get_comments(), ["a quiet move"]);
+    assert_eq!(moves[3].get_nags(), [4]);
+    assert_eq!(moves[4].get_nags(), [14, 32]);
+    assert_eq!(game.get_result(), "*");
+
+    let game: PgnGame = read_game("{Intro} {Note} 1. e4 *");
+    assert_eq!(game.get_comment(), Some("Intro"));
+    assert_eq!(game.get_moves()[0].get_comments(), ["Note"]);
+    assert_eq!(read_game("{Intro} {Note} *").get_comment(), Some("Intro"));
+}
+
+#[test]
+fn nested_variations_are_kept_and_replayed() {
+    let game: PgnGame =
+        read_game("1. e4 e5 (1... c5 2. Nf3 (2. Nc3 {closed} Nc6) 2... d6) (1... e6) 2. Nf3 1-0");
+    let moves: &[PgnMove] = game.get_moves();
+    assert_eq!(moves.len(), 3);
+    assert_eq!(moves[1].get_variations().len(), 2);
+
+    let sicilian: &[PgnMove] = &moves[1].get_variations()[0];
+    assert_eq!(
+        sicilian.iter().map(PgnMove::get_san).collect::<Vec<&str>>(),
+        ["c5", "Nf3", "d6"]
+    );
+    let closed: &[PgnMove] = &sicilian[1].get_variations()[0];
+    assert_eq!(closed[0].get_san(), "Nc3");
+    assert_eq!(closed[0].get_comments(), ["closed"]);
+    assert_eq!(moves[1].get_variations()[1][0].get_san(), "e6");
+
+    assert_eq!(game.replay().unwrap().get_notations(), ["e4", "e5", "Nf3"]);
+    assert_eq!(game.get_board_at(1).unwrap().get_notations(), ["e4"]);
+
+    let game: PgnGame = read_game("1. e4 e5 (1... c5 2. Nf3 (2. Ke2 Ke7) d6) *");
+    assert_eq!(
+        game.replay().err(),
+        Some(PgnError::IllegalMove {
+            ply: 4,
+            san: "Ke7".to_string(),
+            reason: SanError::Illegal("Ke7".to_string()),
+        })
+    );
+}
+
+#[test]
+fn several_games_are_read_in_order() {
+    let games: Vec<PgnGame> = parse_pgn(
+        "% exported by hand\n\
+         [Event \"First\"]\n\
+         [White \"Alice \\\"the fox\\\"\"]\n\
+         [Black \"Bob \\\"]\\\" [B]\"]\n\
+         \n\
+         1. e4 e5 1-0\n\
+         \n\
+         [Event \"Second\"]\n\
+         [FEN \"4k3/8/8/8/8/8/4P3/4K3 b - - 0 12\"]\n\
+         \n\
+         12... Kd7 0-1\n",
+    )
+    .unwrap();
+
+    assert_eq!(games.len(), 2);
+    assert_eq!(games[0].get_tags().get("Event"), Some("First"));
+    assert_eq!(games[0].get_tags().get("White"), Some("Alice \"the fox\""));
+    assert_eq!(games[0].get_tags().get("Black"), Some("Bob \"]\" [B]"));
+    assert_eq!(games[0].get_result(), "1-0");
+    assert_eq!(games[1].get_tags().get("Event"), Some("Second"));
+    assert_eq!(games[1].get_result(), "0-1");
+    assert_eq!(
+        games[1].replay().unwrap().to_fen(),
+        "8/3k4/8/8/8/8/4P3/4K3 w - - 1 13"
+    );
+
+    let game: PgnGame = read_game("[Event \"Cup [A]\"]\n\n1. e4 *");
+    assert_eq!(game.get_tags().get("Event"), Some("Cup [A]"));
+}
+
+#[test]
+fn games_without_a_result_end_at_the_next_tag_section() {
+    let games: Vec<PgnGame> = parse_pgn(
+        "[Event \"First\"]\n\
+         [Result \"1-0\"]\n\
+         \n\
+         1. e4 e5 2. Qh5\n\
+         \n\
+         [Event \"Second\"]\n\
+         \n\
+         1. d4 d5 1/2-1/2\n",
+    )
+    .unwrap();
+
+    assert_eq!(games.len(), 2);
+    assert_eq!(games[0].get_tags().get("Event"), Some("First"));
+    assert_eq!(games[0].get_moves().len(), 3);
+    assert_eq!(games[0].get_result(), "*");
+    assert_eq!(games[1].get_tags().get("Event"), Some("Second"));
+    assert_eq!(games[1].replay().unwrap().get_notations(), ["d4", "d5"]);
+    assert_eq!(games[1].get_result(), "1/2-1/2");
+}
+
+#[test]
+fn malformed_pgn_is_reported_with_its_line() {
+    assert_eq!(
+        read_syntax_error("1. e4 {never closed\n e5 *"),
+        (1, "unterminated comment".to_string())
+    );
+    assert_eq!(
+        read_syntax_error("1. e4 e5\n2. Nf3) *"),
+        (2, "unmatched closing parenthesis".to_string())
+    );
+    assert_eq!(
+        read_syntax_error("1. e4 (1. d4 *"),
+        (1, "unterminated variation".to_string())
+    );
+    assert_eq!(
+        read_syntax_error("1. e4 (1. d4\n[Event \"Late\"]\n1. e4 *"),
+        (2, "unterminated variation".to_string())
+    );
+    assert_eq!(
+        read_syntax_error("[Event Club]\n1. e4 *"),
+        (1, "malformed tag pair".to_string())
+    );
+    assert_eq!(
+        read_syntax_error("[Event \"Club\"\n[Site \"Paris\"]\n\n1. e4 *"),
+        (1, "unterminated tag pair".to_string())
+    );
+    assert_eq!(
+        read_syntax_error("$1 1. e4 *"),
+        (1, "NAG without a move".to_string())
+    );
+    assert_eq!(
+        read_syntax_error("1. e4 @@ *"),
+        (1, "unexpected token '@@'".to_string())
+    );
+
+    let game: PgnGame = read_game("[FEN \"8/8/8/8/8/8/8/8 w - - 0 1\"]\n*");
+    assert_eq!(
+        game.replay().err(),
+        Some(PgnError::InvalidFen(FenError::MissingKing(Color::White)))
+    );
+}
+
+#[test]
+fn exported_games_are_read_back_unchanged() {
+    let mut board: Board = Board::new().initialize();
+    board.set_pgn_tag("Event", "Paris Opera");
+    board.set_pgn_tag("White", "Morphy, Paul");
+    board.set_pgn_tag("Black", "Duke Karl / Count Isouard");
+    board.set_pgn_tag("Annotator", "Club");
+    play(&mut board, &OPERA_GAME);
+    let pgn: String = board.to_pgn();
+
+    let game: PgnGame = read_game(&pgn);
+    assert_eq!(game.get_result(), "1-0");
+    assert_eq!(game.get_moves().len(), OPERA_GAME.len());
+    assert_eq!(game.replay().unwrap().to_pgn(), pgn);
+
+    let mut board: Board = Board::from_fen("4k3/8/8/8/8/8/4P3/4K3 b - - 0 12").unwrap();
+    play(&mut board, &["Kd7", "e4"]);
+    let pgn: String = board.to_pgn();
+    assert_eq!(read_game(&pgn).replay().unwrap().to_pgn(), pgn);
+}
+
+#[test]
+fn games_played_on_past_a_fivefold_repetition_are_imported() {
+    // The starting position occurs for the fifth time after 8... Ng8.
+    let pgn: &str = "1. Nf3 Nf6 2. Ng1 Ng8 3. Nf3 Nf6 4. Ng1 Ng8 5. Nf3 Nf6 6. Ng1 Ng8\n\
+                     7. Nf3 Nf6 8. Ng1 Ng8 9. e4 e5 *\n";
+    let game: PgnGame = read_game(pgn);
+    assert_eq!(game.get_moves().len(), 18);
+
+    let board: Board = game.replay().unwrap();
+    assert!(!board.is_end());
+    assert!(board.get_automatic_draws());
+    assert_eq!(board.get_notations().len(), 18);
+
+    let board: Board = game.get_board_at(16).unwrap();
+    assert_eq!(board.get_outcome(), Some(Outcome::FivefoldRepetition));
+}
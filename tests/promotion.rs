@@ -0,0 +1,108 @@
+mod common;
+
+use chess::{
+    board::Board,
+    color::Color,
+    kind::Kind,
+    moves::{Move, MoveError},
+};
+
+use common::{click, get_piece, play_squares, square};
+
+/// The moves bringing a white pawn to b7, next to the black rook on a8.
+const PAWN_ON_B7: &str = "a2a4 b7b5 a4b5 a7a6 b5a6 c8b7 a6b7 b8c6";
+
+#[test]
+fn the_player_chooses_the_promotion_piece() {
+    for (kind, symbol) in [
+        (Kind::Knight, "=N"),
+        (Kind::Bishop, "=B"),
+        (Kind::Rook, "=R"),
+        (Kind::Queen, "=Q"),
+    ] {
+        let mut board: Board = Board::new().initialize();
+        play_squares(&mut board, PAWN_ON_B7);
+        play_squares(&mut board, "b7a8");
+        assert!(board.get_pending_promotion() == Some(square("a8")));
+        assert!(get_piece(&board, "a8") == Some((Kind::Pawn, Color::White)));
+
+        board.promote(kind);
+
+        assert!(board.get_pending_promotion().is_none());
+        assert!(get_piece(&board, "a8") == Some((kind, Color::White)));
+        assert!(board.get_notations().last().unwrap().ends_with(symbol));
+        assert_eq!(board.get_white_score(), 10);
+    }
+}
+
+#[test]
+fn moves_wait_for_the_promotion_piece() {
+    let mut board: Board = Board::new().initialize();
+    play_squares(&mut board, PAWN_ON_B7);
+    play_squares(&mut board, "b7b8");
+
+    click(&mut board, "e7");
+    click(&mut board, "e5");
+    assert!(get_piece(&board, "e7") == Some((Kind::Pawn, Color::Black)));
+    assert!(board.get_pending_promotion() == Some(square("b8")));
+
+    board.promote(Kind::Knight);
+    play_squares(&mut board, "e7e5");
+    assert!(get_piece(&board, "b8") == Some((Kind::Knight, Color::White)));
+}
+
+#[test]
+fn auto_queen_promotes_without_asking() {
+    let mut board: Board = Board::new().initialize();
+    board.set_auto_queen(true);
+    assert!(board.get_auto_queen());
+    play_squares(&mut board, PAWN_ON_B7);
+    play_squares(&mut board, "b7b8");
+
+    assert!(board.get_pending_promotion().is_none());
+    assert!(get_piece(&board, "b8") == Some((Kind::Queen, Color::White)));
+    assert!(board.get_notations().last().unwrap().ends_with("=Q"));
+    play_squares(&mut board, "e7e5");
+}
+
+#[test]
+fn underpromotions_are_played_and_recorded() {
+    for (san, kind, fen) in [
+        ("e8=N", Kind::Knight, "4N3/7k/8/8/8/8/8/K7 b - - 0 1"),
+        ("e8=B", Kind::Bishop, "4B3/7k/8/8/8/8/8/K7 b - - 0 1"),
+        ("e8=R", Kind::Rook, "4R3/7k/8/8/8/8/8/K7 b - - 0 1"),
+    ] {
+        let mut board: Board = Board::from_fen("8/4P2k/8/8/8/8/8/K7 w - - 0 1").unwrap();
+        let chess_move: Move = board.parse_san(san).unwrap();
+        assert_eq!(chess_move.get_promotion(), Some(kind));
+        board.make_move(chess_move).unwrap();
+
+        assert_eq!(board.get_notations(), [san]);
+        assert_eq!(board.get_moves()[0].get_promotion(), Some(kind));
+        assert_eq!(board.get_cell(square("e8")).get_piece_kind(), kind);
+        assert_eq!(board.to_fen(), fen);
+    }
+}
+
+#[test]
+fn promotions_are_written_before_the_check() {
+    let mut board: Board = Board::from_fen("7k/4P3/8/8/8/8/8/K7 w - - 0 1").unwrap();
+    let chess_move: Move = board.get_move(square("e7"), square("e8"), Some(Kind::Rook));
+    board.make_move(chess_move).unwrap();
+    assert_eq!(board.get_notations(), ["e8=R+"]);
+}
+
+#[test]
+fn moves_are_refused_while_a_promotion_is_pending() {
+    let mut board: Board = Board::from_fen("8/4P2k/8/8/8/8/8/K7 w - - 0 1").unwrap();
+    click(&mut board, "e7");
+    click(&mut board, "e8");
+    assert_eq!(board.get_pending_promotion(), Some(square("e8")));
+    let king_move: Move = board.get_move(square("a1"), square("b1"), None);
+    assert_eq!(board.make_move(king_move), Err(MoveError::PromotionPending));
+
+    board.promote(Kind::Knight);
+    assert_eq!(board.get_pending_promotion(), None);
+    assert_eq!(board.get_notations(), ["e8=N"]);
+    assert_eq!(board.get_moves()[0].get_promotion(), Some(Kind::Knight));
+}
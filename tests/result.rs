@@ -0,0 +1,143 @@
+mod common;
+
+use chess::{
+    board::Board,
+    color::Color,
+    event::GameEvent,
+    outcome::{GameResult, Termination},
+    pgn::{parse_pgn, PgnGame},
+};
+
+use common::play;
+
+#[test]
+fn checkmate_gives_the_win_to_the_mating_side() {
+    let mut board: Board = Board::new().initialize();
+    assert_eq!(board.get_result(), GameResult::Ongoing);
+    assert_eq!(board.get_termination(), None);
+    assert!(!board.to_pgn().contains("Termination"));
+
+    play(&mut board, &["f3", "e5", "g4", "Qh4#"]);
+
+    assert_eq!(board.get_result(), GameResult::BlackWins);
+    assert_eq!(board.get_result().get_winner(), Some(Color::Black));
+    assert_eq!(board.get_termination(), Some(Termination::Checkmate));
+
+    let games: Vec<PgnGame> = parse_pgn(&board.to_pgn()).unwrap();
+    assert_eq!(games[0].get_result(), "0-1");
+    assert_eq!(games[0].get_tags().get("Termination"), Some("normal"));
+}
+
+#[test]
+fn draws_are_results_of_their_own() {
+    let mut board: Board = Board::from_fen("7k/8/6Q1/8/8/8/8/K7 w - - 0 1").unwrap();
+    play(&mut board, &["Qf7"]);
+
+    assert_eq!(board.get_result(), GameResult::Draw);
+    assert_eq!(board.get_result().to_string(), "1/2-1/2");
+    assert_eq!(board.get_termination(), Some(Termination::Stalemate));
+    assert!(board.to_pgn().contains("[Result \"1/2-1/2\"]"));
+}
+
+#[test]
+fn abandoned_games_are_won_by_the_opponent() {
+    let mut board: Board = Board::new().initialize();
+    play(&mut board, &["e4"]);
+    board.abandon(Color::Black);
+
+    assert_eq!(board.get_result(), GameResult::WhiteWins);
+    assert_eq!(board.get_termination(), Some(Termination::Abandonment));
+    assert_eq!(Termination::Abandonment.get_description(), "abandonment");
+
+    let games: Vec<PgnGame> = parse_pgn(&board.to_pgn()).unwrap();
+    assert_eq!(games[0].get_result(), "1-0");
+    assert_eq!(games[0].get_tags().get("Termination"), Some("abandoned"));
+}
+
+#[test]
+fn resigning_ends_the_game_with_a_comment() {
+    let mut board: Board = Board::new().initialize();
+    play(&mut board, &["e4"]);
+
+    assert!(board.resign(Color::Black));
+    assert!(!board.resign(Color::White));
+    assert_eq!(board.get_result(), GameResult::WhiteWins);
+    assert_eq!(board.get_termination(), Some(Termination::Resignation));
+    assert_eq!(board.get_events(), [(1, GameEvent::Resigned(Color::Black))]);
+
+    let games: Vec<PgnGame> = parse_pgn(&board.to_pgn()).unwrap();
+    assert_eq!(games[0].get_result(), "1-0");
+    assert_eq!(games[0].get_moves()[0].get_comments(), ["Black resigns"]);
+}
+
+#[test]
+fn accepted_draw_offers_end_the_game() {
+    let mut board: Board = Board::new().initialize();
+    play(&mut board, &["e4"]);
+
+    assert!(!board.accept_draw(Color::Black));
+    assert!(board.offer_draw(Color::White));
+    assert!(!board.offer_draw(Color::Black));
+    assert_eq!(board.get_draw_offer(), Some(Color::White));
+    assert!(board.accept_draw(Color::Black));
+
+    assert_eq!(board.get_result(), GameResult::Draw);
+    assert_eq!(board.get_termination(), Some(Termination::Agreement));
+    assert_eq!(board.get_draw_offer(), None);
+    assert_eq!(
+        board.get_events(),
+        [
+            (1, GameEvent::DrawOffered(Color::White)),
+            (1, GameEvent::DrawAccepted(Color::Black)),
+        ]
+    );
+    assert!(board
+        .to_pgn()
+        .contains("1. e4 {White offers a draw} {Black accepts the draw} 1/2-1/2"));
+}
+
+#[test]
+fn only_the_opponent_answers_a_draw_offer() {
+    let mut board: Board = Board::new().initialize();
+    play(&mut board, &["e4"]);
+    board.offer_draw(Color::White);
+
+    assert!(!board.accept_draw(Color::White));
+    assert!(!board.decline_draw(Color::White));
+    assert_eq!(board.get_draw_offer(), Some(Color::White));
+    assert_eq!(board.get_result(), GameResult::Ongoing);
+    assert_eq!(
+        board.get_events(),
+        [(1, GameEvent::DrawOffered(Color::White))]
+    );
+
+    assert!(board.decline_draw(Color::Black));
+    assert!(!board.accept_draw(Color::Black));
+    assert_eq!(board.get_result(), GameResult::Ongoing);
+}
+
+#[test]
+fn moving_declines_a_draw_offer() {
+    let mut board: Board = Board::new().initialize();
+    play(&mut board, &["e4"]);
+    board.offer_draw(Color::White);
+    play(&mut board, &["e5"]);
+
+    assert_eq!(board.get_draw_offer(), None);
+    assert_eq!(board.get_result(), GameResult::Ongoing);
+    assert!(board
+        .to_pgn()
+        .contains("1. e4 {White offers a draw} {Black declines the draw} 1... e5 *"));
+
+    assert!(board.undo());
+    assert_eq!(board.get_draw_offer(), Some(Color::White));
+    assert_eq!(
+        board.get_events(),
+        [(1, GameEvent::DrawOffered(Color::White))]
+    );
+
+    assert!(board.decline_draw(Color::Black));
+    assert!(board.offer_draw(Color::Black));
+    play(&mut board, &["e5"]);
+    assert_eq!(board.get_draw_offer(), Some(Color::Black));
+}
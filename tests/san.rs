@@ -0,0 +1,149 @@
+mod common;
+
+use chess::{
+    board::Board, kind::Kind, moves::Move, piece::Piece, position::Position, san::SanError,
+};
+
+use common::play;
+
+/// Reads a move given in UCI notation.
+///
+/// # Arguments
+///
+/// * `board` - The board the move is played on.
+/// * `uci` - The move in UCI notation, such as `b1d2` or `e7e8q`.
+///
+/// # Returns
+///
+/// The `Move` read.
+fn parse_uci(board: &Board, uci: &str) -> Move {
+    board.get_move(
+        Position::from_notation(&uci[..2]).unwrap(),
+        Position::from_notation(&uci[2..4]).unwrap(),
+        uci[4..]
+            .chars()
+            .next()
+            .map(|symbol| Piece::from_symbol(symbol).get_kind()),
+    )
+}
+
+/// Writes a move given in UCI notation as SAN.
+///
+/// # Arguments
+///
+/// * `fen` - The position the move is played from.
+/// * `uci` - The move in UCI notation, such as `b1d2`.
+///
+/// # Returns
+///
+/// The SAN of the move.
+fn get_san(fen: &str, uci: &str) -> String {
+    let board: Board = Board::from_fen(fen).unwrap();
+    board.get_san(parse_uci(&board, uci))
+}
+
+#[test]
+fn moves_are_disambiguated_by_file_rank_or_both() {
+    let knights: &str = "4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1";
+    assert_eq!(get_san(knights, "b1d2"), "Nbd2");
+    assert_eq!(get_san(knights, "f1d2"), "Nfd2");
+    assert_eq!(get_san(knights, "b1c3"), "Nc3");
+
+    let rooks: &str = "4k3/8/8/R7/8/8/8/R3K3 w - - 0 1";
+    assert_eq!(get_san(rooks, "a1a3"), "R1a3");
+    assert_eq!(get_san(rooks, "a5a3"), "R5a3");
+
+    let queens: &str = "4k3/8/8/8/8/Q7/8/Q1Q1K3 w - - 0 1";
+    assert_eq!(get_san(queens, "a1b2"), "Qa1b2");
+    assert_eq!(get_san(queens, "a3b2"), "Q3b2");
+    assert_eq!(get_san(queens, "c1b2"), "Qcb2");
+}
+
+#[test]
+fn special_moves_have_their_own_notation() {
+    let castling: &str = "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1";
+    assert_eq!(get_san(castling, "e1g1"), "O-O");
+    assert_eq!(get_san(castling, "e1c1"), "O-O-O");
+
+    let en_passant: &str = "4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1";
+    assert_eq!(get_san(en_passant, "e5d6"), "exd6");
+
+    let promotion: &str = "3r3k/4P3/8/8/8/8/8/K7 w - - 0 1";
+    assert_eq!(get_san(promotion, "e7e8q"), "e8=Q+");
+    assert_eq!(get_san(promotion, "e7d8n"), "exd8=N");
+    assert_eq!(get_san(promotion, "e7d8r"), "exd8=R+");
+}
+
+#[test]
+fn checks_and_checkmates_are_suffixed() {
+    let mut board: Board = Board::new().initialize();
+    play(&mut board, &["f3", "e5", "g4"]);
+    let checkmate: Move = board.parse_san("Qh4").unwrap();
+    assert_eq!(board.get_san(checkmate), "Qh4#");
+    play(&mut board, &["Qh4"]);
+    assert_eq!(board.get_notations(), ["f3", "e5", "g4", "Qh4#"]);
+
+    let rook: &str = "4k3/8/8/8/8/8/8/R3K3 w Q - 0 1";
+    assert_eq!(get_san(rook, "e1c1"), "O-O-O");
+    assert_eq!(get_san(rook, "a1a8"), "Ra8+");
+}
+
+#[test]
+fn san_is_read_leniently() {
+    let board: Board = Board::from_fen("r3k2r/1P6/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+    let castling: Move = board.parse_san("O-O").unwrap();
+    assert_eq!(board.parse_san("0-0").unwrap(), castling);
+    assert_eq!(board.parse_san("O-O+").unwrap(), castling);
+    assert_eq!(
+        board.parse_san("b8Q").unwrap(),
+        board.parse_san("b8=Q+").unwrap()
+    );
+    assert_eq!(
+        board.parse_san("bxa8=N!?").unwrap().get_promotion(),
+        Some(Kind::Knight)
+    );
+    assert_eq!(board.parse_san("Rxa8").unwrap(), parse_uci(&board, "a1a8"));
+}
+
+#[test]
+fn san_errors_are_reported() {
+    let board: Board = Board::new().initialize();
+    assert_eq!(
+        board.parse_san("e5"),
+        Err(SanError::Illegal("e5".to_string()))
+    );
+    assert_eq!(
+        board.parse_san("O-O"),
+        Err(SanError::Illegal("O-O".to_string()))
+    );
+    assert_eq!(
+        board.parse_san("Zf3"),
+        Err(SanError::Invalid("Zf3".to_string()))
+    );
+    assert_eq!(
+        board.parse_san("e9"),
+        Err(SanError::Invalid("e9".to_string()))
+    );
+    assert_eq!(
+        board.parse_san("N"),
+        Err(SanError::Invalid("N".to_string()))
+    );
+    assert_eq!(
+        board.parse_san("Nf3=Q"),
+        Err(SanError::Invalid("Nf3=Q".to_string()))
+    );
+
+    let knights: Board = Board::from_fen("4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1").unwrap();
+    assert_eq!(
+        knights.parse_san("Nd2"),
+        Err(SanError::Ambiguous("Nd2".to_string()))
+    );
+    assert_eq!(
+        knights.parse_san("Nbd2").unwrap(),
+        parse_uci(&knights, "b1d2")
+    );
+    assert_eq!(
+        SanError::Ambiguous("Nd2".to_string()).to_string(),
+        "'Nd2' matches several legal moves"
+    );
+}
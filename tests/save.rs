@@ -0,0 +1,252 @@
+mod common;
+
+use std::{sync::Arc, time::Duration};
+
+use chess::{
+    board::Board,
+    clock::{MockTimeSource, TimeControl},
+    color::Color,
+    outcome::Outcome,
+    pgn::{parse_pgn, PgnGame},
+    save::{GameLibrary, MemoryStore, SaveError, SavedGame},
+};
+
+use common::play;
+
+/// Saves a game and reads it back.
+///
+/// # Arguments
+///
+/// * `board` - The game.
+///
+/// # Returns
+///
+/// The board read back from the save.
+fn reload(board: &Board) -> Board {
+    let text: String = SavedGame::new("game", board.clone(), None, 3).serialize();
+    SavedGame::deserialize(&text).unwrap().get_board().clone()
+}
+
+#[test]
+fn games_are_restored_with_their_history_and_settings() {
+    let time: Arc<MockTimeSource> = Arc::new(MockTimeSource::new(0));
+    let mut board: Board = Board::new().initialize();
+    board.set_time_source(time.clone());
+    board.set_time_control(Some(TimeControl::parse("300+2").unwrap()));
+    board.set_auto_queen(true);
+    time.advance(4_000);
+    play(&mut board, &["e4"]);
+    board.offer_draw(Color::White);
+    time.advance(1_500);
+    play(&mut board, &["e5", "Nf3"]);
+
+    let text: String =
+        SavedGame::new("Club\nnight", board.clone(), Some(Color::Black), 4).serialize();
+    assert!(text.starts_with("chess-save 2\nname Club night\n"));
+
+    let saved: SavedGame = SavedGame::deserialize_with_time_source(&text, time.clone()).unwrap();
+    let restored: &Board = saved.get_board();
+    assert_eq!(saved.get_name(), "Club night");
+    assert_eq!(saved.get_computer(), Some(Color::Black));
+    assert_eq!(saved.get_level(), 4);
+    assert!(restored.get_auto_queen());
+    assert_eq!(restored.get_notations(), board.get_notations());
+    assert_eq!(restored.get_events(), board.get_events());
+    assert_eq!(restored.get_draw_offer(), None);
+    assert_eq!(restored.to_pgn(), board.to_pgn());
+    assert!(restored.is_clock_paused());
+    assert_eq!(
+        restored.get_remaining_time(Color::White),
+        Some(Duration::from_millis(300_000 - 4_000 + 2_000 + 2_000))
+    );
+    assert_eq!(
+        restored.get_remaining_time(Color::Black),
+        board.get_remaining_time(Color::Black)
+    );
+    assert_eq!(
+        restored.get_clock().unwrap().get_moves_to_go(Color::White),
+        None
+    );
+}
+
+#[test]
+fn time_spent_after_a_save_is_charged_to_the_player_to_move() {
+    let time: Arc<MockTimeSource> = Arc::new(MockTimeSource::new(1_000_000));
+    let mut board: Board = Board::new().initialize();
+    board.set_time_source(time.clone());
+    board.set_time_control(Some(TimeControl::parse("300").unwrap()));
+    time.advance(5_000);
+    play(&mut board, &["e4"]);
+
+    let text: String = SavedGame::new("game", board.clone(), None, 3).serialize();
+    assert!(text.contains("\nclock 300 295000 300000 1005000\n"));
+
+    let mut paused: Board = board.clone();
+    paused.pause_clock();
+    let paused_text: String = SavedGame::new("game", paused, None, 3).serialize();
+    assert!(paused_text.contains("\nclock 300 295000 300000\n"));
+
+    time.advance(60_000);
+    let saved: SavedGame = SavedGame::deserialize_with_time_source(&text, time.clone()).unwrap();
+    let restored: &Board = saved.get_board();
+    assert!(restored.is_clock_paused());
+    assert_eq!(
+        restored.get_remaining_time(Color::White),
+        Some(Duration::from_secs(295))
+    );
+    assert_eq!(
+        restored.get_remaining_time(Color::Black),
+        Some(Duration::from_secs(240))
+    );
+
+    time.advance(300_000);
+    let saved: SavedGame = SavedGame::deserialize_with_time_source(&text, time.clone()).unwrap();
+    assert_eq!(
+        saved.get_board().get_outcome(),
+        Some(Outcome::Timeout(Color::White))
+    );
+
+    let saved: SavedGame =
+        SavedGame::deserialize_with_time_source(&paused_text, time.clone()).unwrap();
+    assert_eq!(
+        saved.get_board().get_remaining_time(Color::Black),
+        Some(Duration::from_secs(300))
+    );
+    assert!(SavedGame::deserialize("chess-save 1\nclock 300 1 2 3 4\n\n*\n").is_err());
+}
+
+#[test]
+fn endings_outside_the_moves_are_restored() {
+    let mut board: Board = Board::new().initialize();
+    play(&mut board, &["e4", "e5"]);
+    board.offer_draw(Color::White);
+    assert!(reload(&board).get_draw_offer() == Some(Color::White));
+
+    let mut resigned: Board = board.clone();
+    resigned.resign(Color::Black);
+    assert_eq!(
+        reload(&resigned).get_outcome(),
+        Some(Outcome::Resignation(Color::White))
+    );
+
+    let mut agreed: Board = board.clone();
+    agreed.accept_draw(Color::Black);
+    assert_eq!(reload(&agreed).get_outcome(), Some(Outcome::Agreement));
+
+    let mut abandoned: Board = board.clone();
+    abandoned.abandon(Color::White);
+    assert_eq!(
+        reload(&abandoned).get_outcome(),
+        Some(Outcome::Abandonment(Color::Black))
+    );
+
+    let mut claimed: Board = Board::new().initialize();
+    play(
+        &mut claimed,
+        &["Nf3", "Nf6", "Ng1", "Ng8", "Nf3", "Nf6", "Ng1", "Ng8"],
+    );
+    claimed.claim_draw();
+    assert_eq!(
+        reload(&claimed).get_outcome(),
+        Some(Outcome::ThreefoldRepetition)
+    );
+
+    let time: Arc<MockTimeSource> = Arc::new(MockTimeSource::new(0));
+    let mut flagged: Board = Board::new().initialize();
+    flagged.set_time_source(time.clone());
+    flagged.set_time_control(Some(TimeControl::parse("10").unwrap()));
+    time.advance(10_000);
+    flagged.check_flag();
+    assert_eq!(
+        reload(&flagged).get_outcome(),
+        Some(Outcome::Timeout(Color::Black))
+    );
+}
+
+#[test]
+fn games_played_on_past_a_fivefold_repetition_are_restored() {
+    let game: PgnGame = parse_pgn(
+        "1. Nf3 Nf6 2. Ng1 Ng8 3. Nf3 Nf6 4. Ng1 Ng8 5. Nf3 Nf6 6. Ng1 Ng8\n\
+         7. Nf3 Nf6 8. Ng1 Ng8 9. e4 e5 *\n",
+    )
+    .unwrap()
+    .remove(0);
+    let board: Board = game.replay().unwrap();
+
+    let restored: Board = reload(&board);
+    assert_eq!(restored.get_notations(), board.get_notations());
+    assert!(!restored.is_end());
+    assert!(restored.get_automatic_draws());
+}
+
+#[test]
+fn older_and_newer_saves_are_told_apart() {
+    let legacy: SavedGame =
+        SavedGame::deserialize("[Event \"Casual\"]\n\n1. e4 e5 2. Nf3 *\n").unwrap();
+    assert_eq!(legacy.get_name(), "Saved game");
+    assert_eq!(legacy.get_board().get_notations(), ["e4", "e5", "Nf3"]);
+    assert_eq!(legacy.get_computer(), None);
+
+    assert_eq!(
+        SavedGame::deserialize("chess-save 3\nname future\n\n*\n").err(),
+        Some(SaveError::UnsupportedVersion(3))
+    );
+    assert_eq!(
+        SavedGame::deserialize("chess-save 1\nevent 0 accept black\n\n*\n").err(),
+        Some(SaveError::InvalidLine("event 0 accept black".to_string()))
+    );
+    assert!(SavedGame::deserialize("chess-save 1\ncolour blue\n\n*\n").is_err());
+}
+
+#[test]
+fn the_library_keeps_named_games() {
+    let mut library: GameLibrary<MemoryStore> = GameLibrary::new(MemoryStore::new());
+    let mut board: Board = Board::new().initialize();
+    assert!(library.load_current().unwrap().is_none());
+
+    play(&mut board, &["d4"]);
+    let first: u32 = library
+        .add_game(&SavedGame::new("first", board.clone(), None, 3))
+        .unwrap();
+    play(&mut board, &["d5"]);
+    let second: u32 = library
+        .add_game(&SavedGame::new("second", board.clone(), None, 3))
+        .unwrap();
+    library
+        .save_current(&SavedGame::new(
+            "current",
+            board.clone(),
+            Some(Color::White),
+            2,
+        ))
+        .unwrap();
+
+    library.rename_game(first, "opening").unwrap();
+    assert_eq!(
+        library.get_games(),
+        [
+            (first, "opening".to_string()),
+            (second, "second".to_string())
+        ]
+    );
+    assert_eq!(library.open_game(first).unwrap().get_name(), "opening");
+    assert_eq!(
+        library
+            .open_game(second)
+            .unwrap()
+            .get_board()
+            .get_notations(),
+        ["d4", "d5"]
+    );
+
+    library.delete_game(first).unwrap();
+    assert_eq!(library.get_games(), [(second, "second".to_string())]);
+    assert_eq!(
+        library.open_game(first).err(),
+        Some(SaveError::NotFound(first))
+    );
+    assert_eq!(
+        library.load_current().unwrap().unwrap().get_computer(),
+        Some(Color::White)
+    );
+}
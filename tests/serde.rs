@@ -0,0 +1,183 @@
+#![cfg(feature = "serde")]
+
+mod common;
+
+use std::{sync::Arc, time::Duration};
+
+use chess::{
+    board::Board,
+    clock::{MockTimeSource, TimeControl},
+    color::Color,
+    event::GameEvent,
+    outcome::{GameResult, Outcome, Termination},
+    position::Position,
+    save::{GameRecord, RECORD_VERSION},
+};
+use serde_json::{json, Value};
+
+use common::play;
+
+const FIXTURE: &str = include_str!("fixtures/game_record.json");
+
+#[test]
+fn core_types_use_a_readable_schema() {
+    let board: Board = Board::new().initialize();
+
+    assert_eq!(
+        serde_json::to_value(Position::from_notation("e4").unwrap()).unwrap(),
+        json!("e4")
+    );
+    assert!(serde_json::from_value::<Position>(json!("i9")).is_err());
+    assert_eq!(serde_json::to_value(Color::Black).unwrap(), json!("black"));
+    assert_eq!(
+        serde_json::to_value(TimeControl::parse("40/5400:1800+30").unwrap()).unwrap(),
+        json!("40/5400:1800+30")
+    );
+    assert_eq!(
+        serde_json::to_value(board.parse_san("Nf3").unwrap()).unwrap(),
+        json!({
+            "from": "g1",
+            "to": "f3",
+            "piece": { "kind": "knight", "color": "white" },
+            "piece_captured": null,
+            "promotion": null,
+            "is_castling": false,
+            "is_en_passant": false
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(Outcome::Checkmate(Color::White)).unwrap(),
+        json!({ "checkmate": "white" })
+    );
+    assert_eq!(
+        serde_json::to_value(GameEvent::DrawOffered(Color::Black)).unwrap(),
+        json!({ "draw_offered": "black" })
+    );
+}
+
+#[test]
+fn boards_are_serialized_as_game_records() {
+    let mut board: Board = Board::new().initialize();
+    play(
+        &mut board,
+        &["e4", "e5", "Qh5", "Nc6", "Bc4", "Nf6", "Qxf7#"],
+    );
+
+    let value: Value = serde_json::to_value(&board).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "version": RECORD_VERSION,
+            "fen": "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "tags": [
+                ["Event", "?"],
+                ["Site", "?"],
+                ["Date", "????.??.??"],
+                ["Round", "?"],
+                ["White", "?"],
+                ["Black", "?"]
+            ],
+            "moves": ["e2e4", "e7e5", "d1h5", "b8c6", "f1c4", "g8f6", "h5f7"],
+            "events": [],
+            "result": "white_wins",
+            "termination": "checkmate",
+            "outcome": { "checkmate": "white" },
+            "clock": null
+        })
+    );
+
+    let restored: Board = serde_json::from_value(value).unwrap();
+    assert_eq!(
+        restored.get_outcome(),
+        Some(Outcome::Checkmate(Color::White))
+    );
+    assert_eq!(restored.to_pgn(), board.to_pgn());
+}
+
+#[test]
+fn games_round_trip_with_events_endings_and_clocks() {
+    let time: Arc<MockTimeSource> = Arc::new(MockTimeSource::new(0));
+    let mut board: Board = Board::from_fen("8/P6k/8/8/8/8/8/KR6 w - - 0 1").unwrap();
+    board.set_time_source(time.clone());
+    board.set_time_control(Some(TimeControl::parse("60+1").unwrap()));
+    board.set_pgn_tag("White", "Alice");
+    time.advance(3_000);
+    play(&mut board, &["a8=N"]);
+    board.offer_draw(Color::White);
+    time.advance(2_000);
+    play(&mut board, &["Kg6"]);
+    board.abandon(Color::White);
+
+    let text: String = serde_json::to_string(&board).unwrap();
+    let restored: Board = serde_json::from_str(&text).unwrap();
+    assert_eq!(restored.get_notations(), board.get_notations());
+    assert_eq!(restored.get_events(), board.get_events());
+    assert_eq!(
+        restored.get_outcome(),
+        Some(Outcome::Abandonment(Color::Black))
+    );
+    assert_eq!(restored.to_pgn(), board.to_pgn());
+    assert!(restored.is_clock_paused());
+    assert_eq!(
+        restored.get_remaining_time(Color::White),
+        Some(Duration::from_millis(58_000))
+    );
+    assert_eq!(
+        restored.get_remaining_time(Color::Black),
+        Some(Duration::from_millis(59_000))
+    );
+}
+
+#[test]
+fn games_played_on_past_a_fivefold_repetition_round_trip() {
+    let mut board: Board = Board::new().initialize();
+    board.set_automatic_draws(false);
+    for _ in 0..4 {
+        play(&mut board, &["Nf3", "Nf6", "Ng1", "Ng8"]);
+    }
+    play(&mut board, &["e4", "e5"]);
+    board.set_automatic_draws(true);
+
+    let text: String = serde_json::to_string(&board).unwrap();
+    let restored: Board = serde_json::from_str(&text).unwrap();
+    assert_eq!(restored.get_notations(), board.get_notations());
+    assert!(!restored.is_end());
+}
+
+#[test]
+fn fixture_files_load_as_games() {
+    let record: GameRecord = serde_json::from_str(FIXTURE).unwrap();
+    assert_eq!(record.get_version(), 1);
+    assert_eq!(record.get_result(), GameResult::WhiteWins);
+    assert_eq!(record.get_termination(), Some(Termination::Resignation));
+
+    let board: Board = Board::try_from(record).unwrap();
+    assert_eq!(
+        board.get_notations(),
+        ["e4", "e5", "Nf3", "Nc6", "Bb5"].map(String::from)
+    );
+    assert_eq!(
+        board.get_events()[2],
+        (5, GameEvent::Resigned(Color::Black))
+    );
+    assert_eq!(
+        board.get_remaining_time(Color::Black),
+        Some(Duration::from_millis(251_000))
+    );
+    assert!(board.to_pgn().contains("[White \"Alice\"]"));
+}
+
+#[test]
+fn records_which_cannot_be_replayed_are_rejected() {
+    let mut value: Value = serde_json::from_str(FIXTURE).unwrap();
+    value["moves"][2] = json!("g1g3");
+    assert!(serde_json::from_value::<Board>(value).is_err());
+
+    let mut value: Value = serde_json::from_str(FIXTURE).unwrap();
+    value["outcome"] = json!("stalemate");
+    assert!(serde_json::from_value::<Board>(value).is_err());
+
+    let mut value: Value = serde_json::from_str(FIXTURE).unwrap();
+    value["version"] = json!(RECORD_VERSION + 1);
+    assert!(serde_json::from_value::<Board>(value).is_err());
+}
@@ -0,0 +1,223 @@
+use chess::{
+    board::Board,
+    clock::TimeControl,
+    color::Color,
+    engine::SearchLimits,
+    pgn::{parse_pgn, PgnGame},
+    tournament::{
+        play_game, Adjudication, EngineMatch, MatchGame, MatchSettings, MatchStats, Player,
+        PlayerConfig, Sprt, SprtResult,
+    },
+};
+
+/// A time control long enough for games searched to a fixed depth.
+const TIME_CONTROL: &str = "60";
+
+/// The path of the scripted engine which never ends a search by itself.
+#[cfg(unix)]
+const STALLED_ENGINE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/stalled_engine.sh"
+);
+
+/// Creates a built-in player searching to a fixed depth.
+///
+/// # Arguments
+///
+/// * `depth` - The depth of its searches.
+///
+/// # Returns
+///
+/// The player.
+fn builtin(depth: u32) -> Box<dyn Player> {
+    PlayerConfig::parse(&format!("builtin:depth={},hash=1", depth))
+        .unwrap()
+        .create()
+        .unwrap()
+}
+
+#[test]
+fn players_are_read_from_their_specification() {
+    assert_eq!(
+        PlayerConfig::parse("builtin:depth=3,nodes=500,name=Fast").unwrap(),
+        PlayerConfig::Builtin {
+            name: "Fast".to_string(),
+            limits: SearchLimits::new().with_depth(3).with_nodes(500),
+            hash_size: 16,
+        }
+    );
+    assert_eq!(
+        PlayerConfig::parse("./builtin-engine").unwrap(),
+        PlayerConfig::Uci {
+            path: "./builtin-engine".to_string()
+        }
+    );
+    assert!(PlayerConfig::parse("builtin:speed=3").is_err());
+}
+
+#[test]
+fn statistics_give_the_elo_difference() {
+    let stats: MatchStats = MatchStats::new(10, 20, 5);
+
+    assert_eq!(stats.get_games(), 35);
+    assert!((stats.get_score() - 20.0 / 35.0).abs() < 1e-9);
+    assert!((stats.get_elo_difference().unwrap() - 49.975).abs() < 0.01);
+    assert!((stats.get_elo_error().unwrap() - 76.350).abs() < 0.01);
+    assert!((stats.get_llr(0.0, 10.0) - 0.317).abs() < 0.001);
+    assert_eq!(MatchStats::new(3, 0, 0).get_elo_difference(), None);
+    assert_eq!(MatchStats::default().get_elo_difference(), None);
+}
+
+#[test]
+fn sprt_decides_once_the_results_are_conclusive() {
+    let sprt: Sprt = Sprt::new(0.0, 5.0, 0.05, 0.05);
+
+    assert!((sprt.get_upper_bound() - 2.944).abs() < 0.001);
+    assert!((sprt.get_lower_bound() + 2.944).abs() < 0.001);
+    assert_eq!(
+        sprt.get_result(&MatchStats::new(400, 400, 250)),
+        SprtResult::AcceptH1
+    );
+    assert_eq!(
+        sprt.get_result(&MatchStats::new(250, 400, 400)),
+        SprtResult::AcceptH0
+    );
+    assert_eq!(
+        sprt.get_result(&MatchStats::new(300, 400, 200)),
+        SprtResult::Continue
+    );
+}
+
+#[test]
+fn games_are_adjudicated_after_the_last_move() {
+    let mut white: Box<dyn Player> = builtin(1);
+    let mut black: Box<dyn Player> = builtin(1);
+    let game: MatchGame = play_game(
+        white.as_mut(),
+        black.as_mut(),
+        &Board::new().initialize(),
+        &TimeControl::parse(TIME_CONTROL).unwrap(),
+        Adjudication::new().with_max_moves(5),
+    )
+    .unwrap();
+
+    assert_eq!(game.get_result(), "1/2-1/2");
+    assert_eq!(game.get_reason(), "adjudication");
+    assert_eq!(game.get_board().get_notations().len(), 10);
+
+    let games: Vec<PgnGame> = parse_pgn(&game.get_board().to_pgn()).unwrap();
+    assert_eq!(games[0].get_result(), "1/2-1/2");
+    assert_eq!(games[0].get_tags().get("Termination"), Some("adjudication"));
+    assert_eq!(games[0].get_tags().get("TimeControl"), Some("60"));
+    assert_eq!(
+        games[0].get_tags().get("White"),
+        Some("builtin depth=1,hash=1")
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn silent_engines_lose_on_time() {
+    let mut white: Box<dyn Player> = PlayerConfig::Uci {
+        path: STALLED_ENGINE.to_string(),
+    }
+    .create()
+    .unwrap();
+    let mut black: Box<dyn Player> = builtin(1);
+    let game: MatchGame = play_game(
+        white.as_mut(),
+        black.as_mut(),
+        &Board::new().initialize(),
+        &TimeControl::parse("0.1").unwrap(),
+        Adjudication::new(),
+    )
+    .unwrap();
+
+    assert_eq!(game.get_winner(), Some(Color::Black));
+    assert_eq!(game.get_reason(), "timeout");
+    assert_eq!(game.get_board().get_notations().len(), 0);
+
+    let games: Vec<PgnGame> = parse_pgn(&game.get_board().to_pgn()).unwrap();
+    assert_eq!(games[0].get_tags().get("Termination"), Some("time forfeit"));
+}
+
+#[test]
+fn games_end_with_the_rules() {
+    let mut white: Box<dyn Player> = builtin(3);
+    let mut black: Box<dyn Player> = builtin(3);
+    let opening: Board = Board::from_fen("6k1/5ppp/8/8/8/8/5PPP/3R2K1 w - - 0 1").unwrap();
+    let game: MatchGame = play_game(
+        white.as_mut(),
+        black.as_mut(),
+        &opening,
+        &TimeControl::parse(TIME_CONTROL).unwrap(),
+        Adjudication::new(),
+    )
+    .unwrap();
+
+    assert_eq!(game.get_winner(), Some(Color::White));
+    assert_eq!(game.get_reason(), "checkmate");
+    assert_eq!(game.get_board().get_notations(), ["Rd8#"]);
+}
+
+#[test]
+fn matches_alternate_colors_and_openings() {
+    let openings: Vec<Board> = vec![
+        Board::from_fen("6k1/5ppp/8/8/8/8/5PPP/3R2K1 w - - 0 1").unwrap(),
+        Board::from_fen("3r2k1/5ppp/8/8/8/8/5PPP/6K1 b - - 0 1").unwrap(),
+    ];
+    let settings: MatchSettings = MatchSettings::new(4, TimeControl::parse(TIME_CONTROL).unwrap())
+        .with_openings(openings)
+        .unwrap()
+        .with_adjudication(Adjudication::new().with_max_moves(20));
+    let mut engine_match: EngineMatch = EngineMatch::new(builtin(3), builtin(1), settings);
+    let mut results: Vec<String> = Vec::new();
+
+    while let Some(game) = engine_match.play_next().unwrap() {
+        let tags: (&str, &str) = (
+            game.get_board().get_pgn_tags().get("White").unwrap(),
+            game.get_board().get_pgn_tags().get("Round").unwrap(),
+        );
+        results.push(format!("{} {} {}", tags.1, tags.0, game.get_result()));
+    }
+
+    assert_eq!(
+        results,
+        [
+            "1 builtin depth=3,hash=1 1-0",
+            "2 builtin depth=1,hash=1 1-0",
+            "3 builtin depth=3,hash=1 0-1",
+            "4 builtin depth=1,hash=1 0-1",
+        ]
+    );
+    assert_eq!(engine_match.get_stats(), MatchStats::new(2, 0, 2));
+    assert!(engine_match.is_finished());
+}
+
+#[cfg(unix)]
+#[test]
+fn engines_lose_by_illegal_moves() {
+    let mut white: Box<dyn Player> = builtin(1);
+    let mut black: Box<dyn Player> = PlayerConfig::parse(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/fake_engine.sh"
+    ))
+    .unwrap()
+    .create()
+    .unwrap();
+    let game: MatchGame = play_game(
+        white.as_mut(),
+        black.as_mut(),
+        &Board::new().initialize(),
+        &TimeControl::parse(TIME_CONTROL).unwrap(),
+        Adjudication::new(),
+    )
+    .unwrap();
+
+    assert_eq!(game.get_winner(), Some(Color::White));
+    assert_eq!(game.get_reason(), "illegal move");
+    assert_eq!(
+        game.get_board().get_pgn_tags().get("Black"),
+        Some("Fake Engine 1.0")
+    );
+}
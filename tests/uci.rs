@@ -0,0 +1,85 @@
+use std::{
+    io::Write,
+    process::{Child, Command, Output, Stdio},
+};
+
+use chess::board::Board;
+
+/// Runs the UCI engine with the given commands as its standard input.
+///
+/// # Arguments
+///
+/// * `commands` - The commands, one per line.
+///
+/// # Returns
+///
+/// The lines written by the engine.
+fn run_engine(commands: &str) -> Vec<String> {
+    let mut engine: Child = Command::new(env!("CARGO_BIN_EXE_uci"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    engine
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(commands.as_bytes())
+        .unwrap();
+    let output: Output = engine.wait_with_output().unwrap();
+
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn engine_answers_the_handshake() {
+    let lines: Vec<String> = run_engine("uci\nisready\nquit\n");
+
+    assert!(lines.iter().any(|line| line.starts_with("id name ")));
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("option name Hash type spin")));
+    assert_eq!(lines[lines.len() - 2], "uciok");
+    assert_eq!(lines[lines.len() - 1], "readyok");
+}
+
+#[test]
+fn engine_searches_the_position_given() {
+    let lines: Vec<String> =
+        run_engine("position fen 6k1/5ppp/8/8/8/8/5PPP/3R2K1 w - - 0 1\ngo depth 4\n");
+
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("info depth 1 score mate 1 ")));
+    assert_eq!(lines.last().unwrap(), "bestmove d1d8");
+}
+
+#[test]
+fn engine_plays_the_moves_given() {
+    let lines: Vec<String> = run_engine(
+        "position startpos moves e2e4 e7e5 g1f3 b8c6 f1c4 g8f6 f3g5 d7d5 e4d5 f6d5 g5f7\n\
+         go depth 2\n",
+    );
+
+    assert!(lines.last().unwrap().starts_with("bestmove "));
+    assert_ne!(lines.last().unwrap(), "bestmove 0000");
+}
+
+#[test]
+fn engine_plays_on_past_automatic_draws() {
+    let shuffle: &str = "g1f3 g8f6 f3g1 f6g8 ";
+    let lines: Vec<String> = run_engine(&format!(
+        "position startpos moves {}e2e4\ngo depth 2\n",
+        shuffle.repeat(4)
+    ));
+
+    assert!(!lines.iter().any(|line| line.starts_with("info string")));
+    let best_move: &str = lines.last().unwrap().strip_prefix("bestmove ").unwrap();
+    let board: Board =
+        Board::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 9").unwrap();
+    assert!(board.parse_uci(best_move).is_some(), "{}", best_move);
+}
@@ -0,0 +1,128 @@
+#![cfg(unix)]
+
+use std::time::{Duration, Instant};
+
+use chess::{
+    board::Board,
+    engine::Score,
+    fen::STARTING_FEN,
+    moves::Move,
+    uci::{
+        parse_message, BestMove, GoParams, ScoreBound, UciEngine, UciError, UciInfo, UciMessage,
+    },
+};
+
+/// The path of the scripted engine used by the tests.
+const FAKE_ENGINE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/fake_engine.sh");
+
+/// The path of the scripted engine which never ends a search by itself.
+const STALLED_ENGINE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/stalled_engine.sh"
+);
+
+/// The time the stalled engine is given before it is stopped.
+const TIMEOUT: Duration = Duration::from_millis(100);
+
+#[test]
+fn client_performs_the_handshake() {
+    let engine: UciEngine = UciEngine::spawn(FAKE_ENGINE, &[]).unwrap();
+
+    assert_eq!(engine.get_name(), "Fake Engine 1.0");
+    assert_eq!(engine.get_author(), "Nobody");
+}
+
+#[test]
+fn client_sends_the_position_and_reads_the_search() {
+    let mut engine: UciEngine = UciEngine::spawn(FAKE_ENGINE, &[]).unwrap();
+    let mut board: Board = Board::from_fen(STARTING_FEN).unwrap();
+    for san in ["e4", "c5"] {
+        let chess_move: Move = board.parse_san(san).unwrap();
+        board.make_move(chess_move).unwrap();
+    }
+
+    engine.new_game().unwrap();
+    engine.set_position(&board).unwrap();
+    let mut infos: Vec<UciInfo> = Vec::new();
+    let best_move: BestMove = engine
+        .go(&GoParams::new().with_depth(2), TIMEOUT, |info| {
+            infos.push(info.clone())
+        })
+        .unwrap();
+
+    assert_eq!(
+        infos[0].get_string(),
+        Some("position startpos moves e2e4 c7c5")
+    );
+    assert_eq!(infos[1].get_depth(), Some(1));
+    assert_eq!(infos[1].get_score(), Some(Score::Centipawns(13)));
+    assert_eq!(infos[1].get_bound(), Some(ScoreBound::Exact));
+    assert_eq!(infos[2].get_seldepth(), Some(4));
+    assert_eq!(infos[2].get_multipv(), Some(1));
+    assert_eq!(infos[2].get_score(), Some(Score::Mate(-2)));
+    assert_eq!(infos[2].get_bound(), Some(ScoreBound::Upper));
+    assert_eq!(infos[2].get_nodes(), Some(400));
+    assert_eq!(infos[2].get_nps(), Some(8000));
+    assert_eq!(infos[2].get_time(), Some(50));
+    assert_eq!(infos[2].get_pv(), ["e2e4", "e7e5", "g1f3"]);
+    assert_eq!(best_move.get_best_move(), Some("e2e4"));
+    assert_eq!(best_move.get_ponder(), Some("e7e5"));
+}
+
+#[test]
+fn client_reads_the_pv_as_moves() {
+    let board: Board = Board::from_fen(STARTING_FEN).unwrap();
+    let info: UciInfo = match parse_message("info depth 3 score cp 20 pv e2e4 e7e5 e1e3 g1f3") {
+        UciMessage::Info(info) => info,
+        message => panic!("unexpected message {:?}", message),
+    };
+
+    let moves: Vec<String> = info
+        .get_pv_moves(&board)
+        .iter()
+        .map(|chess_move| chess_move.get_uci())
+        .collect();
+    assert_eq!(moves, ["e2e4", "e7e5"]);
+}
+
+#[test]
+fn client_writes_go_commands() {
+    assert_eq!(GoParams::new().with_depth(8).get_command(), "go depth 8");
+    assert_eq!(GoParams::new().get_command(), "go infinite");
+    assert_eq!(
+        parse_message("bestmove (none)"),
+        UciMessage::BestMove(match parse_message("bestmove 0000") {
+            UciMessage::BestMove(best_move) => best_move,
+            message => panic!("unexpected message {:?}", message),
+        })
+    );
+}
+
+#[test]
+fn client_stops_a_search_at_the_timeout() {
+    let mut engine: UciEngine = UciEngine::spawn(STALLED_ENGINE, &["stop".to_string()]).unwrap();
+    let board: Board = Board::from_fen(STARTING_FEN).unwrap();
+    let mut infos: u32 = 0;
+
+    engine.set_position(&board).unwrap();
+    let best_move: BestMove = engine
+        .go(&GoParams::new(), TIMEOUT, |_| infos += 1)
+        .unwrap();
+
+    assert_eq!(infos, 1);
+    assert_eq!(best_move.get_best_move(), Some("d2d4"));
+}
+
+#[test]
+fn client_gives_up_on_an_engine_which_never_answers() {
+    let mut engine: UciEngine = UciEngine::spawn(STALLED_ENGINE, &[]).unwrap();
+    let board: Board = Board::from_fen(STARTING_FEN).unwrap();
+    let start: Instant = Instant::now();
+
+    engine.set_position(&board).unwrap();
+    let result: Result<BestMove, UciError> =
+        engine.go(&GoParams::new().with_depth(2), TIMEOUT, |_| {});
+
+    assert!(matches!(result, Err(UciError::Timeout(command)) if command == "go"));
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
@@ -0,0 +1,135 @@
+mod common;
+
+use chess::{board::Board, castling::CastlingRights, color::Color, kind::Kind, position::Position};
+
+use common::play;
+
+/// Represents everything undo has to restore, read from a board.
+#[derive(Debug, PartialEq)]
+struct Snapshot {
+    /// The FEN of the position.
+    fen: String,
+    /// The Zobrist key of the position.
+    hash: u64,
+    /// The castling rights.
+    castling_rights: CastlingRights,
+    /// The en passant square.
+    en_passant: Option<Position>,
+    /// The halfmove clock.
+    halfmove_clock: u32,
+    /// The notations of the moves played.
+    notations: Vec<String>,
+}
+
+/// Reads the state undo has to restore from a board.
+///
+/// # Arguments
+///
+/// * `board` - The board to read.
+///
+/// # Returns
+///
+/// The `Snapshot` of the board.
+fn snapshot(board: &Board) -> Snapshot {
+    Snapshot {
+        fen: board.to_fen(),
+        hash: board.hash(),
+        castling_rights: board.get_castling_rights(),
+        en_passant: board.get_en_passant(),
+        halfmove_clock: board.get_halfmove_clock(),
+        notations: board.get_notations().to_vec(),
+    }
+}
+
+#[test]
+fn undo_restores_castling_rights_and_captured_pieces() {
+    let mut board: Board = Board::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 5 20").unwrap();
+    let before: Snapshot = snapshot(&board);
+
+    play(&mut board, &["O-O"]);
+    assert!(!board.get_castling_rights().get_king_side(Color::White));
+    assert!(board.undo());
+    assert_eq!(snapshot(&board), before);
+
+    play(&mut board, &["Rxa8+"]);
+    assert_eq!(board.get_halfmove_clock(), 0);
+    assert!(!board.get_castling_rights().get_queen_side(Color::Black));
+    assert!(board.undo());
+    assert_eq!(snapshot(&board), before);
+    assert_eq!(
+        board
+            .get_cell(Position::from_notation("a8").unwrap())
+            .get_piece_kind(),
+        Kind::Rook
+    );
+    assert_eq!(board.get_color_turn(), Color::White);
+}
+
+#[test]
+fn undo_restores_the_en_passant_square() {
+    let mut board: Board = Board::new().initialize();
+    play(&mut board, &["e4", "a6", "e5"]);
+    let before_push: Snapshot = snapshot(&board);
+    play(&mut board, &["d5"]);
+    let before_capture: Snapshot = snapshot(&board);
+    assert_eq!(board.get_en_passant(), Position::from_notation("d6"));
+
+    play(&mut board, &["exd6"]);
+    assert!(board
+        .get_cell(Position::from_notation("d5").unwrap())
+        .get_piece()
+        .is_none());
+    assert!(board.undo());
+    assert_eq!(snapshot(&board), before_capture);
+    assert_eq!(
+        board
+            .get_cell(Position::from_notation("d5").unwrap())
+            .get_piece_kind(),
+        Kind::Pawn
+    );
+
+    assert!(board.undo());
+    assert_eq!(snapshot(&board), before_push);
+    assert_eq!(board.get_en_passant(), None);
+}
+
+#[test]
+fn undo_reopens_a_finished_game() {
+    let mut board: Board = Board::new().initialize();
+    play(&mut board, &["f3", "e5", "g4", "Qh4#"]);
+    assert!(board.is_end());
+
+    assert!(board.undo());
+    assert!(!board.is_end());
+    assert_eq!(board.get_notations(), ["f3", "e5", "g4"]);
+    play(&mut board, &["Nc6"]);
+}
+
+#[test]
+fn redo_replays_the_moves_taken_back() {
+    let mut board: Board = Board::new().initialize();
+    assert!(!board.can_undo());
+    play(&mut board, &["e4", "e5", "Nf3"]);
+    let played: Snapshot = snapshot(&board);
+
+    assert!(board.undo());
+    assert!(board.undo());
+    assert!(board.can_redo());
+    assert!(board.redo());
+    assert!(board.redo());
+    assert!(!board.can_redo());
+    assert_eq!(snapshot(&board), played);
+}
+
+#[test]
+fn a_new_move_clears_the_moves_taken_back() {
+    let mut board: Board = Board::new().initialize();
+    play(&mut board, &["e4", "e5", "Nf3"]);
+
+    assert!(board.undo());
+    assert!(board.undo());
+    play(&mut board, &["c5"]);
+    assert!(!board.can_redo());
+    assert!(!board.redo());
+    assert_eq!(board.get_notations(), ["e4", "c5"]);
+}
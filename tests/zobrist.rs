@@ -0,0 +1,98 @@
+use chess::{board::Board, fen::STARTING_FEN, moves::Move};
+
+/// The reference keys published with the Polyglot book format.
+const POSITIONS: [(&str, u64); 9] = [
+    (STARTING_FEN, 0x463B96181691FC9C),
+    (
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1",
+        0x823C9B50FD114196,
+    ),
+    (
+        "rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq d6 0 2",
+        0x0756B94461C50FB0,
+    ),
+    (
+        "rnbqkbnr/ppp1pppp/8/3pP3/8/8/PPPP1PPP/RNBQKBNR b KQkq - 0 2",
+        0x662FAFB965DB29D4,
+    ),
+    (
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+        0x22A48B5A8E47FF78,
+    ),
+    (
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPPKPPP/RNBQ1BNR b kq - 1 3",
+        0x652A607CA3F242C1,
+    ),
+    (
+        "rnbq1bnr/ppp1pkpp/8/3pPp2/8/8/PPPPKPPP/RNBQ1BNR w - - 2 4",
+        0x00FDD303C946BDD9,
+    ),
+    (
+        "rnbqkbnr/p1pppppp/8/8/PpP4P/8/1P1PPPP1/RNBQKBNR b KQkq c3 0 3",
+        0x3C8123EA7B067637,
+    ),
+    (
+        "rnbqkbnr/p1pppppp/8/8/P6P/R1p5/1P1PPPP1/1NBQKBNR b Kkq - 1 4",
+        0x5C3F9B829B279560,
+    ),
+];
+
+#[test]
+fn hash_matches_polyglot_reference_keys() {
+    for (fen, expected) in POSITIONS {
+        let board: Board = Board::from_fen(fen).unwrap();
+        assert_eq!(board.hash(), expected, "{}", fen);
+        assert_eq!(board.get_setup().hash(), expected, "{}", fen);
+    }
+}
+
+#[test]
+fn hash_is_updated_along_the_game() {
+    let mut board: Board = Board::from_fen(STARTING_FEN).unwrap();
+    let line: [&str; 12] = [
+        "e4", "d5", "e5", "f5", "exf6", "Nxf6", "Nf3", "Nc6", "Bb5", "Bd7", "O-O", "e5",
+    ];
+
+    for san in line {
+        let chess_move: Move = board.parse_san(san).unwrap();
+        board.make_move(chess_move).unwrap();
+        assert_eq!(board.hash(), board.get_setup().hash(), "after {}", san);
+    }
+
+    while board.undo() {
+        assert_eq!(board.hash(), board.get_setup().hash());
+    }
+    assert_eq!(board.hash(), POSITIONS[0].1);
+}
+
+#[test]
+fn repetition_keys_only_count_legal_en_passant_captures() {
+    // The pawn on e4 is pinned to its king, so it cannot take on d3.
+    let pinned: Board = Board::from_fen("1n2k3/8/8/8/3Pp3/8/8/4R2K b - d3 0 1").unwrap();
+    let unpinned: Board = Board::from_fen("1n2k3/8/8/8/3Pp3/8/8/7K b - d3 0 1").unwrap();
+
+    assert_eq!(
+        pinned.get_position_keys(),
+        [pinned.get_setup().get_position_key()]
+    );
+    assert_ne!(pinned.get_setup().get_position_key(), pinned.hash());
+    assert_eq!(
+        unpinned.get_position_keys(),
+        [unpinned.get_setup().get_position_key()]
+    );
+    assert_eq!(unpinned.get_setup().get_position_key(), unpinned.hash());
+}
+
+#[test]
+fn repetition_keys_follow_the_game() {
+    let mut board: Board = Board::from_fen(STARTING_FEN).unwrap();
+    for san in ["e4", "d5", "e5", "f5", "exf6", "Nxf6", "Nf3", "Nc6"] {
+        board.make_move(board.parse_san(san).unwrap()).unwrap();
+        assert_eq!(
+            board.get_position_keys().last(),
+            Some(&board.get_setup().get_position_key()),
+            "after {}",
+            san
+        );
+    }
+}